@@ -18,7 +18,7 @@ mod layout;
 mod view;
 
 pub use layout::{AdapterLayout, AdapterLayoutInterface};
-pub use view::AdapterView;
+pub use view::{AdapterView, ScrollSettings};
 
 /// Trait representing some view which may "adapt" to a specific change in
 /// external data.
@@ -37,13 +37,22 @@ pub type DownwardVecAdapter<T, W> =
     Widget<AdapterView<DownwardVecLayout<T>, W>>;
 
 /// An adapter layout which lays out elements from a Vec growing downwards.
-#[derive(Default)]
 pub struct DownwardVecLayout<T> {
     data: Vec<T>,
     reference_index: usize,
     avg_size: f32,
 }
 
+impl<T> Default for DownwardVecLayout<T> {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            reference_index: 0,
+            avg_size: 0.0,
+        }
+    }
+}
+
 impl<T> AdapterLayout for DownwardVecLayout<T> {
     type ElementKey = usize;
     type Collection = Vec<T>;
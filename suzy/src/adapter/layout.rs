@@ -136,11 +136,33 @@ impl<ElementKey, Content> AdapterLayoutData<ElementKey, Content> {
         self.active.iter_mut().map(|(_k, child)| child)
     }
 
+    /// Get the element for a key, if it was positioned by the last layout.
+    pub fn active_child(
+        &self,
+        key: &ElementKey,
+    ) -> Option<&widget::Ephemeral<Content>>
+    where
+        ElementKey: Hash + Eq,
+    {
+        self.active.get(key)
+    }
+
+    pub fn position(&self) -> [f32; 2] {
+        self.position
+    }
+
     pub fn move_content(&mut self, dx: f32, dy: f32) {
         let [px, py] = &mut self.position;
         *px += dx;
         *py += dy;
     }
+
+    /// How far the content must move to return to the rest position.
+    pub fn overscroll(&self) -> [f32; 2] {
+        let [px, py] = self.position;
+        let [rx, ry] = self.rest_position;
+        [rx - px, ry - py]
+    }
 }
 
 struct Interface<'a, Key, Content> {
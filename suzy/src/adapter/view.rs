@@ -1,14 +1,142 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
+use std::time::{Duration, Instant};
+
 use crate::{
+    dims::Rect,
     pointer::PointerId,
     watch::WatchedMeta,
-    widget::{self, UniqueHandle},
+    widget::{self, UniqueHandle, WidgetRect},
 };
 
 use super::{layout::AdapterLayoutData, Adaptable, AdapterLayout};
 
+/// If the content has not moved for this long before being released, it is
+/// considered to have stopped, and is not flung.
+const VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Below this speed, in pixels per second, a fling comes to rest.
+const MIN_VELOCITY: f32 = 10.0;
+
+/// Below this distance, in pixels, overscroll snaps to the rest position.
+const MIN_OVERSCROLL: f32 = 0.5;
+
+/// Configurable physics used when scrolling an [`AdapterView`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollSettings {
+    /// If false, the content stops as soon as it is released, instead of
+    /// continuing with the velocity it was released at.
+    pub kinetic: bool,
+
+    /// The rate at which a fling slows down.  Each second, the velocity
+    /// is reduced by a factor of `e` raised to this power, so higher values
+    /// stop a fling sooner.
+    pub friction: f32,
+
+    /// The distance, in pixels, over which resistance builds when the
+    /// content is dragged past its ends.  Once the content is this far past
+    /// an end, dragging it further only moves it half as much.
+    pub rubber_band: f32,
+
+    /// The stiffness of the spring which returns the content to its rest
+    /// position after it is released past its ends.  Higher values return
+    /// it sooner.
+    pub spring_stiffness: f32,
+}
+
+impl Default for ScrollSettings {
+    fn default() -> Self {
+        Self {
+            kinetic: true,
+            friction: 3.0,
+            rubber_band: 100.0,
+            spring_stiffness: 150.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Motion {
+    velocity: [f32; 2],
+    sample_time: Option<Instant>,
+    sample_delta: [f32; 2],
+    last_tick: Option<Instant>,
+}
+
+impl Motion {
+    fn sample(&mut self, delta: [f32; 2]) {
+        let now = crate::app::time_unwatched();
+        self.sample_delta[0] += delta[0];
+        self.sample_delta[1] += delta[1];
+        // events within the same frame share a timestamp, so accumulate
+        // movement until time advances
+        let sample_time = *self.sample_time.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(sample_time);
+        if !elapsed.is_zero() {
+            let secs = elapsed.as_secs_f32();
+            for axis in 0..2 {
+                let sample = self.sample_delta[axis] / secs;
+                self.velocity[axis] = 0.8 * sample + 0.2 * self.velocity[axis];
+            }
+            self.sample_time = Some(now);
+            self.sample_delta = [0.0, 0.0];
+        }
+    }
+
+    /// Stop any fling, and start measuring velocity from now.
+    fn catch(&mut self) {
+        self.velocity = [0.0, 0.0];
+        self.sample_time = Some(crate::app::time_unwatched());
+        self.sample_delta = [0.0, 0.0];
+    }
+
+    fn release(&mut self, kinetic: bool) {
+        let now = crate::app::time_unwatched();
+        let stale = self.sample_time.is_none_or(|sample_time| {
+            now.saturating_duration_since(sample_time) > VELOCITY_TIMEOUT
+        });
+        if stale || !kinetic {
+            self.velocity = [0.0, 0.0];
+        }
+        self.sample_time = None;
+        self.sample_delta = [0.0, 0.0];
+        self.last_tick = Some(now);
+    }
+
+    fn stop(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Advance the content along one axis by `dt` seconds, returning how far it
+/// moved.
+///
+/// `offset` is how far the content is from its rest position; if it is
+/// past an end, a critically damped spring pulls it back, otherwise
+/// friction slows it down.
+fn step_axis(
+    settings: &ScrollSettings,
+    offset: f32,
+    velocity: &mut f32,
+    dt: f32,
+) -> f32 {
+    let v0 = *velocity;
+    if offset.abs() >= MIN_OVERSCROLL {
+        let omega = settings.spring_stiffness.max(0.0).sqrt();
+        let decay = (-omega * dt).exp();
+        let coeff = v0 + omega * offset;
+        *velocity = (v0 - omega * coeff * dt) * decay;
+        (offset + coeff * dt) * decay - offset
+    } else if settings.friction > 0.0 {
+        let decay = (-settings.friction * dt).exp();
+        *velocity = v0 * decay;
+        v0 * (1.0 - decay) / settings.friction
+    } else {
+        v0 * dt
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 enum PointerStatus {
     Grabbed,
@@ -60,6 +188,10 @@ impl PointerSet {
 }
 
 /// Base adapter view.
+///
+/// The content can be dragged with a pointer, or scrolled with the mouse
+/// wheel.  When released, it keeps moving with the velocity it was released
+/// at, and if it is past its ends, it springs back; see [`ScrollSettings`].
 pub struct AdapterView<Layout, Content>
 where
     Layout: AdapterLayout,
@@ -69,6 +201,10 @@ where
     position_flag: WatchedMeta<'static>,
     layout: Layout,
     current_pointers: PointerSet,
+    settings: ScrollSettings,
+    motion: Motion,
+    motion_flag: WatchedMeta<'static>,
+    scroll_target: Option<Layout::ElementKey>,
     handle: UniqueHandle,
 }
 
@@ -99,6 +235,166 @@ where
     ) -> impl Iterator<Item = &widget::Ephemeral<Content>> {
         self.inner.watch_each_child()
     }
+
+    /// Get the physics used when scrolling the view.
+    pub fn settings(&self) -> &ScrollSettings {
+        &self.settings
+    }
+
+    /// Get a mutable reference to the physics used when scrolling the
+    /// view.
+    pub fn settings_mut(&mut self) -> &mut ScrollSettings {
+        &mut self.settings
+    }
+
+    /// Check if the content is moving on its own, either because it was
+    /// flung or because it is returning to its rest position.
+    pub fn is_animating(&self) -> bool {
+        self.motion_flag.watched_auto();
+        self.motion.last_tick.is_some()
+    }
+
+    /// Scroll the view so that an element is entirely visible, moving the
+    /// content as little as possible.
+    ///
+    /// This stops any fling, and takes effect the next time the view is laid
+    /// out.  If the layout can not locate the element, the view does not
+    /// scroll.
+    pub fn scroll_to(&mut self, item: Layout::ElementKey) {
+        self.scroll_target = Some(item);
+        self.motion.stop();
+        self.motion_flag.trigger_auto();
+        self.position_flag.trigger_auto();
+    }
+
+    /// Move the content, resisting movement which takes it further past
+    /// its ends.
+    fn scroll_by(&mut self, dx: f32, dy: f32) -> [f32; 2] {
+        let rubber_band = self.settings.rubber_band.max(0.0);
+        let overscroll = self.inner.overscroll();
+        let resist = |delta: f32, over: f32| {
+            if delta * over < 0.0 {
+                delta * rubber_band / (rubber_band + over.abs())
+            } else {
+                delta
+            }
+        };
+        let delta = [resist(dx, overscroll[0]), resist(dy, overscroll[1])];
+        self.inner.move_content(delta[0], delta[1]);
+        self.position_flag.trigger_auto();
+        delta
+    }
+
+    fn drag(&mut self, dx: f32, dy: f32) {
+        let delta = self.scroll_by(dx, dy);
+        self.motion.sample(delta);
+    }
+
+    /// Let the content move on its own, with the velocity it was released
+    /// at.
+    fn release(&mut self) {
+        self.motion.release(self.settings.kinetic);
+        self.motion_flag.trigger_auto();
+    }
+
+    fn animate(&mut self) {
+        self.motion_flag.watched_auto();
+        let Some(last_tick) = self.motion.last_tick else {
+            return;
+        };
+        if self.current_pointers.primary_pointer().is_some() {
+            self.motion.stop();
+            self.motion_flag.trigger_auto();
+            return;
+        }
+        let now = crate::app::time();
+        let dt = now.saturating_duration_since(last_tick).as_secs_f32();
+        self.motion.last_tick = Some(now);
+        let overscroll = self.inner.overscroll();
+        let velocity = &mut self.motion.velocity;
+        let speed = velocity[0].hypot(velocity[1]);
+        let settled =
+            overscroll.iter().all(|over| over.abs() < MIN_OVERSCROLL);
+        if settled && speed < MIN_VELOCITY {
+            self.motion.stop();
+            self.motion_flag.trigger_auto();
+            if overscroll != [0.0, 0.0] {
+                self.inner.move_content(overscroll[0], overscroll[1]);
+                self.position_flag.trigger_auto();
+            }
+            return;
+        }
+        let mut delta = [0.0, 0.0];
+        for axis in 0..2 {
+            let offset = -overscroll[axis];
+            delta[axis] =
+                step_axis(&self.settings, offset, &mut velocity[axis], dt);
+        }
+        if delta != [0.0, 0.0] {
+            self.inner.move_content(delta[0], delta[1]);
+            self.position_flag.trigger_auto();
+        }
+    }
+}
+
+impl<Layout, Content> AdapterView<Layout, Content>
+where
+    Layout: AdapterLayout,
+    Content: Adaptable<Layout::ElementData>,
+{
+    fn layout(&mut self, rect: &WidgetRect) {
+        self.layout.layout(self.inner.get_interface(rect));
+    }
+
+    /// Scroll to the element passed to [`Self::scroll_to`], once the view
+    /// has been laid out.
+    fn reveal_target(&mut self, rect: &WidgetRect) {
+        if rect.width() <= 0.0 || rect.height() <= 0.0 {
+            // wait until the view has a size
+            return;
+        }
+        let Some(item) = self.scroll_target.take() else {
+            return;
+        };
+        // how far the content jumped to bring the element into the layout
+        let mut jump = [0.0, 0.0];
+        if self.inner.active_child(&item).is_none() {
+            // move the element roughly to where the reference element is,
+            // so that the layout positions it
+            let [ref_x, ref_y] = self.inner.position();
+            let Some([x, y]) =
+                self.layout.element_location(&item, [ref_x, ref_y])
+            else {
+                return;
+            };
+            jump = [ref_x - x, ref_y - y];
+            self.inner.move_content(jump[0], jump[1]);
+            self.layout(rect);
+        }
+        let Some(element) = self.inner.active_child(&item) else {
+            return;
+        };
+        // line the element up with the edge of the view it was past, as if
+        // the content had scrolled there
+        let dx = if element.left() < rect.left() || jump[0] > 0.0 {
+            rect.left() - element.left()
+        } else if element.right() > rect.right() || jump[0] < 0.0 {
+            (rect.right() - element.right()).max(rect.left() - element.left())
+        } else {
+            0.0
+        };
+        let dy = if element.top() > rect.top() || jump[1] < 0.0 {
+            rect.top() - element.top()
+        } else if element.bottom() < rect.bottom() || jump[1] > 0.0 {
+            (rect.bottom() - element.bottom()).min(rect.top() - element.top())
+        } else {
+            0.0
+        };
+        if dx != 0.0 || dy != 0.0 {
+            self.inner.move_content(dx, dy);
+            self.layout(rect);
+        }
+    }
 }
 
 impl<Layout, Content> Default for AdapterView<Layout, Content>
@@ -113,6 +409,10 @@ where
             position_flag: WatchedMeta::default(),
             layout,
             current_pointers: PointerSet::default(),
+            settings: ScrollSettings::default(),
+            motion: Motion::default(),
+            motion_flag: WatchedMeta::default(),
+            scroll_target: None,
             handle: UniqueHandle::default(),
         }
     }
@@ -129,7 +429,8 @@ where
         desc.watch(|this, rect| {
             this.position_flag.watched_auto();
             this.data_flag.watched_auto();
-            this.layout.layout(this.inner.get_interface(rect));
+            this.layout(rect);
+            this.reveal_target(rect);
         });
         desc.watch(|this, _rect| {
             let current_pointers = &mut this.current_pointers;
@@ -137,6 +438,9 @@ where
                 current_pointers.remove(pointer_id);
             });
         });
+        desc.watch(|this, _rect| {
+            this.animate();
+        });
         desc.iter_children(|this| this.inner.active_children().into());
     }
 
@@ -150,6 +454,7 @@ where
             PointerAction::Down => {
                 if self.hittest(rect, event.pos()) {
                     self.current_pointers.add_pending(event.id());
+                    self.motion.catch();
                 }
                 false
             }
@@ -160,8 +465,7 @@ where
                 self.current_pointers.add_grabbed(event.id());
                 if self.current_pointers.primary_pointer() == Some(event.id())
                 {
-                    self.inner.move_content(*x, *y);
-                    self.position_flag.trigger_auto();
+                    self.drag(*x, *y);
                 }
                 event.force_grab(self.handle.id());
                 true
@@ -189,21 +493,25 @@ where
                 if Some(event.id())
                     == self.current_pointers.primary_pointer() =>
             {
-                self.inner.move_content(*x, *y);
-                self.position_flag.trigger_auto();
+                self.drag(*x, *y);
                 true
             }
             PointerAction::Wheel(x, y)
                 if self.current_pointers.primary_pointer().is_none()
                     && self.hittest(rect, event.pos()) =>
             {
-                self.inner.move_content(*x, *y);
-                self.position_flag.trigger_auto();
+                self.scroll_by(*x, *y);
+                self.release();
                 true
             }
             PointerAction::Up => {
                 let ungrabbed = event.try_ungrab(self.handle.id());
+                let was_primary = self.current_pointers.primary_pointer()
+                    == Some(event.id());
                 self.current_pointers.remove(event.id());
+                if was_primary {
+                    self.release();
+                }
                 ungrabbed
             }
             _ => false,
@@ -5,21 +5,46 @@
 //!
 //! Apps have an associated window and "root" widgets, which are assigned
 //! to fill the whole window area.
+//!
+//! Above the root widgets, apps draw overlays: widgets such as popups,
+//! dialogs and tooltips, which are shown and dismissed by the widgets which
+//! own them.  Each overlay belongs to a [`Layer`], which decides how it is
+//! ordered and how it receives events; see [`Overlay`].
 
-use std::{cell::RefCell, rc::Rc, time};
+use std::{
+    any::Any,
+    cell::RefCell,
+    rc::{Rc, Weak},
+    time,
+};
 
 use crate::{
+    clipboard::Clipboard,
     dims::{Padding2d, Rect, SimpleRect},
+    keyboard::{Key, KeyAction, KeyEvent, TextEvent},
+    navigation::{Direction, NavAction},
     platform::RenderPlatform,
     pointer::{PointerEvent, PointerEventData},
-    widget::{self, Widget},
+    widget::{self, UniqueHandle, Widget},
 };
 
+mod drag;
+mod focus;
+mod hittest;
+mod hover;
+mod overlay;
+mod recording;
+mod shortcut;
 mod tester;
 mod values;
 
+pub use hittest::WidgetHit;
+pub(crate) use overlay::OverlayRequest;
+pub use overlay::{Layer, Overlay};
+pub use recording::{RecordedEvent, Recording};
 pub use tester::TestingExt;
 pub(crate) use values::AppState;
+pub use values::TextInputState;
 
 #[cfg(feature = "platform-opengl")]
 pub type App<P = crate::platforms::DefaultRenderPlatform> = app_struct::App<P>;
@@ -29,13 +54,11 @@ pub type App<P> = app_struct::App<P>;
 
 mod app_struct {
     use crate::{
-        pointer::PointerId,
-        watch::WatchContext,
-        widget::{AnonWidget, UniqueHandleId},
+        pointer::PointerId, watch::WatchContext, widget::UniqueHandleId,
     };
-    use std::{cell::RefCell, collections::HashMap, rc::Rc};
+    use std::{collections::HashMap, rc::Rc};
 
-    type RootHolder<P> = Rc<RefCell<dyn AnonWidget<P>>>;
+    use super::RootHolder;
 
     /// A type which contains the context in which widgets run.
     ///
@@ -47,11 +70,34 @@ mod app_struct {
         pub(crate) watch_ctx: WatchContext<'static>,
         pub(super) roots: Vec<RootHolder<P>>,
         pub(super) pointer_grab_map: HashMap<PointerId, UniqueHandleId>,
+        pub(super) hover_map: HashMap<PointerId, super::hover::Hovered>,
         pub(crate) state: Rc<super::AppState>,
         pub(super) needs_draw: bool,
+        pub(super) drag: Option<super::drag::ActiveDrag<P>>,
+        pub(super) overlays: Vec<super::overlay::ActiveOverlay<P>>,
+        pub(super) recorder: Option<super::recording::Recorder>,
+    }
+}
+
+pub(crate) type RootHolder<P> = Rc<RefCell<dyn widget::AnonWidget<P>>>;
+
+/// Identifies a root widget added to an app; see `App::remove_root`.
+#[derive(Clone, Debug)]
+pub struct RootId {
+    ptr: Weak<dyn Any>,
+}
+
+impl RootId {
+    fn is<P: ?Sized>(&self, root: &RootHolder<P>) -> bool {
+        std::ptr::addr_eq(self.ptr.as_ptr(), Rc::as_ptr(root))
     }
 }
 
+/// Creates a widget which is drawn above the root widgets, such as the
+/// visual for a drag or a popup.
+pub(crate) type OverlayFactory<P> =
+    Box<dyn FnOnce(&mut App<P>) -> RootHolder<P>>;
+
 /// Get the time recorded at the start of the frame.
 ///
 /// This will bind watch closures it is called in, and can be used to
@@ -86,7 +132,72 @@ pub fn coarse_time() -> time::Instant {
         .expect("there is no valid app state to get coarse_time from")
 }
 
-impl<P> App<P> {
+/// Get the clipboard used by the current app.
+///
+/// Returns None if called outside the context of a suzy app.
+#[must_use]
+pub fn clipboard() -> Option<Rc<dyn Clipboard>> {
+    AppState::try_with_current(AppState::clipboard)
+}
+
+/// Request that the platform start sending text events.
+///
+/// Widgets which accept typed text should call this when they gain focus,
+/// and respond to [`Content::text_event`](crate::widget::Content::text_event).
+/// The widget becomes the owner of text input, until another widget
+/// starts it.
+///
+/// This has no effect if called outside the context of a suzy app.
+pub fn start_text_input(owner: &UniqueHandle) {
+    AppState::try_with_current(|state| {
+        let owner = owner.id();
+        let previous = state.text_input_owner.replace(Some(owner.clone()));
+        let mut text_input = state.text_input.get_unwatched();
+        if previous != Some(owner) {
+            text_input.rect = None;
+        }
+        text_input.active = true;
+        state.text_input.set_if_neq_auto(text_input);
+    });
+}
+
+/// Request that the platform stop sending text events.
+///
+/// This also clears any rect set with [`set_text_input_rect`].  It has no
+/// effect unless `owner` is the widget which last started text input, so
+/// a widget losing focus does not stop input started by the widget which
+/// gained it.
+///
+/// This has no effect if called outside the context of a suzy app.
+pub fn stop_text_input(owner: &UniqueHandle) {
+    AppState::try_with_current(|state| {
+        if state.text_input_owner.borrow().as_ref() != Some(&owner.id()) {
+            return;
+        }
+        state.text_input_owner.replace(None);
+        state.text_input.set_if_neq_auto(TextInputState::default());
+    });
+}
+
+/// Indicate the area of the window text is being entered into.
+///
+/// Platforms may use this to position input method candidate windows so
+/// they do not obscure the text.  This has no effect unless `owner` is the
+/// widget which last started text input.
+///
+/// This has no effect if called outside the context of a suzy app.
+pub fn set_text_input_rect<R: Rect>(owner: &UniqueHandle, rect: &R) {
+    AppState::try_with_current(|state| {
+        if state.text_input_owner.borrow().as_ref() != Some(&owner.id()) {
+            return;
+        }
+        let mut text_input = state.text_input.get_unwatched();
+        text_input.rect = Some(SimpleRect::from(rect));
+        state.text_input.set_if_neq_auto(text_input);
+    });
+}
+
+impl<P: 'static> App<P> {
     pub fn new(width: f32, height: f32) -> Self {
         use std::collections::HashMap;
 
@@ -100,8 +211,12 @@ impl<P> App<P> {
             watch_ctx,
             roots: Vec::new(),
             pointer_grab_map: HashMap::new(),
+            hover_map: HashMap::new(),
             state,
             needs_draw: true,
+            drag: None,
+            overlays: Vec::new(),
+            recorder: None,
         }
     }
 
@@ -109,13 +224,22 @@ impl<P> App<P> {
         &self.state
     }
 
+    /// Replace the clipboard used by the app.
+    ///
+    /// Platforms call this to provide access to the system clipboard.
+    pub fn set_clipboard<C: 'static + Clipboard>(&mut self, clipboard: C) {
+        self.state.set_clipboard(Rc::new(clipboard));
+    }
+
     /// Add a root widget to the app.
     ///
     /// Root widgets are assigned a Rect representing the whole window.
     /// They are drawn in the order they are added to the app.
     /// They recieve pointer events in reverse order of when they are added to
     /// the app.
-    pub fn add_root<T>(&mut self, mut widget: Widget<T>)
+    ///
+    /// The returned id can be used to remove the root widget again.
+    pub fn add_root<T>(&mut self, mut widget: Widget<T>) -> RootId
     where
         P: RenderPlatform,
         T: widget::Content<P>,
@@ -125,13 +249,39 @@ impl<P> App<P> {
         let rect = SimpleRect::with_size(width, height);
         widget.set_fill(&rect, &Padding2d::zero());
         let holder = Rc::new(RefCell::new(widget));
-        let watcher = Rc::downgrade(&holder);
+        Widget::init(Rc::downgrade(&holder), self);
+        let ptr = Rc::downgrade(&holder);
         self.roots.push(holder);
-        Widget::init(watcher, self);
         self.needs_draw = true;
+        RootId { ptr }
+    }
+
+    /// Remove a root widget from the app, dropping it.
+    ///
+    /// Returns false if the root widget was already removed.
+    pub fn remove_root(&mut self, root: &RootId) -> bool {
+        let len = self.roots.len();
+        self.roots.retain(|holder| !root.is(holder));
+        let removed = self.roots.len() != len;
+        self.needs_draw |= removed;
+        removed
+    }
+
+    pub(crate) fn init_root<T>(&mut self, widget: Widget<T>) -> RootHolder<P>
+    where
+        P: RenderPlatform,
+        T: widget::Content<P>,
+    {
+        let holder = Rc::new(RefCell::new(widget));
+        let watcher = Rc::downgrade(&holder);
+        Widget::init(watcher, self);
+        holder
     }
 
     pub fn start_frame(&mut self, frame_time: time::Instant) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_frame(frame_time);
+        }
         self.state.frame_start.set_external(frame_time);
         let duration =
             frame_time.duration_since(self.state.coarse_time.get_unwatched());
@@ -143,6 +293,10 @@ impl<P> App<P> {
 
     pub fn update_watches(&mut self) {
         self.watch_ctx.update();
+        if self.update_overlays() {
+            // run the watch closures of the overlays which were just shown
+            self.watch_ctx.update();
+        }
         self.needs_draw = true;
     }
 
@@ -150,12 +304,16 @@ impl<P> App<P> {
     where
         P: RenderPlatform,
     {
-        for root in &mut self.roots {
+        for root in self.all_roots() {
             root.borrow_mut().draw(ctx);
         }
+        if let Some(visual) = self.drag.as_ref().and_then(|d| d.visual()) {
+            visual.borrow_mut().draw(ctx);
+        }
     }
 
     pub fn resize(&mut self, width: f32, height: f32) {
+        self.record(RecordedEvent::Resize { width, height });
         self.state.window_width.set_external(width);
         self.state.window_height.set_external(height);
         for root in &mut self.roots {
@@ -167,17 +325,134 @@ impl<P> App<P> {
     }
 
     pub fn update_dpi(&mut self, dpi: [f32; 2]) {
+        self.record(RecordedEvent::Dpi(dpi));
         self.state.dpi.set_external(dpi);
         self.needs_draw = true;
     }
 
+    /// Send a pointer event to the root widgets.
+    ///
+    /// Overlays receive the event before the root widgets, topmost first;
+    /// see [`Layer`] for how each layer handles pointer events.  While a
+    /// modal is shown, the event is treated as handled even if no widget
+    /// handles it.
+    ///
+    /// After the event is delivered, the app updates which widgets are
+    /// under the pointer; see
+    /// [`UniqueHandle::is_hovered`](crate::widget::UniqueHandle::is_hovered).
     pub fn pointer_event(&mut self, pointer: PointerEventData) -> bool {
+        self.record(RecordedEvent::Pointer(pointer));
+        if self.drag_pointer_event(&pointer) {
+            self.needs_draw = true;
+            return true;
+        }
+        self.update_overlays();
+        if self.dismiss_popups_outside(&pointer) {
+            self.update_hover(&pointer);
+            return true;
+        }
+        let roots: Vec<RootHolder<P>> = self.input_roots().cloned().collect();
         let mut event = PointerEvent::new(pointer, &mut self.pointer_grab_map);
-        let mut handled = false;
-        let mut iter = self.roots.iter_mut().rev();
-        while let (false, Some(root)) = (handled, iter.next()) {
-            handled = root.borrow_mut().pointer_event(&mut event);
+        let (_, handled) =
+            AppState::use_as_current(Rc::clone(&self.state), || {
+                let mut handled = false;
+                let mut iter = roots.iter().rev();
+                while let (false, Some(root)) = (handled, iter.next()) {
+                    handled = root.borrow_mut().pointer_event(&mut event);
+                }
+                handled
+            });
+        self.update_hover(&pointer);
+        self.begin_requested_drag();
+        self.needs_draw = true;
+        handled || self.is_modal_shown()
+    }
+
+    /// Send a key event to the root widgets.
+    ///
+    /// Like pointer events, key events are recieved by root widgets in
+    /// reverse order of when they were added to the app, until one of them
+    /// handles the event.  While a modal is shown, widgets below it do not
+    /// receive key events.
+    ///
+    /// If no widget handles a press of Tab, focus is moved to the next
+    /// widget in tab order (or the previous one, if Shift is held).  If no
+    /// widget handles a press of an arrow key, focus is moved to the nearest
+    /// widget in that direction.
+    ///
+    /// If no widget handles the event, it is checked against the
+    /// registered keyboard shortcuts; see the [`shortcut`](crate::shortcut)
+    /// module.  Widgets take precedence so that, for example, a focused
+    /// text field receives Ctrl+C even if a shortcut uses that chord.
+    /// Focus is only moved by Tab or the arrow keys if the event did not
+    /// trigger a shortcut either.
+    pub fn key_event(&mut self, event: KeyEvent) -> bool {
+        self.record(RecordedEvent::Key(event));
+        self.deliver_key_event(event)
+    }
+
+    fn deliver_key_event(&mut self, event: KeyEvent) -> bool {
+        let roots = self.input_roots();
+        let (_, handled) =
+            AppState::use_as_current(Rc::clone(&self.state), || {
+                let mut handled = false;
+                let mut iter = roots.rev();
+                while let (false, Some(root)) = (handled, iter.next()) {
+                    handled = root.borrow_mut().key_event(&event);
+                }
+                handled
+            });
+        self.needs_draw = true;
+        handled
+            || self.trigger_shortcut(&event)
+            || self.default_key_action(&event)
+    }
+
+    fn default_key_action(&mut self, event: &KeyEvent) -> bool {
+        let mods = event.modifiers;
+        if event.action != KeyAction::Down || mods.ctrl || mods.alt {
+            return false;
+        }
+        match event.key {
+            Key::Escape if self.is_dragging() => {
+                self.cancel_drag();
+                true
+            }
+            Key::Tab if mods.shift => self.focus_previous(),
+            Key::Tab => self.focus_next(),
+            key => match Direction::from_key(key) {
+                Some(direction) if !mods.shift => self.navigate(direction),
+                _ => false,
+            },
         }
+    }
+
+    /// Send a navigation action to the root widgets.
+    ///
+    /// Navigation actions are delivered to widgets as the key events for
+    /// the equivalent key; see [`NavAction::key`].  `action` indicates if
+    /// the button which triggered the navigation action was pressed or
+    /// released.
+    pub fn nav_event(&mut self, nav: NavAction, action: KeyAction) -> bool {
+        self.record(RecordedEvent::Nav(nav, action));
+        self.deliver_key_event(KeyEvent::new(action, nav.key()))
+    }
+
+    /// Send a text input event to the root widgets.
+    ///
+    /// Text events are delivered the same way as key events.
+    pub fn text_event(&mut self, event: TextEvent) -> bool {
+        self.record(RecordedEvent::Text(event.clone()));
+        let roots = self.input_roots();
+        let (_, handled) =
+            AppState::use_as_current(Rc::clone(&self.state), || {
+                let mut handled = false;
+                let mut iter = roots.rev();
+                while let (false, Some(root)) = (handled, iter.next()) {
+                    handled = root.borrow_mut().text_event(&event);
+                }
+                handled
+            });
         self.needs_draw = true;
         handled
     }
@@ -0,0 +1,196 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::any::TypeId;
+
+use crate::{
+    drag::{DragEvent, DragPayload, DragRequest},
+    pointer::{PointerAction, PointerEventData, PointerId},
+    widget::{UniqueHandleId, VisitedWidget, WidgetVisitor},
+};
+
+use super::{App, OverlayFactory, RootHolder};
+
+/// A drag which is in progress.
+pub(super) struct ActiveDrag<P: ?Sized> {
+    pointer: PointerId,
+    source: UniqueHandleId,
+    payload: DragPayload,
+    visual: Option<RootHolder<P>>,
+    target: Option<UniqueHandleId>,
+}
+
+impl<P: ?Sized> ActiveDrag<P> {
+    pub(super) fn visual(&self) -> Option<&RootHolder<P>> {
+        self.visual.as_ref()
+    }
+}
+
+/// Finds the widget which accepts a payload type at a point, and which a
+/// pointer event there would reach first.
+struct DropTargetFinder {
+    point: [f32; 2],
+    type_id: TypeId,
+    path: Vec<Option<UniqueHandleId>>,
+    found: Option<UniqueHandleId>,
+}
+
+impl WidgetVisitor for DropTargetFinder {
+    fn visit(&mut self, widget: &VisitedWidget<'_>) {
+        let hit = widget
+            .handle
+            .map(|handle| handle.id())
+            .filter(|id| id.accepts_drop(self.type_id))
+            .filter(|_| widget.contains_point());
+        self.path.push(hit);
+    }
+
+    fn leave(&mut self) {
+        // children receive pointer events before their parent, so the
+        // first target left is the one a pointer event would reach first
+        if let Some(Some(id)) = self.path.pop() {
+            self.found.get_or_insert(id);
+        }
+    }
+
+    fn query_point(&self) -> Option<[f32; 2]> {
+        Some(self.point)
+    }
+}
+
+impl<P: 'static> App<P> {
+    /// Check if a drag-and-drop is in progress.
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Cancel the drag-and-drop in progress, if any.
+    ///
+    /// The current target is sent [`DragEvent::Leave`] and the source is
+    /// sent [`DragEvent::Cancelled`].  This also happens when Escape is
+    /// pressed and not handled by any widget.
+    pub fn cancel_drag(&mut self) {
+        if let Some(drag) = self.drag.take() {
+            if let Some(target) = &drag.target {
+                target.notify_drag(DragEvent::Leave);
+            }
+            drag.source.notify_drag(DragEvent::Cancelled);
+            self.end_drag(drag.pointer);
+        }
+    }
+
+    /// Start the drag requested by a widget during the last pointer event.
+    pub(super) fn begin_requested_drag(&mut self) {
+        let request = self.state.drag_request.borrow_mut().take();
+        let Some(request) = request else {
+            return;
+        };
+        let DragRequest {
+            pointer,
+            pos,
+            source,
+            payload,
+            visual,
+        } = request;
+        let visual = visual
+            .and_then(|factory| factory.downcast::<OverlayFactory<P>>().ok())
+            .map(|factory| factory(self));
+        self.state.dragging.set(true);
+        self.drag = Some(ActiveDrag {
+            pointer,
+            source,
+            payload,
+            visual,
+            target: None,
+        });
+        self.drag_moved(pos);
+    }
+
+    /// Handle a pointer event if it belongs to the drag in progress.
+    pub(super) fn drag_pointer_event(
+        &mut self,
+        pointer: &PointerEventData,
+    ) -> bool {
+        if !self.drag.as_ref().is_some_and(|d| d.pointer == pointer.id) {
+            return false;
+        }
+        let pos = [pointer.x, pointer.y];
+        match pointer.action {
+            PointerAction::Move(_, _) | PointerAction::Hover(_, _) => {
+                self.drag_moved(pos);
+            }
+            PointerAction::Up => {
+                self.drag_moved(pos);
+                self.finish_drag(pos);
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn drag_moved(&mut self, pos: [f32; 2]) {
+        let Some(drag) = &self.drag else {
+            return;
+        };
+        if let Some(visual) = &drag.visual {
+            visual.borrow_mut().set_center(pos);
+        }
+        let mut finder = DropTargetFinder {
+            point: pos,
+            type_id: drag.payload.value_type_id(),
+            path: Vec::new(),
+            found: None,
+        };
+        for root in self.input_roots().rev() {
+            root.borrow_mut().visit(&mut finder);
+            if finder.found.is_some() {
+                break;
+            }
+        }
+        let Some(drag) = &mut self.drag else {
+            return;
+        };
+        if finder.found == drag.target {
+            if let Some(target) = &drag.target {
+                target.notify_drag(DragEvent::Over { pos });
+            }
+            return;
+        }
+        if let Some(prev) = &drag.target {
+            prev.notify_drag(DragEvent::Leave);
+        }
+        if let Some(target) = &finder.found {
+            target.notify_drag(DragEvent::Enter {
+                pos,
+                payload: drag.payload.clone(),
+            });
+        }
+        drag.target = finder.found;
+    }
+
+    fn finish_drag(&mut self, pos: [f32; 2]) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+        if let Some(target) = &drag.target {
+            target.notify_drag(DragEvent::Drop {
+                pos,
+                payload: drag.payload,
+            });
+            drag.source.notify_drag(DragEvent::Completed);
+        } else {
+            drag.source.notify_drag(DragEvent::Cancelled);
+        }
+        self.end_drag(drag.pointer);
+    }
+
+    fn end_drag(&mut self, pointer: PointerId) {
+        let drag_id = self.state.drag_handle.id();
+        if self.pointer_grab_map.get(&pointer) == Some(&drag_id) {
+            self.pointer_grab_map.remove(&pointer);
+        }
+        self.state.dragging.set(false);
+        self.needs_draw = true;
+    }
+}
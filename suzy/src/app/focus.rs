@@ -0,0 +1,192 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use crate::{
+    dims::SimpleRect,
+    navigation::Direction,
+    widget::{UniqueHandleId, VisitedWidget, WidgetVisitor},
+};
+
+use super::App;
+
+/// Collects the focusable widgets which participate in tab traversal.
+#[derive(Default)]
+struct TabOrderCollector {
+    entries: Vec<(i32, UniqueHandleId)>,
+}
+
+impl WidgetVisitor for TabOrderCollector {
+    fn visit(&mut self, widget: &VisitedWidget<'_>) {
+        if let Some(handle) = widget.handle {
+            let tab_index = handle.tab_index();
+            if handle.is_focusable() && !tab_index.is_negative() {
+                self.entries.push((tab_index, handle.id()));
+            }
+        }
+    }
+}
+
+/// Collects the focusable widgets which participate in directional
+/// navigation, along with their positions.
+#[derive(Default)]
+struct NavCandidateCollector {
+    entries: Vec<(SimpleRect, UniqueHandleId)>,
+}
+
+impl WidgetVisitor for NavCandidateCollector {
+    fn visit(&mut self, widget: &VisitedWidget<'_>) {
+        if let Some(handle) = widget.handle {
+            if handle.is_focusable() {
+                self.entries.push((widget.rect.into(), handle.id()));
+            }
+        }
+    }
+}
+
+/// Finds the handles of the focused widget and its ancestors.
+struct FocusPathCollector {
+    focused: UniqueHandleId,
+    stack: Vec<Option<UniqueHandleId>>,
+    path: Option<Vec<UniqueHandleId>>,
+}
+
+impl WidgetVisitor for FocusPathCollector {
+    fn visit(&mut self, widget: &VisitedWidget<'_>) {
+        let id = widget.handle.map(|handle| handle.id());
+        let is_focused = id.as_ref() == Some(&self.focused);
+        self.stack.push(id);
+        if is_focused && self.path.is_none() {
+            self.path = Some(self.stack.iter().flatten().cloned().collect());
+        }
+    }
+
+    fn leave(&mut self) {
+        self.stack.pop();
+    }
+}
+
+impl<P> App<P> {
+    /// Get the id of the currently focused widget, if any.
+    #[must_use]
+    pub fn focused(&self) -> Option<UniqueHandleId> {
+        self.state.focused()
+    }
+
+    /// Remove focus from whichever widget currently has it.
+    pub fn clear_focus(&mut self) {
+        self.state.set_focus(None);
+        self.needs_draw = true;
+    }
+
+    /// Move focus to the next widget in tab order, as if the user pressed
+    /// Tab.
+    ///
+    /// Returns false if there are no widgets which can be focused this way.
+    pub fn focus_next(&mut self) -> bool {
+        self.move_focus(false)
+    }
+
+    /// Move focus to the previous widget in tab order, as if the user
+    /// pressed Shift+Tab.
+    ///
+    /// Returns false if there are no widgets which can be focused this way.
+    pub fn focus_previous(&mut self) -> bool {
+        self.move_focus(true)
+    }
+
+    /// Move focus to the nearest widget in a direction, as if the user
+    /// pressed an arrow key.
+    ///
+    /// If no widget is currently focused, this focuses the first widget in
+    /// tab order instead.
+    ///
+    /// Returns false if there is no widget to move focus to.
+    pub fn navigate(&mut self, direction: Direction) -> bool {
+        let focused = match self.state.focused() {
+            Some(focused) => focused,
+            None => return self.focus_next(),
+        };
+        if let Some(target) = focused.nav_target(direction) {
+            if target.is_focusable() {
+                self.state.set_focus(Some(target));
+                self.needs_draw = true;
+                return true;
+            }
+        }
+        let mut collector = NavCandidateCollector::default();
+        for root in self.input_roots() {
+            root.borrow_mut().visit(&mut collector);
+        }
+        let candidates = collector.entries;
+        let current = match candidates.iter().find(|(_, id)| *id == focused) {
+            Some((rect, _)) => *rect,
+            None => return self.focus_next(),
+        };
+        let best = candidates
+            .into_iter()
+            .filter(|(_, id)| *id != focused)
+            .filter_map(|(rect, id)| {
+                direction.score(&current, &rect).map(|score| (score, id))
+            })
+            .min_by(|(a, _), (b, _)| a.total_cmp(b));
+        match best {
+            Some((_, target)) => {
+                self.state.set_focus(Some(target));
+                self.needs_draw = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the handles of the focused widget and its ancestors, outermost
+    /// first.
+    ///
+    /// Returns None if no widget is focused, or if the focused widget can
+    /// not currently receive key events, such as below a modal.
+    pub(super) fn focus_path(&self) -> Option<Vec<UniqueHandleId>> {
+        let mut collector = FocusPathCollector {
+            focused: self.state.focused()?,
+            stack: Vec::new(),
+            path: None,
+        };
+        for root in self.input_roots() {
+            root.borrow_mut().visit(&mut collector);
+        }
+        collector.path
+    }
+
+    fn move_focus(&mut self, reverse: bool) -> bool {
+        let mut order = self.tab_order();
+        let len = order.len();
+        if len == 0 {
+            return false;
+        }
+        let current = self
+            .state
+            .focused()
+            .and_then(|focused| order.iter().position(|id| *id == focused));
+        let index = match (current, reverse) {
+            (None, false) => 0,
+            (None, true) => len - 1,
+            (Some(current), false) => (current + 1) % len,
+            (Some(current), true) => (current + len - 1) % len,
+        };
+        self.state.set_focus(Some(order.swap_remove(index)));
+        self.needs_draw = true;
+        true
+    }
+
+    fn tab_order(&mut self) -> Vec<UniqueHandleId> {
+        let mut collector = TabOrderCollector::default();
+        for root in self.input_roots() {
+            root.borrow_mut().visit(&mut collector);
+        }
+        // positive tab indices come first, in increasing order, followed by
+        // zeros; the sort is stable, so ties keep their described order
+        collector
+            .entries
+            .sort_by_key(|&(tab_index, _)| (tab_index == 0_i32, tab_index));
+        collector.entries.into_iter().map(|(_, id)| id).collect()
+    }
+}
@@ -0,0 +1,84 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use crate::widget::{
+    UniqueHandleId, VisitedWidget, WidgetRect, WidgetVisitor,
+};
+
+use super::App;
+
+/// A widget found by `App::widgets_at`.
+#[non_exhaustive]
+pub struct WidgetHit {
+    /// The name of the widget's content type.
+    pub type_name: &'static str,
+
+    /// The id of the widget's handle, if its content has one; see
+    /// [`Content::handle`](crate::widget::Content::handle).
+    pub handle: Option<UniqueHandleId>,
+
+    /// A copy of the widget's rectangle, in its own coordinate space.
+    pub rect: WidgetRect,
+}
+
+impl std::fmt::Debug for WidgetHit {
+    fn fmt(&self, fmtter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmtter
+            .debug_struct("WidgetHit")
+            .field("type_name", &self.type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Collects the widgets whose shape contains a point, in the order pointer
+/// events would reach them.
+struct HitCollector {
+    point: [f32; 2],
+    pending: Vec<Option<WidgetHit>>,
+    found: Vec<WidgetHit>,
+}
+
+impl WidgetVisitor for HitCollector {
+    fn visit(&mut self, widget: &VisitedWidget<'_>) {
+        let hit = widget.contains_point().then(|| WidgetHit {
+            type_name: widget.type_name,
+            handle: widget.handle.map(|handle| handle.id()),
+            rect: WidgetRect::from_rect(widget.rect),
+        });
+        self.pending.push(hit);
+    }
+
+    fn leave(&mut self) {
+        // children receive pointer events before their parent
+        if let Some(Some(hit)) = self.pending.pop() {
+            self.found.push(hit);
+        }
+    }
+
+    fn query_point(&self) -> Option<[f32; 2]> {
+        Some(self.point)
+    }
+}
+
+impl<P> App<P> {
+    /// Find the widgets whose [`hittest`](crate::widget::Content::hittest)
+    /// contains a point, without sending an event.
+    ///
+    /// Widgets are returned in the order a pointer event at that point
+    /// would be offered to them: overlays first, topmost first, then root
+    /// widgets in reverse order of when they were added, and children
+    /// before their parents.  Widgets which would not receive the event,
+    /// such as those below a modal, are not included.
+    #[must_use]
+    pub fn widgets_at(&self, point: [f32; 2]) -> Vec<WidgetHit> {
+        let mut collector = HitCollector {
+            point,
+            pending: Vec::new(),
+            found: Vec::new(),
+        };
+        for root in self.input_roots().rev() {
+            root.borrow_mut().visit(&mut collector);
+        }
+        collector.found
+    }
+}
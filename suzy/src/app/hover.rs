@@ -0,0 +1,125 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use crate::{
+    pointer::{PointerAction, PointerEventData, PointerId},
+    widget::{UniqueHandleId, VisitedWidget, WidgetVisitor},
+};
+
+use super::{App, RecordedEvent};
+
+/// The widgets a pointer is over, and where it was when they were found.
+pub(super) struct Hovered {
+    pos: [f32; 2],
+    widgets: Vec<UniqueHandleId>,
+}
+
+/// Finds the widget with a handle which a pointer event at a point would
+/// reach first, along with its ancestors which also contain the point.
+struct HoverCollector {
+    point: [f32; 2],
+    path: Vec<Option<UniqueHandleId>>,
+    found: Option<Vec<UniqueHandleId>>,
+}
+
+impl WidgetVisitor for HoverCollector {
+    fn visit(&mut self, widget: &VisitedWidget<'_>) {
+        let hit = widget
+            .handle
+            .filter(|_| widget.contains_point())
+            .map(|handle| handle.id());
+        self.path.push(hit);
+    }
+
+    fn leave(&mut self) {
+        // children receive pointer events before their parent, so the
+        // first hit left is the one a pointer event would reach first
+        if let Some(Some(id)) = self.path.pop() {
+            if self.found.is_none() {
+                let ancestors = self.path.iter().flatten().cloned();
+                self.found =
+                    Some(std::iter::once(id).chain(ancestors).collect());
+            }
+        }
+    }
+
+    fn query_point(&self) -> Option<[f32; 2]> {
+        Some(self.point)
+    }
+}
+
+impl<P> App<P> {
+    /// Notify the app that a pointer is no longer over the window, e.g.
+    /// because the mouse moved outside of it.
+    ///
+    /// Every widget the pointer was over will stop being hovered by it.
+    pub fn pointer_leave(&mut self, pointer: PointerId) {
+        self.record(RecordedEvent::PointerLeave(pointer));
+        self.set_hovered(pointer, None);
+    }
+
+    /// Update which widgets are under a pointer after a pointer event.
+    ///
+    /// Only the widget a pointer event would reach first, and its
+    /// ancestors, are hovered; widgets covered by it or by a higher root
+    /// are not.  Hover and move events which leave the pointer where it
+    /// was do not search the widget tree again.
+    pub(super) fn update_hover(&mut self, pointer: &PointerEventData) {
+        let pos = [pointer.x, pointer.y];
+        let gone = match pointer.action {
+            // touches are only over the window while they are held down
+            PointerAction::Up => pointer.id != PointerId::Mouse,
+            _ => false,
+        };
+        if gone || pos[0].is_nan() || pos[1].is_nan() {
+            self.set_hovered(pointer.id, None);
+            return;
+        }
+        let stationary = matches!(
+            pointer.action,
+            PointerAction::Hover(..) | PointerAction::Move(..)
+        ) && self
+            .hover_map
+            .get(&pointer.id)
+            .is_some_and(|hovered| hovered.pos == pos);
+        if stationary {
+            return;
+        }
+        let mut collector = HoverCollector {
+            point: pos,
+            path: Vec::new(),
+            found: None,
+        };
+        for root in self.input_roots().rev() {
+            root.borrow_mut().visit(&mut collector);
+            if collector.found.is_some() {
+                break;
+            }
+        }
+        let widgets = collector.found.unwrap_or_default();
+        self.set_hovered(pointer.id, Some(Hovered { pos, widgets }));
+    }
+
+    fn set_hovered(&mut self, pointer: PointerId, current: Option<Hovered>) {
+        let previous = match current {
+            Some(current) => self.hover_map.insert(pointer, current),
+            None => self.hover_map.remove(&pointer),
+        };
+        let previous = previous.map_or_else(Vec::new, |prev| prev.widgets);
+        let current = self
+            .hover_map
+            .get(&pointer)
+            .map_or(&[][..], |hovered| &hovered.widgets[..]);
+        for id in &previous {
+            if !current.contains(id) {
+                id.pointer_over(false);
+            }
+        }
+        for id in current {
+            if !previous.contains(id) {
+                id.pointer_over(true);
+            }
+        }
+        self.needs_draw = true;
+    }
+}
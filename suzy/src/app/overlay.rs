@@ -0,0 +1,314 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{
+    any::Any,
+    cell::{Cell, Ref, RefCell, RefMut},
+    rc::Rc,
+};
+
+use crate::{
+    platform::RenderPlatform,
+    pointer::{PointerAction, PointerEventData},
+    watch::DefaultOwner,
+    widget::{self, Widget},
+};
+
+use super::{App, AppState, OverlayFactory, RootHolder};
+
+type WatchedCellCore<T> =
+    crate::watch::WatchedCellCore<'static, T, DefaultOwner>;
+
+/// The layers an app draws widgets in, from the bottom up.
+///
+/// Within a layer, overlays shown later are drawn above ones shown
+/// earlier.  Pointer events are offered to the topmost widgets first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// The layer of the root widgets.  Overlays in this layer are drawn
+    /// above the root widgets, and otherwise behave the same way.
+    Base,
+
+    /// Popups, such as menus and the list of a dropdown.  Pressing a
+    /// pointer outside of a popup dismisses it, and the press is not
+    /// delivered to any widget.
+    ///
+    /// Popups shown while a modal is shown belong to it, and are drawn
+    /// above it.
+    Popup,
+
+    /// Modals, such as dialogs.  While a modal is shown, widgets below it
+    /// do not receive pointer, key or text events, and can not be focused
+    /// with Tab or the arrow keys, wherever the pointer is.  A widget below
+    /// the modal loses focus when it is shown, and global shortcuts do not
+    /// trigger until it is dismissed.
+    Modal,
+
+    /// Tooltips, which are drawn above everything else, and never receive
+    /// pointer events.
+    Tooltip,
+}
+
+/// The state an overlay shares with the app showing it.
+struct OverlayState {
+    layer: Layer,
+    shown: WatchedCellCore<bool>,
+    initialized: Cell<bool>,
+}
+
+/// A request to show an overlay, made from inside a widget.
+pub(crate) struct OverlayRequest {
+    state: Rc<OverlayState>,
+    factory: Box<dyn Any>,
+}
+
+/// An overlay the app is currently showing.
+pub(super) struct ActiveOverlay<P: ?Sized> {
+    state: Rc<OverlayState>,
+    widget: RootHolder<P>,
+}
+
+/// A widget which is drawn in one of the app's [`Layer`]s while it is
+/// shown.
+///
+/// An overlay is owned by the widget which shows it, usually as a field of
+/// its content; it can be shown and dismissed from that widget's watch
+/// closures and event handlers.  The owner is also responsible for
+/// positioning the overlay, through [`Overlay::widget_mut`].  When the
+/// owner is dropped, the overlay is dismissed.
+///
+/// The overlay's widget is not initialized until it is first shown, and
+/// keeps its state while it is dismissed.
+pub struct Overlay<T> {
+    widget: Rc<RefCell<Widget<T>>>,
+    state: Rc<OverlayState>,
+}
+
+impl<T: Default> Default for Overlay<T> {
+    /// Create an overlay in the [`Layer::Popup`] layer.
+    fn default() -> Self {
+        Self::new(Layer::Popup)
+    }
+}
+
+impl<T> Overlay<T> {
+    /// Create an overlay in a layer, with default content.
+    #[must_use]
+    pub fn new(layer: Layer) -> Self
+    where
+        T: Default,
+    {
+        Self::with_widget(layer, Widget::default())
+    }
+
+    /// Create an overlay in a layer, which will show a widget.
+    #[must_use]
+    pub fn with_widget(layer: Layer, widget: Widget<T>) -> Self {
+        Self {
+            widget: Rc::new(RefCell::new(widget)),
+            state: Rc::new(OverlayState {
+                layer,
+                shown: WatchedCellCore::new(false),
+                initialized: Cell::new(false),
+            }),
+        }
+    }
+
+    /// Get the layer this overlay is shown in.
+    #[must_use]
+    pub fn layer(&self) -> Layer {
+        self.state.layer
+    }
+
+    /// Check if the overlay is shown.
+    ///
+    /// This will bind a current watch function to the state of the
+    /// overlay, so it re-runs when the overlay is dismissed, including by a
+    /// press outside of a popup.
+    #[must_use]
+    pub fn is_shown(&self) -> bool {
+        self.state.shown.get_auto()
+    }
+
+    /// Request that the app show the overlay.
+    ///
+    /// The overlay appears when the app next updates its watches.  This has
+    /// no effect if the overlay is already shown, or if called outside the
+    /// context of a suzy app.
+    pub fn show<P>(&self)
+    where
+        P: 'static + RenderPlatform,
+        T: widget::Content<P>,
+    {
+        if self.state.shown.get_unwatched() {
+            return;
+        }
+        let widget = Rc::clone(&self.widget);
+        let state = Rc::clone(&self.state);
+        let factory: OverlayFactory<P> = Box::new(move |app| {
+            if !state.initialized.replace(true) {
+                Widget::init(Rc::downgrade(&widget), app);
+            }
+            widget
+        });
+        let request = OverlayRequest {
+            state: Rc::clone(&self.state),
+            factory: Box::new(factory),
+        };
+        AppState::try_with_current(|app_state| {
+            app_state.overlay_requests.borrow_mut().push(request);
+            self.state.shown.set_auto(true);
+        });
+    }
+
+    /// Hide the overlay.
+    pub fn dismiss(&self) {
+        if self.state.shown.get_unwatched() {
+            self.state.shown.set_auto(false);
+        }
+    }
+
+    /// Get a reference to the overlay's widget.
+    ///
+    /// # Panics
+    /// Panics if called from an event handler of the overlay's own widget.
+    pub fn widget(&self) -> Ref<'_, Widget<T>> {
+        self.widget.borrow()
+    }
+
+    /// Get a mutable reference to the overlay's widget.
+    ///
+    /// # Panics
+    /// Panics if called from an event handler of the overlay's own widget.
+    pub fn widget_mut(&self) -> RefMut<'_, Widget<T>> {
+        self.widget.borrow_mut()
+    }
+}
+
+impl<T> Drop for Overlay<T> {
+    fn drop(&mut self) {
+        self.state.shown.set_external(false);
+    }
+}
+
+impl<P: ?Sized> App<P> {
+    /// The root widgets, followed by the overlays shown above them, in the
+    /// order they are drawn.
+    pub(super) fn all_roots(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &RootHolder<P>> {
+        let overlays = self.overlays.iter().map(|overlay| &overlay.widget);
+        self.roots.iter().chain(overlays)
+    }
+
+    /// The root widgets and overlays which can receive pointer events and
+    /// focus, in the order they are drawn.
+    ///
+    /// If a modal is shown, this is only the topmost modal and the popups
+    /// above it.
+    pub(super) fn input_roots(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &RootHolder<P>> {
+        let modal = self
+            .overlays
+            .iter()
+            .rposition(|overlay| overlay.state.layer == Layer::Modal);
+        let (roots, overlays) = match modal {
+            Some(index) => (&self.roots[..0], &self.overlays[index..]),
+            None => (&self.roots[..], &self.overlays[..]),
+        };
+        let overlays = overlays
+            .iter()
+            .filter(|overlay| overlay.state.layer != Layer::Tooltip)
+            .map(|overlay| &overlay.widget);
+        roots.iter().chain(overlays)
+    }
+
+    /// Check if a modal is shown, which keeps pointer events, keys and
+    /// global shortcuts from reaching the widgets below it.
+    pub(super) fn is_modal_shown(&self) -> bool {
+        self.overlays
+            .iter()
+            .any(|overlay| overlay.state.layer == Layer::Modal)
+    }
+}
+
+impl<P: 'static> App<P> {
+    /// Add the overlays requested since the last update, and remove the
+    /// ones which were dismissed.
+    ///
+    /// Returns true if any new overlay was initialized.
+    pub(super) fn update_overlays(&mut self) -> bool {
+        self.overlays
+            .retain(|overlay| overlay.state.shown.get_unwatched());
+        let requests = self.state.overlay_requests.take();
+        let mut initialized = false;
+        let mut modal_shown = false;
+        for OverlayRequest { state, factory } in requests {
+            let already_active = self
+                .overlays
+                .iter()
+                .any(|overlay| Rc::ptr_eq(&overlay.state, &state));
+            if !state.shown.get_unwatched() || already_active {
+                continue;
+            }
+            let Ok(factory) = factory.downcast::<OverlayFactory<P>>() else {
+                continue;
+            };
+            initialized |= !state.initialized.get();
+            let widget = factory(self);
+            let layer = state.layer;
+            let is_above = |other: Layer| match (layer, other) {
+                // popups shown while a modal is shown belong to it
+                (Layer::Popup, Layer::Modal) => false,
+                _ => other > layer,
+            };
+            let index = self
+                .overlays
+                .iter()
+                .position(|overlay| is_above(overlay.state.layer))
+                .unwrap_or(self.overlays.len());
+            self.overlays.insert(index, ActiveOverlay { state, widget });
+            self.needs_draw = true;
+            modal_shown |= layer == Layer::Modal;
+        }
+        if modal_shown && self.focus_path().is_none() {
+            // the focused widget is below the modal
+            self.state.set_focus(None);
+        }
+        initialized
+    }
+
+    /// Dismiss the popups a pointer was pressed outside of.
+    ///
+    /// Returns true if any popup was dismissed, in which case the press
+    /// should not reach any other widget.
+    pub(super) fn dismiss_popups_outside(
+        &mut self,
+        pointer: &PointerEventData,
+    ) -> bool {
+        if !matches!(pointer.action, PointerAction::Down) {
+            return false;
+        }
+        let point = [pointer.x, pointer.y];
+        let mut dismissed = false;
+        // popups above the one which was pressed are dismissed, up to the
+        // first overlay which is not a popup
+        for overlay in self.overlays.iter().rev() {
+            match overlay.state.layer {
+                Layer::Tooltip => continue,
+                Layer::Popup if !overlay.widget.borrow().contains(point) => {
+                    overlay.state.shown.set_external(false);
+                    dismissed = true;
+                }
+                _ => break,
+            }
+        }
+        if dismissed {
+            self.overlays
+                .retain(|overlay| overlay.state.shown.get_unwatched());
+            self.needs_draw = true;
+        }
+        dismissed
+    }
+}
@@ -0,0 +1,627 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{
+    io::{self, BufRead, Write},
+    time,
+};
+
+use crate::{
+    keyboard::{Key, KeyAction, KeyEvent, Modifiers, ScanCode, TextEvent},
+    navigation::{Direction, NavAction},
+    pointer::{AltMouseButton, PointerAction, PointerEventData, PointerId},
+};
+
+use super::App;
+
+const HEADER: &str = "suzy-recording 1";
+
+/// An input event captured by `App::start_recording`.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum RecordedEvent {
+    /// A new frame was started, this long after the previous one.
+    Frame(time::Duration),
+
+    /// A pointer event was sent to the app.
+    Pointer(PointerEventData),
+
+    /// The window was resized.
+    Resize {
+        /// The new width of the window.
+        width: f32,
+        /// The new height of the window.
+        height: f32,
+    },
+
+    /// The DPI of the window changed.
+    Dpi([f32; 2]),
+
+    /// A pointer left the window.
+    PointerLeave(PointerId),
+
+    /// A key event was sent to the app.
+    Key(KeyEvent),
+
+    /// A text input event was sent to the app.
+    Text(TextEvent),
+
+    /// A navigation action was sent to the app.
+    Nav(NavAction, KeyAction),
+}
+
+/// A sequence of input events captured from an app, which can be saved to
+/// a file and replayed with
+/// [`TestingExt::replay`](crate::app::TestingExt::replay).
+///
+/// Recordings are saved in a compact text format, with one event per line.
+#[derive(Clone, Debug, Default)]
+pub struct Recording {
+    events: Vec<RecordedEvent>,
+}
+
+impl Recording {
+    /// Get the recorded events, in order.
+    #[must_use]
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Write the recording to a file, or other writer.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{HEADER}")?;
+        for event in &self.events {
+            match event {
+                RecordedEvent::Frame(duration) => {
+                    writeln!(writer, "f {}", duration.as_nanos())?;
+                }
+                RecordedEvent::Pointer(data) => {
+                    writeln!(
+                        writer,
+                        "p {} {} {} {}{}",
+                        write_id(data.id),
+                        data.x,
+                        data.y,
+                        write_action(data.action),
+                        write_details(data),
+                    )?;
+                }
+                RecordedEvent::Resize { width, height } => {
+                    writeln!(writer, "r {width} {height}")?;
+                }
+                RecordedEvent::Dpi([x, y]) => {
+                    writeln!(writer, "d {x} {y}")?;
+                }
+                RecordedEvent::PointerLeave(id) => {
+                    writeln!(writer, "l {}", write_id(*id))?;
+                }
+                RecordedEvent::Key(event) => {
+                    writeln!(
+                        writer,
+                        "k {} {}{}",
+                        write_key_action(event.action),
+                        write_key(event.key),
+                        write_key_details(event),
+                    )?;
+                }
+                RecordedEvent::Text(TextEvent::Input(text)) => {
+                    writeln!(writer, "t input {}", write_text(text))?;
+                }
+                RecordedEvent::Text(TextEvent::Editing { text, cursor }) => {
+                    writeln!(
+                        writer,
+                        "t edit {} {} {}",
+                        write_text(text),
+                        cursor.start,
+                        cursor.end,
+                    )?;
+                }
+                RecordedEvent::Nav(nav, action) => {
+                    writeln!(
+                        writer,
+                        "n {} {}",
+                        write_key_action(*action),
+                        write_nav(*nav),
+                    )?;
+                }
+            }
+        }
+        writer.flush()
+    }
+
+    /// Read a recording previously written with [`Recording::write_to`].
+    pub fn read_from<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut lines = reader.lines();
+        if lines.next().transpose()?.as_deref() != Some(HEADER) {
+            return Err(invalid("not a suzy recording"));
+        }
+        let mut events = Vec::new();
+        for line in lines {
+            let line = line?;
+            let mut tokens = line.split_whitespace();
+            let event = match tokens.next() {
+                None => continue,
+                Some("f") => {
+                    let nanos: u64 = parse(tokens.next())?;
+                    RecordedEvent::Frame(time::Duration::from_nanos(nanos))
+                }
+                Some("p") => {
+                    let id = read_id(&mut tokens)?;
+                    let x = parse(tokens.next())?;
+                    let y = parse(tokens.next())?;
+                    let action = read_action(&mut tokens)?;
+                    let mut data = PointerEventData::new(id, action, x, y);
+                    for detail in &mut tokens {
+                        read_detail(&mut data, detail)?;
+                    }
+                    RecordedEvent::Pointer(data)
+                }
+                Some("r") => RecordedEvent::Resize {
+                    width: parse(tokens.next())?,
+                    height: parse(tokens.next())?,
+                },
+                Some("d") => RecordedEvent::Dpi([
+                    parse(tokens.next())?,
+                    parse(tokens.next())?,
+                ]),
+                Some("l") => {
+                    RecordedEvent::PointerLeave(read_id(&mut tokens)?)
+                }
+                Some("k") => {
+                    let action = read_key_action(tokens.next())?;
+                    let key = read_key(&mut tokens)?;
+                    let mut event = KeyEvent::new(action, key);
+                    for detail in &mut tokens {
+                        read_key_detail(&mut event, detail)?;
+                    }
+                    RecordedEvent::Key(event)
+                }
+                Some("t") => {
+                    RecordedEvent::Text(read_text_event(&mut tokens)?)
+                }
+                Some("n") => {
+                    let action = read_key_action(tokens.next())?;
+                    RecordedEvent::Nav(read_nav(&mut tokens)?, action)
+                }
+                Some(other) => {
+                    return Err(invalid(format!("unknown event: {other}")));
+                }
+            };
+            if let Some(extra) = tokens.next() {
+                return Err(invalid(format!("unexpected token: {extra}")));
+            }
+            events.push(event);
+        }
+        Ok(Self { events })
+    }
+}
+
+/// Captures events while an app is recording.
+pub(super) struct Recorder {
+    last_frame: time::Instant,
+    recording: Recording,
+}
+
+impl Recorder {
+    pub(super) fn record(&mut self, event: RecordedEvent) {
+        self.recording.events.push(event);
+    }
+
+    pub(super) fn record_frame(&mut self, frame_time: time::Instant) {
+        let duration = frame_time.saturating_duration_since(self.last_frame);
+        self.last_frame = frame_time;
+        self.record(RecordedEvent::Frame(duration));
+    }
+}
+
+impl<P> App<P> {
+    /// Start recording the input events the app receives, and the timing
+    /// of its frames.
+    ///
+    /// If the app was already recording, the previous recording is
+    /// discarded.
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(Recorder {
+            last_frame: self.state.frame_start.get_unwatched(),
+            recording: Recording::default(),
+        });
+    }
+
+    /// Stop recording, and return the recording, if the app was recording.
+    pub fn stop_recording(&mut self) -> Option<Recording> {
+        self.recorder.take().map(|recorder| recorder.recording)
+    }
+
+    /// Check if the app is currently recording.
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    pub(super) fn record(&mut self, event: RecordedEvent) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(event);
+        }
+    }
+}
+
+fn invalid<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn parse<T: std::str::FromStr>(token: Option<&str>) -> io::Result<T> {
+    let token = token.ok_or_else(|| invalid("unexpected end of line"))?;
+    token
+        .parse()
+        .map_err(|_| invalid(format!("invalid value: {token}")))
+}
+
+fn write_id(id: PointerId) -> String {
+    match id {
+        PointerId::Mouse => "mouse".to_string(),
+        PointerId::Touch(index) => format!("touch {index}"),
+        PointerId::Other(index) => format!("other {index}"),
+    }
+}
+
+fn read_id<'a, I>(tokens: &mut I) -> io::Result<PointerId>
+where
+    I: Iterator<Item = &'a str>,
+{
+    match tokens.next() {
+        Some("mouse") => Ok(PointerId::Mouse),
+        Some("touch") => Ok(PointerId::Touch(parse(tokens.next())?)),
+        Some("other") => Ok(PointerId::Other(parse(tokens.next())?)),
+        other => Err(invalid(format!("invalid pointer id: {other:?}"))),
+    }
+}
+
+fn write_action(action: PointerAction) -> String {
+    match action {
+        PointerAction::Down => "down".to_string(),
+        PointerAction::Move(dx, dy) => format!("move {dx} {dy}"),
+        PointerAction::Up => "up".to_string(),
+        PointerAction::Wheel(dx, dy) => format!("wheel {dx} {dy}"),
+        PointerAction::AltDown(button) => {
+            format!("altdown {}", write_button(button))
+        }
+        PointerAction::AltUp(button) => {
+            format!("altup {}", write_button(button))
+        }
+        PointerAction::Hover(dx, dy) => format!("hover {dx} {dy}"),
+    }
+}
+
+fn read_action<'a, I>(tokens: &mut I) -> io::Result<PointerAction>
+where
+    I: Iterator<Item = &'a str>,
+{
+    match tokens.next() {
+        Some("down") => Ok(PointerAction::Down),
+        Some("move") => Ok(PointerAction::Move(
+            parse(tokens.next())?,
+            parse(tokens.next())?,
+        )),
+        Some("up") => Ok(PointerAction::Up),
+        Some("wheel") => Ok(PointerAction::Wheel(
+            parse(tokens.next())?,
+            parse(tokens.next())?,
+        )),
+        Some("altdown") => Ok(PointerAction::AltDown(read_button(tokens)?)),
+        Some("altup") => Ok(PointerAction::AltUp(read_button(tokens)?)),
+        Some("hover") => Ok(PointerAction::Hover(
+            parse(tokens.next())?,
+            parse(tokens.next())?,
+        )),
+        other => Err(invalid(format!("invalid pointer action: {other:?}"))),
+    }
+}
+
+/// Write the optional details of a pointer event which differ from the
+/// defaults, as `key=value` tokens.
+fn write_details(data: &PointerEventData) -> String {
+    let mut details = write_modifiers(data.modifiers);
+    if data.click_count != 1 {
+        details += &format!(" clicks={}", data.click_count);
+    }
+    if let Some(pressure) = data.pressure {
+        details += &format!(" pressure={pressure}");
+    }
+    if let Some([x, y]) = data.tilt {
+        details += &format!(" tilt={x},{y}");
+    }
+    if let Some(timestamp) = data.timestamp {
+        details += &format!(" time={}", timestamp.as_nanos());
+    }
+    details
+}
+
+fn read_detail(data: &mut PointerEventData, detail: &str) -> io::Result<()> {
+    let (key, value) = detail
+        .split_once('=')
+        .ok_or_else(|| invalid(format!("unexpected token: {detail}")))?;
+    match key {
+        "mods" => data.modifiers = read_modifiers(value)?,
+        "clicks" => data.click_count = parse(Some(value))?,
+        "pressure" => data.pressure = Some(parse(Some(value))?),
+        "tilt" => {
+            let mut axes = value.split(',');
+            let tilt = [parse(axes.next())?, parse(axes.next())?];
+            if axes.next().is_some() {
+                return Err(invalid(format!("invalid tilt: {value}")));
+            }
+            data.tilt = Some(tilt);
+        }
+        "time" => {
+            let nanos = parse(Some(value))?;
+            data.timestamp = Some(time::Duration::from_nanos(nanos));
+        }
+        _ => return Err(invalid(format!("unknown detail: {key}"))),
+    }
+    Ok(())
+}
+
+fn write_button(button: AltMouseButton) -> &'static str {
+    match button {
+        AltMouseButton::Middle => "middle",
+        AltMouseButton::Right => "right",
+        AltMouseButton::X1 => "x1",
+        AltMouseButton::X2 => "x2",
+    }
+}
+
+fn read_button<'a, I>(tokens: &mut I) -> io::Result<AltMouseButton>
+where
+    I: Iterator<Item = &'a str>,
+{
+    match tokens.next() {
+        Some("middle") => Ok(AltMouseButton::Middle),
+        Some("right") => Ok(AltMouseButton::Right),
+        Some("x1") => Ok(AltMouseButton::X1),
+        Some("x2") => Ok(AltMouseButton::X2),
+        other => Err(invalid(format!("invalid mouse button: {other:?}"))),
+    }
+}
+
+/// Write the modifier keys held during an event as a `mods=` token, if any
+/// are held.
+fn write_modifiers(mods: Modifiers) -> String {
+    if mods.is_empty() {
+        return String::new();
+    }
+    let names = [
+        (mods.shift, "shift"),
+        (mods.ctrl, "ctrl"),
+        (mods.alt, "alt"),
+        (mods.logo, "logo"),
+    ];
+    let held: Vec<_> = names
+        .iter()
+        .filter(|(held, _)| *held)
+        .map(|(_, name)| *name)
+        .collect();
+    format!(" mods={}", held.join("+"))
+}
+
+fn read_modifiers(value: &str) -> io::Result<Modifiers> {
+    let mut mods = Modifiers::NONE;
+    for name in value.split('+') {
+        let held = match name {
+            "shift" => &mut mods.shift,
+            "ctrl" => &mut mods.ctrl,
+            "alt" => &mut mods.alt,
+            "logo" => &mut mods.logo,
+            _ => return Err(invalid(format!("invalid modifier: {name}"))),
+        };
+        *held = true;
+    }
+    Ok(mods)
+}
+
+fn write_key_action(action: KeyAction) -> &'static str {
+    match action {
+        KeyAction::Down => "down",
+        KeyAction::Up => "up",
+    }
+}
+
+fn read_key_action(token: Option<&str>) -> io::Result<KeyAction> {
+    match token {
+        Some("down") => Ok(KeyAction::Down),
+        Some("up") => Ok(KeyAction::Up),
+        other => Err(invalid(format!("invalid key action: {other:?}"))),
+    }
+}
+
+fn write_key(key: Key) -> String {
+    let name = match key {
+        Key::Character(ch) => {
+            return format!(
+                "char {}",
+                write_text(ch.encode_utf8(&mut [0; 4]))
+            );
+        }
+        Key::F(index) => return format!("f {index}"),
+        Key::Enter => "enter",
+        Key::Tab => "tab",
+        Key::Space => "space",
+        Key::Backspace => "backspace",
+        Key::Delete => "delete",
+        Key::Escape => "escape",
+        Key::Insert => "insert",
+        Key::Home => "home",
+        Key::End => "end",
+        Key::PageUp => "pageup",
+        Key::PageDown => "pagedown",
+        Key::ArrowLeft => "left",
+        Key::ArrowRight => "right",
+        Key::ArrowUp => "up",
+        Key::ArrowDown => "down",
+        Key::Shift => "shift",
+        Key::Control => "control",
+        Key::Alt => "alt",
+        Key::Logo => "logo",
+        Key::CapsLock => "capslock",
+        Key::Unknown => "unknown",
+    };
+    name.to_string()
+}
+
+fn read_key<'a, I>(tokens: &mut I) -> io::Result<Key>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let key = match tokens.next() {
+        Some("char") => {
+            let text = read_text(tokens.next())?;
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => Key::Character(ch),
+                _ => {
+                    return Err(invalid(format!(
+                        "invalid character: {text:?}"
+                    )))
+                }
+            }
+        }
+        Some("f") => Key::F(parse(tokens.next())?),
+        Some("enter") => Key::Enter,
+        Some("tab") => Key::Tab,
+        Some("space") => Key::Space,
+        Some("backspace") => Key::Backspace,
+        Some("delete") => Key::Delete,
+        Some("escape") => Key::Escape,
+        Some("insert") => Key::Insert,
+        Some("home") => Key::Home,
+        Some("end") => Key::End,
+        Some("pageup") => Key::PageUp,
+        Some("pagedown") => Key::PageDown,
+        Some("left") => Key::ArrowLeft,
+        Some("right") => Key::ArrowRight,
+        Some("up") => Key::ArrowUp,
+        Some("down") => Key::ArrowDown,
+        Some("shift") => Key::Shift,
+        Some("control") => Key::Control,
+        Some("alt") => Key::Alt,
+        Some("logo") => Key::Logo,
+        Some("capslock") => Key::CapsLock,
+        Some("unknown") => Key::Unknown,
+        other => return Err(invalid(format!("invalid key: {other:?}"))),
+    };
+    Ok(key)
+}
+
+/// Write the optional details of a key event which differ from the
+/// defaults, as `key=value` tokens.
+fn write_key_details(event: &KeyEvent) -> String {
+    let mut details = write_modifiers(event.modifiers);
+    if event.scancode != ScanCode::UNKNOWN {
+        details += &format!(" scan={}", event.scancode.0);
+    }
+    if event.repeat {
+        details += " repeat=true";
+    }
+    details
+}
+
+fn read_key_detail(event: &mut KeyEvent, detail: &str) -> io::Result<()> {
+    let (key, value) = detail
+        .split_once('=')
+        .ok_or_else(|| invalid(format!("unexpected token: {detail}")))?;
+    match key {
+        "mods" => event.modifiers = read_modifiers(value)?,
+        "scan" => event.scancode = ScanCode(parse(Some(value))?),
+        "repeat" => event.repeat = parse(Some(value))?,
+        _ => return Err(invalid(format!("unknown detail: {key}"))),
+    }
+    Ok(())
+}
+
+/// Write text as a single token: a `=` followed by the text, with `%`,
+/// whitespace and control characters percent-encoded.
+fn write_text(text: &str) -> String {
+    let mut token = String::from("=");
+    for ch in text.chars() {
+        if ch == '%' || ch.is_whitespace() || ch.is_control() {
+            for byte in ch.encode_utf8(&mut [0; 4]).bytes() {
+                token += &format!("%{byte:02X}");
+            }
+        } else {
+            token.push(ch);
+        }
+    }
+    token
+}
+
+fn read_text(token: Option<&str>) -> io::Result<String> {
+    let token = token.ok_or_else(|| invalid("unexpected end of line"))?;
+    let encoded = token
+        .strip_prefix('=')
+        .ok_or_else(|| invalid(format!("invalid text: {token}")))?;
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut rest = encoded.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = tail
+                .get(..2)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| invalid(format!("invalid text: {token}")))?;
+            bytes.push(hex);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes)
+        .map_err(|_| invalid(format!("invalid text: {token}")))
+}
+
+fn read_text_event<'a, I>(tokens: &mut I) -> io::Result<TextEvent>
+where
+    I: Iterator<Item = &'a str>,
+{
+    match tokens.next() {
+        Some("input") => Ok(TextEvent::Input(read_text(tokens.next())?)),
+        Some("edit") => {
+            let text = read_text(tokens.next())?;
+            let start = parse(tokens.next())?;
+            let end = parse(tokens.next())?;
+            Ok(TextEvent::Editing {
+                text,
+                cursor: start..end,
+            })
+        }
+        other => Err(invalid(format!("invalid text event: {other:?}"))),
+    }
+}
+
+fn write_nav(nav: NavAction) -> &'static str {
+    match nav {
+        NavAction::Navigate(Direction::Left) => "left",
+        NavAction::Navigate(Direction::Right) => "right",
+        NavAction::Navigate(Direction::Up) => "up",
+        NavAction::Navigate(Direction::Down) => "down",
+        NavAction::Activate => "activate",
+        NavAction::Back => "back",
+    }
+}
+
+fn read_nav<'a, I>(tokens: &mut I) -> io::Result<NavAction>
+where
+    I: Iterator<Item = &'a str>,
+{
+    match tokens.next() {
+        Some("left") => Ok(NavAction::Navigate(Direction::Left)),
+        Some("right") => Ok(NavAction::Navigate(Direction::Right)),
+        Some("up") => Ok(NavAction::Navigate(Direction::Up)),
+        Some("down") => Ok(NavAction::Navigate(Direction::Down)),
+        Some("activate") => Ok(NavAction::Activate),
+        Some("back") => Ok(NavAction::Back),
+        other => Err(invalid(format!("invalid nav action: {other:?}"))),
+    }
+}
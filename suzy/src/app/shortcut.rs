@@ -0,0 +1,40 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use crate::keyboard::KeyEvent;
+
+use super::App;
+
+impl<P> App<P> {
+    /// Trigger the shortcut matching a key event, if any.
+    pub(super) fn trigger_shortcut(&mut self, event: &KeyEvent) -> bool {
+        let shortcuts = &self.state.shortcuts;
+        let scopes = shortcuts.matching_scopes(event);
+        if scopes.is_empty() {
+            return false;
+        }
+        let path = if scopes.iter().any(Option::is_some) {
+            self.focus_path().unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        // search from the focused widget outward, then global shortcuts,
+        // which belong to the layers below a modal
+        let scoped = path
+            .iter()
+            .rev()
+            .find_map(|scope| shortcuts.find(event, Some(scope)));
+        let found = if self.is_modal_shown() {
+            scoped
+        } else {
+            scoped.or_else(|| shortcuts.find(event, None))
+        };
+        match found {
+            Some(shortcut) => {
+                shortcut.trigger();
+                true
+            }
+            None => false,
+        }
+    }
+}
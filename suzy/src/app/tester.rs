@@ -1,13 +1,17 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
-use std::time;
+use std::{rc::Rc, time};
 
 use drying_paint::WatchedValueCore;
 
-use crate::{platform::RenderPlatform, pointer::PointerEventData};
+use crate::{
+    clipboard::{Clipboard, MemoryClipboard},
+    platform::RenderPlatform,
+    pointer::PointerEventData,
+};
 
-use super::App;
+use super::{App, RecordedEvent, Recording};
 
 pub trait TestingExt {
     /// Short-hand to simulate a mouse click
@@ -22,6 +26,17 @@ pub trait TestingExt {
     /// though `frame_time` has passed (e.g. for the purposes of [`crate::app::time()`]).
     fn next_frame(&mut self, frame_time: time::Duration);
 
+    /// Give the app a new, empty in-memory clipboard, and return it so its
+    /// contents can be inspected.
+    fn test_clipboard(&mut self) -> Rc<MemoryClipboard>;
+
+    /// Feed a recorded session back into the app, with the same frame
+    /// timing; see `App::start_recording`.
+    ///
+    /// Frames are advanced with [`TestingExt::next_frame`], and watches are
+    /// updated once the recording is finished.
+    fn replay(&mut self, recording: &Recording);
+
     /// Start the next frame with a default frame time.
     fn next_frame_60fps(&mut self) {
         let frame_time = time::Duration::from_nanos(16_666_667);
@@ -36,20 +51,54 @@ impl<P: RenderPlatform> TestingExt for App<P> {
         self.start_frame(frame_time);
     }
 
+    fn test_clipboard(&mut self) -> Rc<MemoryClipboard> {
+        let clipboard = Rc::new(MemoryClipboard::new());
+        let shared: Rc<dyn Clipboard> =
+            Rc::<MemoryClipboard>::clone(&clipboard);
+        self.state.set_clipboard(shared);
+        clipboard
+    }
+
+    fn replay(&mut self, recording: &Recording) {
+        for event in recording.events() {
+            match event.clone() {
+                RecordedEvent::Frame(duration) => self.next_frame(duration),
+                RecordedEvent::Pointer(data) => {
+                    self.pointer_event(data);
+                }
+                RecordedEvent::Resize { width, height } => {
+                    self.resize(width, height);
+                }
+                RecordedEvent::Dpi(dpi) => self.update_dpi(dpi),
+                RecordedEvent::PointerLeave(id) => self.pointer_leave(id),
+                RecordedEvent::Key(event) => {
+                    self.key_event(event);
+                }
+                RecordedEvent::Text(event) => {
+                    self.text_event(event);
+                }
+                RecordedEvent::Nav(nav, action) => {
+                    self.nav_event(nav, action);
+                }
+            }
+        }
+        self.update_watches();
+    }
+
     fn mouse_click(&mut self, pos: [f32; 2]) {
         let [px, py] = pos;
-        self.pointer_event(PointerEventData {
-            id: crate::pointer::PointerId::Mouse,
-            action: crate::pointer::PointerAction::Down,
-            x: px,
-            y: py,
-        });
+        self.pointer_event(PointerEventData::new(
+            crate::pointer::PointerId::Mouse,
+            crate::pointer::PointerAction::Down,
+            px,
+            py,
+        ));
         self.next_frame_60fps();
-        self.pointer_event(PointerEventData {
-            id: crate::pointer::PointerId::Mouse,
-            action: crate::pointer::PointerAction::Up,
-            x: px,
-            y: py,
-        });
+        self.pointer_event(PointerEventData::new(
+            crate::pointer::PointerId::Mouse,
+            crate::pointer::PointerAction::Up,
+            px,
+            py,
+        ));
     }
 }
@@ -1,9 +1,21 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
-use std::{cell::Cell, rc::Rc, time};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time,
+};
 
-use crate::watch::{DefaultOwner, WatchedValue};
+use super::OverlayRequest;
+use crate::{
+    clipboard::{Clipboard, MemoryClipboard},
+    dims::SimpleRect,
+    drag::DragRequest,
+    shortcut::ShortcutRegistry,
+    watch::{DefaultOwner, WatchedValue},
+    widget::{UniqueHandle, UniqueHandleId},
+};
 
 type WatchedCellCore<T> =
     crate::watch::WatchedCellCore<'static, T, DefaultOwner>;
@@ -12,12 +24,35 @@ thread_local! {
     static CURRENT: Cell<Option<Rc<AppState>>> = const { Cell::new(None) };
 }
 
+/// The state of text input requested by widgets, which platforms should
+/// apply to their window.
+///
+/// See [`start_text_input`](crate::app::start_text_input).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextInputState {
+    /// If the platform should currently be sending text events.
+    pub active: bool,
+    /// The area of the window text is being entered into, if known.
+    ///
+    /// Platforms may use this to position input method candidate windows.
+    pub rect: Option<SimpleRect>,
+}
+
 pub struct AppState {
     pub(super) frame_start: WatchedCellCore<time::Instant>,
     pub(super) coarse_time: WatchedCellCore<time::Instant>,
     pub(super) window_width: WatchedCellCore<f32>,
     pub(super) window_height: WatchedCellCore<f32>,
     pub(super) dpi: WatchedCellCore<[f32; 2]>,
+    pub(super) text_input: WatchedCellCore<TextInputState>,
+    pub(super) text_input_owner: RefCell<Option<UniqueHandleId>>,
+    focused: RefCell<Option<UniqueHandleId>>,
+    pub(crate) drag_handle: UniqueHandle,
+    pub(crate) drag_request: RefCell<Option<DragRequest>>,
+    pub(crate) dragging: Cell<bool>,
+    pub(crate) overlay_requests: RefCell<Vec<OverlayRequest>>,
+    pub(crate) shortcuts: ShortcutRegistry,
+    clipboard: RefCell<Rc<dyn Clipboard>>,
 }
 
 impl AppState {
@@ -45,6 +80,13 @@ impl AppState {
         &self.dpi
     }
 
+    /// The state of text input the platform should apply to its window.
+    pub fn text_input(
+        &self,
+    ) -> impl '_ + WatchedValue<Value = TextInputState> {
+        &self.text_input
+    }
+
     pub(crate) fn new_now(width: f32, height: f32) -> Self {
         let now = time::Instant::now();
         Self {
@@ -53,6 +95,45 @@ impl AppState {
             window_width: WatchedCellCore::new(width),
             window_height: WatchedCellCore::new(height),
             dpi: WatchedCellCore::new([96.0, 96.0]),
+            text_input: WatchedCellCore::default(),
+            text_input_owner: RefCell::new(None),
+            focused: RefCell::new(None),
+            drag_handle: UniqueHandle::new(),
+            drag_request: RefCell::new(None),
+            dragging: Cell::new(false),
+            overlay_requests: RefCell::default(),
+            shortcuts: ShortcutRegistry::default(),
+            clipboard: RefCell::new(Rc::new(MemoryClipboard::new())),
+        }
+    }
+
+    /// Get the clipboard used by the app.
+    #[must_use]
+    pub fn clipboard(&self) -> Rc<dyn Clipboard> {
+        Rc::clone(&self.clipboard.borrow())
+    }
+
+    pub(super) fn set_clipboard(&self, clipboard: Rc<dyn Clipboard>) {
+        self.clipboard.replace(clipboard);
+    }
+
+    pub(crate) fn focused(&self) -> Option<UniqueHandleId> {
+        // the focused widget may have been removed since it gained focus
+        self.focused
+            .borrow()
+            .clone()
+            .filter(UniqueHandleId::is_focusable)
+    }
+
+    pub(crate) fn set_focus(&self, focus: Option<UniqueHandleId>) {
+        let prev = self.focused.replace(focus.clone());
+        if prev != focus {
+            if let Some(prev) = prev {
+                prev.set_focused(false);
+            }
+            if let Some(focus) = focus {
+                focus.set_focused(true);
+            }
         }
     }
 
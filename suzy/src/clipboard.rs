@@ -0,0 +1,105 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+//! Access to the system clipboard.
+//!
+//! Each app has a [`Clipboard`], which platforms replace with one backed by
+//! the system clipboard.  Widgets can reach it with
+//! [`app::clipboard`](crate::app::clipboard).  Apps start with a
+//! [`MemoryClipboard`], which only shares data within the app.
+
+use std::cell::RefCell;
+
+/// A clipboard, which holds data copied by the user.
+///
+/// Every clipboard supports text.  Clipboards may also support data tagged
+/// with a MIME type; the default implementations of those methods report
+/// that it is not supported.
+pub trait Clipboard {
+    /// Get the text on the clipboard, if there is any.
+    fn text(&self) -> Option<String>;
+
+    /// Replace the contents of the clipboard with text.
+    fn set_text(&self, text: &str) -> Result<(), String>;
+
+    /// Get the data on the clipboard with the given MIME type, if there is
+    /// any.
+    fn data(&self, mime_type: &str) -> Option<Vec<u8>> {
+        let _unused = mime_type;
+        None
+    }
+
+    /// Replace the contents of the clipboard with data of the given MIME
+    /// type.
+    fn set_data(&self, mime_type: &str, data: &[u8]) -> Result<(), String> {
+        let _unused = data;
+        Err(format!(
+            "clipboard does not support data of type {mime_type}"
+        ))
+    }
+}
+
+/// A clipboard which stores its contents in memory.
+///
+/// This supports data with any MIME type.  It is used by default, and by
+/// [`TestingExt::test_clipboard`](crate::app::TestingExt::test_clipboard).
+#[derive(Debug, Default)]
+pub struct MemoryClipboard {
+    contents: RefCell<Contents>,
+}
+
+#[derive(Debug, Default)]
+enum Contents {
+    #[default]
+    Empty,
+    Text(String),
+    Data {
+        mime_type: String,
+        data: Vec<u8>,
+    },
+}
+
+impl MemoryClipboard {
+    /// Create an empty clipboard.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove the contents of the clipboard.
+    pub fn clear(&self) {
+        self.contents.replace(Contents::Empty);
+    }
+}
+
+impl Clipboard for MemoryClipboard {
+    fn text(&self) -> Option<String> {
+        match &*self.contents.borrow() {
+            Contents::Text(text) => Some(text.clone()),
+            _ => None,
+        }
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), String> {
+        self.contents.replace(Contents::Text(text.to_owned()));
+        Ok(())
+    }
+
+    fn data(&self, mime_type: &str) -> Option<Vec<u8>> {
+        match &*self.contents.borrow() {
+            Contents::Data {
+                mime_type: stored,
+                data,
+            } if stored == mime_type => Some(data.clone()),
+            _ => None,
+        }
+    }
+
+    fn set_data(&self, mime_type: &str, data: &[u8]) -> Result<(), String> {
+        self.contents.replace(Contents::Data {
+            mime_type: mime_type.to_owned(),
+            data: data.to_vec(),
+        });
+        Ok(())
+    }
+}
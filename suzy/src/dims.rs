@@ -193,7 +193,7 @@ impl Padding2d {
 /// assert_feq(span.start(), 3.0);
 /// assert_feq(span.end(), 10.0);
 /// ```
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Dim {
     pub position: f32,
     pub pivot: f32,
@@ -578,7 +578,7 @@ macro_rules! proxy_rect_impl {
 pub(crate) use proxy_rect_impl;
 
 /// A struct representing a rectangular region
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct SimpleRect {
     x: Dim,
     y: Dim,
@@ -0,0 +1,192 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+//! Drag-and-drop moves a payload from one widget to another using a
+//! pointer.
+//!
+//! A widget starts a drag from its
+//! [`pointer_event`](crate::widget::Content::pointer_event) handler with
+//! [`start_drag`] (or [`start_drag_with_visual`]).  The app then grabs the
+//! pointer for the duration of the drag, so no other widget receives events
+//! for it.
+//!
+//! Widgets declare which payload types they accept with
+//! [`UniqueHandle::accept_drops`], and respond to [`DragEvent`]s with
+//! [`UniqueHandle::handle_drag_event`].  While the pointer moves, the
+//! current target is the accepting widget whose
+//! [`hittest`](crate::widget::Content::hittest) contains the pointer and
+//! which a pointer event there would reach first.  When the pointer is released, the payload is dropped on
+//! the current target; if there is none, the drag is cancelled.  Either way
+//! the widget which started the drag is told the outcome.
+
+use std::{
+    any::{Any, TypeId},
+    rc::Rc,
+};
+
+use crate::{
+    app::AppState,
+    platform::RenderPlatform,
+    pointer::PointerEvent,
+    widget::{self, UniqueHandle, Widget},
+};
+
+/// The value being dragged.
+///
+/// Payloads are cheap to clone, and may be inspected by targets before the
+/// drop happens.
+#[derive(Clone)]
+pub struct DragPayload {
+    value: Rc<dyn Any>,
+    type_name: &'static str,
+}
+
+impl DragPayload {
+    /// Create a new payload.
+    pub fn new<T: 'static>(value: T) -> Self {
+        Self {
+            value: Rc::new(value),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Check if the payload is of type `T`.
+    #[must_use]
+    pub fn is<T: 'static>(&self) -> bool {
+        self.value.is::<T>()
+    }
+
+    /// Get a reference to the payload, if it is of type `T`.
+    #[must_use]
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    pub(crate) fn value_type_id(&self) -> TypeId {
+        (*self.value).type_id()
+    }
+}
+
+impl std::fmt::Debug for DragPayload {
+    fn fmt(&self, fmtter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmtter
+            .debug_struct("DragPayload")
+            .field("type_name", &self.type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An event delivered to widgets involved in a drag.
+///
+/// See [`UniqueHandle::handle_drag_event`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum DragEvent {
+    /// Sent to a target when a drag it accepts moves over it.
+    Enter {
+        /// The position of the pointer.
+        pos: [f32; 2],
+        /// The value being dragged.
+        payload: DragPayload,
+    },
+
+    /// Sent to a target when a drag moves within it.
+    Over {
+        /// The position of the pointer.
+        pos: [f32; 2],
+    },
+
+    /// Sent to a target when a drag moves off of it, or is cancelled while
+    /// over it.
+    Leave,
+
+    /// Sent to a target when a drag is released over it.
+    Drop {
+        /// The position of the pointer.
+        pos: [f32; 2],
+        /// The value being dragged.
+        payload: DragPayload,
+    },
+
+    /// Sent to the widget which started a drag when the payload was
+    /// dropped on a target.
+    Completed,
+
+    /// Sent to the widget which started a drag when it ended without a
+    /// target accepting the payload.
+    Cancelled,
+}
+
+pub(crate) struct DragRequest {
+    pub pointer: crate::pointer::PointerId,
+    pub pos: [f32; 2],
+    pub source: widget::UniqueHandleId,
+    pub payload: DragPayload,
+    pub visual: Option<Box<dyn Any>>,
+}
+
+/// Start dragging a payload with the pointer involved in `event`.
+///
+/// `source` identifies the widget starting the drag, and will receive
+/// [`DragEvent::Completed`] or [`DragEvent::Cancelled`] when the drag ends.
+/// The source will also be notified that the pointer grab was stolen, if
+/// it had grabbed the pointer.
+///
+/// Returns false if a drag is already in progress, or this is called
+/// outside the context of a suzy app.
+pub fn start_drag<T: 'static>(
+    event: &mut PointerEvent<'_>,
+    source: &UniqueHandle,
+    payload: T,
+) -> bool {
+    request_drag(event, source, DragPayload::new(payload), None)
+}
+
+/// Start dragging a payload with the pointer involved in `event`, and draw
+/// `visual` centered on the pointer until the drag ends.
+///
+/// See [`start_drag`] for details.
+pub fn start_drag_with_visual<T, V, P>(
+    event: &mut PointerEvent<'_>,
+    source: &UniqueHandle,
+    payload: T,
+    visual: Widget<V>,
+) -> bool
+where
+    T: 'static,
+    V: widget::Content<P>,
+    P: 'static + RenderPlatform,
+{
+    let factory: crate::app::OverlayFactory<P> =
+        Box::new(move |app| app.init_root(visual));
+    request_drag(
+        event,
+        source,
+        DragPayload::new(payload),
+        Some(Box::new(factory)),
+    )
+}
+
+fn request_drag(
+    event: &mut PointerEvent<'_>,
+    source: &UniqueHandle,
+    payload: DragPayload,
+    visual: Option<Box<dyn Any>>,
+) -> bool {
+    let started = AppState::try_with_current(|state| {
+        let mut request = state.drag_request.borrow_mut();
+        if request.is_some() || state.dragging.get() {
+            return false;
+        }
+        event.force_grab(state.drag_handle.id());
+        *request = Some(DragRequest {
+            pointer: event.id(),
+            pos: event.pos(),
+            source: source.id(),
+            payload,
+            visual,
+        });
+        true
+    });
+    started.unwrap_or(false)
+}
@@ -0,0 +1,687 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+//! Gestures interpret sequences of pointer events as higher-level actions,
+//! such as a tap, a pan, or a two-finger pinch.
+//!
+//! Wrap the content of a widget in a [`GestureBehavior`] to recognize
+//! gestures over it, and use [`GestureBehavior::on_gesture`] in a watch
+//! closure to respond to them.
+//!
+//! Gesture recognition cooperates with the pointer grab map: a pointer is
+//! only claimed once it is clear a gesture is happening (for instance,
+//! once it has moved further than [`GestureSettings::slop`]), at which point
+//! the recognizer steals the pointer from any widget which grabbed it, such
+//! as a button.  Taps are only reported for pointers which no other widget
+//! has grabbed, so tapping a button inside a gesture area clicks the button
+//! without also producing a [`GestureEvent::Tap`].
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    keyboard::{KeyEvent, TextEvent},
+    pointer::{PointerAction, PointerEvent, PointerId},
+    watch::{WatchedMeta, WatchedQueue},
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+/// If a pan has not moved for this long before being released, it is
+/// considered to have stopped, and reports no velocity.
+const VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Configurable thresholds used to recognize gestures.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GestureSettings {
+    /// The distance, in pixels, a pointer may move and still be considered
+    /// a tap or long press.  Moving further than this begins a pan.
+    pub slop: f32,
+
+    /// The longest a pointer may be held down and still be considered a
+    /// tap.
+    pub tap_timeout: Duration,
+
+    /// The longest time between two taps for them to be considered a double
+    /// tap.
+    pub double_tap_interval: Duration,
+
+    /// The furthest distance, in pixels, between two taps for them to be
+    /// considered a double tap.
+    pub double_tap_distance: f32,
+
+    /// How long a pointer must be held without moving to be considered a
+    /// long press.
+    pub long_press_duration: Duration,
+
+    /// How much the distance between two pointers must change, as a
+    /// fraction of the starting distance, before a pinch begins.
+    pub pinch_threshold: f32,
+
+    /// How far, in radians, two pointers must rotate around each other
+    /// before a rotation begins.
+    pub rotate_threshold: f32,
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        Self {
+            slop: 10.0,
+            tap_timeout: Duration::from_millis(500),
+            double_tap_interval: Duration::from_millis(300),
+            double_tap_distance: 40.0,
+            long_press_duration: Duration::from_millis(500),
+            pinch_threshold: 0.1,
+            rotate_threshold: 0.15,
+        }
+    }
+}
+
+/// Describes the progress of a continuous gesture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GesturePhase {
+    /// The gesture was just recognized.
+    Began,
+
+    /// The gesture is ongoing, and has been updated.
+    Changed,
+
+    /// The gesture finished because the pointers involved were released.
+    Ended,
+
+    /// The gesture was interrupted, because another widget took the
+    /// pointers involved.
+    Cancelled,
+}
+
+/// A gesture recognized by a [`GestureBehavior`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum GestureEvent {
+    /// A pointer was pressed and released quickly without moving.
+    Tap {
+        /// The position the pointer was released at.
+        pos: [f32; 2],
+    },
+
+    /// A second tap followed a first one closely.  This is reported in
+    /// addition to the [`Tap`](Self::Tap) for the second tap.
+    DoubleTap {
+        /// The position the pointer was released at.
+        pos: [f32; 2],
+    },
+
+    /// A pointer was held down without moving.
+    LongPress {
+        /// The position the pointer was pressed at.
+        pos: [f32; 2],
+    },
+
+    /// A single pointer was dragged.
+    Pan {
+        /// The progress of the pan.
+        phase: GesturePhase,
+        /// The current position of the pointer.
+        pos: [f32; 2],
+        /// The movement since the last pan event.
+        delta: [f32; 2],
+        /// The total movement since the pan began.
+        translation: [f32; 2],
+        /// The speed of the pointer, in pixels per second.
+        velocity: [f32; 2],
+    },
+
+    /// Two pointers moved towards or away from each other.
+    Pinch {
+        /// The progress of the pinch.
+        phase: GesturePhase,
+        /// The point halfway between the two pointers.
+        center: [f32; 2],
+        /// The current distance between the pointers, relative to the
+        /// distance when the gesture began.
+        scale: f32,
+    },
+
+    /// Two pointers rotated around each other.
+    Rotate {
+        /// The progress of the rotation.
+        phase: GesturePhase,
+        /// The point halfway between the two pointers.
+        center: [f32; 2],
+        /// The total rotation since the gesture began, in radians,
+        /// counter-clockwise.
+        angle: f32,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum PointerStatus {
+    /// The pointer is being observed, but may belong to another widget.
+    Pending,
+    /// The pointer is part of a gesture, and should be grabbed the next
+    /// time an event for it arrives.
+    Claimed,
+    /// The pointer has been grabbed.
+    Grabbed,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TrackedPointer {
+    pointer: PointerId,
+    status: PointerStatus,
+    start: [f32; 2],
+    pos: [f32; 2],
+    down_time: Instant,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PanState {
+    pointer: PointerId,
+    translation: [f32; 2],
+    velocity: [f32; 2],
+    sample_time: Instant,
+    sample_delta: [f32; 2],
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TransformState {
+    pointers: [PointerId; 2],
+    start_distance: f32,
+    prev_angle: f32,
+    scale: f32,
+    angle: f32,
+    pinching: bool,
+    rotating: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ActiveGesture {
+    None,
+    Pan(PanState),
+    Transform(TransformState),
+}
+
+fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+    (b[0] - a[0]).hypot(b[1] - a[1])
+}
+
+fn angle(a: [f32; 2], b: [f32; 2]) -> f32 {
+    (b[1] - a[1]).atan2(b[0] - a[0])
+}
+
+fn midpoint(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0]
+}
+
+/// Wrap an angle difference into the range -π to π.
+fn wrap_angle(angle: f32) -> f32 {
+    use std::f32::consts::{PI, TAU};
+    let wrapped = (angle + PI).rem_euclid(TAU) - PI;
+    if wrapped <= -PI {
+        wrapped + TAU
+    } else {
+        wrapped
+    }
+}
+
+/// A Widget which recognizes gestures made over its content.
+pub struct GestureBehavior<T> {
+    events: WatchedQueue<'static, GestureEvent>,
+    settings: GestureSettings,
+    pointers: Vec<TrackedPointer>,
+    active: ActiveGesture,
+    multi_touch: bool,
+    last_tap: Option<(Instant, [f32; 2])>,
+    long_press_deadline: Option<Instant>,
+    long_press_flag: WatchedMeta<'static>,
+    handle: UniqueHandle,
+    content: T,
+}
+
+impl<T> GestureBehavior<T> {
+    /// Get a reference to the content of this widget.
+    pub fn content(&self) -> &T {
+        &self.content
+    }
+
+    /// Get a mutable reference to the content of this widget.
+    pub fn content_mut(&mut self) -> &mut T {
+        &mut self.content
+    }
+
+    /// Get the thresholds used to recognize gestures.
+    pub fn settings(&self) -> &GestureSettings {
+        &self.settings
+    }
+
+    /// Get a mutable reference to the thresholds used to recognize
+    /// gestures.
+    pub fn settings_mut(&mut self) -> &mut GestureSettings {
+        &mut self.settings
+    }
+
+    /// Handle a recognized gesture.
+    ///
+    /// This should be called in a watch closure, which will re-run for each
+    /// gesture event.
+    pub fn on_gesture<F: FnOnce(GestureEvent)>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.events.handle_item(arg, |event| f(*event));
+        });
+    }
+
+    fn emit(&mut self, event: GestureEvent) {
+        self.events.push_auto(event);
+    }
+
+    fn find(&self, pointer: PointerId) -> Option<usize> {
+        self.pointers
+            .iter()
+            .position(|entry| entry.pointer == pointer)
+    }
+
+    fn schedule_long_press(&mut self, down_time: Instant) {
+        self.long_press_deadline =
+            Some(down_time + self.settings.long_press_duration);
+        self.long_press_flag.trigger_auto();
+    }
+
+    fn check_long_press(&mut self) {
+        self.long_press_flag.watched_auto();
+        if let Some(deadline) = self.long_press_deadline {
+            if crate::app::time() >= deadline {
+                self.long_press_deadline = None;
+                if let [entry] = &mut self.pointers[..] {
+                    if entry.status == PointerStatus::Pending {
+                        entry.status = PointerStatus::Claimed;
+                    }
+                    let pos = entry.start;
+                    self.emit(GestureEvent::LongPress { pos });
+                }
+            }
+        }
+    }
+
+    fn pointer_moved(&mut self, index: usize, event: &mut PointerEvent<'_>) {
+        let now = crate::app::time_unwatched();
+        let pos = event.pos();
+        let prev = self.pointers[index].pos;
+        self.pointers[index].pos = pos;
+        match &mut self.active {
+            ActiveGesture::Pan(pan) if pan.pointer == event.id() => {
+                let delta = [pos[0] - prev[0], pos[1] - prev[1]];
+                pan.translation[0] += delta[0];
+                pan.translation[1] += delta[1];
+                pan.sample_delta[0] += delta[0];
+                pan.sample_delta[1] += delta[1];
+                // events within the same frame share a timestamp, so
+                // accumulate movement until time advances
+                let elapsed = now.saturating_duration_since(pan.sample_time);
+                if !elapsed.is_zero() {
+                    let secs = elapsed.as_secs_f32();
+                    for axis in 0..2 {
+                        let sample = pan.sample_delta[axis] / secs;
+                        pan.velocity[axis] =
+                            0.8 * sample + 0.2 * pan.velocity[axis];
+                    }
+                    pan.sample_time = now;
+                    pan.sample_delta = [0.0, 0.0];
+                }
+                let pan = *pan;
+                self.emit(GestureEvent::Pan {
+                    phase: GesturePhase::Changed,
+                    pos,
+                    delta,
+                    translation: pan.translation,
+                    velocity: pan.velocity,
+                });
+            }
+            ActiveGesture::Transform(_) => self.update_transform(),
+            _ => self.try_begin(index, event),
+        }
+    }
+
+    /// Check if movement of the pointer at `index` should begin a gesture.
+    fn try_begin(&mut self, index: usize, event: &mut PointerEvent<'_>) {
+        let moved = self.pointers.iter().take(2).any(|entry| {
+            distance(entry.start, entry.pos) > self.settings.slop
+        });
+        if !moved {
+            return;
+        }
+        self.long_press_deadline = None;
+        let entry = self.pointers[index];
+        if entry.status != PointerStatus::Grabbed {
+            event.force_grab(self.handle.id());
+            self.pointers[index].status = PointerStatus::Grabbed;
+        }
+        if let [first, second, ..] = self.pointers[..] {
+            self.end_active(GesturePhase::Ended);
+            for entry in self.pointers.iter_mut().take(2) {
+                if entry.status == PointerStatus::Pending {
+                    entry.status = PointerStatus::Claimed;
+                }
+            }
+            self.active = ActiveGesture::Transform(TransformState {
+                pointers: [first.pointer, second.pointer],
+                start_distance: distance(first.start, second.start),
+                prev_angle: angle(first.start, second.start),
+                scale: 1.0,
+                angle: 0.0,
+                pinching: false,
+                rotating: false,
+            });
+            self.update_transform();
+        } else if matches!(self.active, ActiveGesture::None) {
+            let pan = PanState {
+                pointer: entry.pointer,
+                translation: [
+                    entry.pos[0] - entry.start[0],
+                    entry.pos[1] - entry.start[1],
+                ],
+                velocity: [0.0, 0.0],
+                sample_time: crate::app::time_unwatched(),
+                sample_delta: [0.0, 0.0],
+            };
+            self.active = ActiveGesture::Pan(pan);
+            self.emit(GestureEvent::Pan {
+                phase: GesturePhase::Began,
+                pos: entry.pos,
+                delta: pan.translation,
+                translation: pan.translation,
+                velocity: pan.velocity,
+            });
+        }
+    }
+
+    fn update_transform(&mut self) {
+        let ActiveGesture::Transform(mut transform) = self.active else {
+            return;
+        };
+        let positions = transform.pointers.map(|pointer| {
+            self.pointers
+                .iter()
+                .find(|entry| entry.pointer == pointer)
+                .map(|entry| entry.pos)
+        });
+        let [Some(first), Some(second)] = positions else {
+            return;
+        };
+        let center = midpoint(first, second);
+        if transform.start_distance > 0.0 {
+            transform.scale =
+                distance(first, second) / transform.start_distance;
+        }
+        let current_angle = angle(first, second);
+        transform.angle += wrap_angle(current_angle - transform.prev_angle);
+        transform.prev_angle = current_angle;
+        let mut events = Vec::with_capacity(2);
+        if transform.pinching {
+            events.push((GesturePhase::Changed, true));
+        } else if (transform.scale - 1.0).abs()
+            >= self.settings.pinch_threshold
+        {
+            transform.pinching = true;
+            events.push((GesturePhase::Began, true));
+        }
+        if transform.rotating {
+            events.push((GesturePhase::Changed, false));
+        } else if transform.angle.abs() >= self.settings.rotate_threshold {
+            transform.rotating = true;
+            events.push((GesturePhase::Began, false));
+        }
+        self.active = ActiveGesture::Transform(transform);
+        for (phase, is_pinch) in events {
+            self.emit(if is_pinch {
+                GestureEvent::Pinch {
+                    phase,
+                    center,
+                    scale: transform.scale,
+                }
+            } else {
+                GestureEvent::Rotate {
+                    phase,
+                    center,
+                    angle: transform.angle,
+                }
+            });
+        }
+    }
+
+    /// Finish any active gesture.
+    fn end_active(&mut self, phase: GesturePhase) {
+        let now = crate::app::time_unwatched();
+        match std::mem::replace(&mut self.active, ActiveGesture::None) {
+            ActiveGesture::None => {}
+            ActiveGesture::Pan(pan) => {
+                let pos = self
+                    .find(pan.pointer)
+                    .map_or([0.0, 0.0], |index| self.pointers[index].pos);
+                let stale = now.saturating_duration_since(pan.sample_time)
+                    > VELOCITY_TIMEOUT;
+                let velocity = if stale || phase == GesturePhase::Cancelled {
+                    [0.0, 0.0]
+                } else {
+                    pan.velocity
+                };
+                self.emit(GestureEvent::Pan {
+                    phase,
+                    pos,
+                    delta: [0.0, 0.0],
+                    translation: pan.translation,
+                    velocity,
+                });
+            }
+            ActiveGesture::Transform(transform) => {
+                let positions = transform.pointers.map(|pointer| {
+                    self.find(pointer)
+                        .map_or([0.0, 0.0], |index| self.pointers[index].pos)
+                });
+                let center = midpoint(positions[0], positions[1]);
+                if transform.pinching {
+                    self.emit(GestureEvent::Pinch {
+                        phase,
+                        center,
+                        scale: transform.scale,
+                    });
+                }
+                if transform.rotating {
+                    self.emit(GestureEvent::Rotate {
+                        phase,
+                        center,
+                        angle: transform.angle,
+                    });
+                }
+            }
+        }
+    }
+
+    fn involved_in_active(&self, pointer: PointerId) -> bool {
+        match &self.active {
+            ActiveGesture::None => false,
+            ActiveGesture::Pan(pan) => pan.pointer == pointer,
+            ActiveGesture::Transform(transform) => {
+                transform.pointers.contains(&pointer)
+            }
+        }
+    }
+
+    /// Stop tracking a pointer, and re-base the remaining pointers so that
+    /// they must move again before beginning a new gesture.
+    fn remove_pointer(&mut self, index: usize) {
+        self.pointers.remove(index);
+        self.long_press_deadline = None;
+        for entry in &mut self.pointers {
+            entry.start = entry.pos;
+        }
+        if self.pointers.is_empty() {
+            self.multi_touch = false;
+        }
+    }
+
+    fn tap(&mut self, pos: [f32; 2]) {
+        let now = crate::app::time_unwatched();
+        self.emit(GestureEvent::Tap { pos });
+        let is_double = self.last_tap.is_some_and(|(time, prev_pos)| {
+            now.saturating_duration_since(time)
+                <= self.settings.double_tap_interval
+                && distance(prev_pos, pos) <= self.settings.double_tap_distance
+        });
+        if is_double {
+            self.emit(GestureEvent::DoubleTap { pos });
+            self.last_tap = None;
+        } else {
+            self.last_tap = Some((now, pos));
+        }
+    }
+}
+
+impl<T, P> widget::Content<P> for GestureBehavior<T>
+where
+    T: widget::Content<P>,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.bare_child(|this| &mut this.content);
+        desc.watch(|this, _rect| {
+            this.check_long_press();
+        });
+        desc.watch(|this, _rect| {
+            let mut stolen = Vec::new();
+            this.handle.handle_pointer_grab_stolen(|pointer_id| {
+                stolen.push(pointer_id);
+            });
+            let cancelled = stolen
+                .iter()
+                .any(|&pointer_id| this.involved_in_active(pointer_id));
+            if cancelled {
+                this.end_active(GesturePhase::Cancelled);
+            }
+            for pointer_id in stolen {
+                if let Some(index) = this.find(pointer_id) {
+                    this.remove_pointer(index);
+                }
+            }
+        });
+    }
+
+    fn hittest(&self, rect: &WidgetRect, point: [f32; 2]) -> bool {
+        self.content.hittest(rect, point)
+    }
+
+    fn pointer_event_before(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        if let PointerAction::Down = event.action() {
+            if self.hittest(rect, event.pos())
+                && self.find(event.id()).is_none()
+            {
+                let now = crate::app::time_unwatched();
+                self.pointers.push(TrackedPointer {
+                    pointer: event.id(),
+                    status: PointerStatus::Pending,
+                    start: event.pos(),
+                    pos: event.pos(),
+                    down_time: now,
+                });
+                if self.pointers.len() == 1 {
+                    self.schedule_long_press(now);
+                } else {
+                    self.multi_touch = true;
+                    self.long_press_deadline = None;
+                }
+            }
+            return self.content.pointer_event_before(rect, event);
+        }
+        let Some(index) = self.find(event.id()) else {
+            return self.content.pointer_event_before(rect, event);
+        };
+        if self.pointers[index].status == PointerStatus::Claimed {
+            event.force_grab(self.handle.id());
+            self.pointers[index].status = PointerStatus::Grabbed;
+        }
+        match event.action() {
+            PointerAction::Move(_, _) => {
+                self.pointer_moved(index, event);
+                self.pointers[index].status == PointerStatus::Grabbed
+                    || self.content.pointer_event_before(rect, event)
+            }
+            PointerAction::Up => {
+                let entry = self.pointers[index];
+                self.pointers[index].pos = event.pos();
+                if entry.status == PointerStatus::Grabbed {
+                    if self.involved_in_active(entry.pointer) {
+                        self.end_active(GesturePhase::Ended);
+                    }
+                    self.remove_pointer(index);
+                    event.try_ungrab(self.handle.id());
+                    return true;
+                }
+                self.remove_pointer(index);
+                let held = crate::app::time_unwatched()
+                    .saturating_duration_since(entry.down_time);
+                let is_tap = !self.multi_touch
+                    && held <= self.settings.tap_timeout
+                    && distance(entry.start, event.pos())
+                        <= self.settings.slop
+                    && event.try_grab(self.handle.id());
+                if is_tap {
+                    event.try_ungrab(self.handle.id());
+                    self.tap(event.pos());
+                    return true;
+                }
+                self.content.pointer_event_before(rect, event)
+            }
+            _ => self.content.pointer_event_before(rect, event),
+        }
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        self.content.pointer_event(rect, event)
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        self.content.handle()
+    }
+
+    fn key_event(&mut self, rect: &WidgetRect, event: &KeyEvent) -> bool {
+        self.content.key_event(rect, event)
+    }
+
+    fn key_event_before(
+        &mut self,
+        rect: &WidgetRect,
+        event: &KeyEvent,
+    ) -> bool {
+        self.content.key_event_before(rect, event)
+    }
+
+    fn text_event(&mut self, rect: &WidgetRect, event: &TextEvent) -> bool {
+        self.content.text_event(rect, event)
+    }
+}
+
+impl<T: Default> Default for GestureBehavior<T> {
+    fn default() -> Self {
+        Self {
+            events: WatchedQueue::default(),
+            settings: GestureSettings::default(),
+            pointers: Vec::new(),
+            active: ActiveGesture::None,
+            multi_touch: false,
+            last_tap: None,
+            long_press_deadline: None,
+            long_press_flag: WatchedMeta::default(),
+            handle: UniqueHandle::default(),
+            content: T::default(),
+        }
+    }
+}
+
+/// A widget which recognizes gestures made over its content.
+///
+/// Use `GestureArea::on_gesture` to handle recognized gestures.
+pub type GestureArea<T> = Widget<GestureBehavior<T>>;
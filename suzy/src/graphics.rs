@@ -47,6 +47,12 @@ impl<P: ?Sized + RenderPlatform, T: Graphic<P>> Graphic<P> for [T] {
     }
 }
 
+impl<P: ?Sized + RenderPlatform, T: Graphic<P>> Graphic<P> for Vec<T> {
+    fn draw(&mut self, ctx: &mut DrawContext<'_, P>) {
+        self.as_mut_slice().draw(ctx);
+    }
+}
+
 impl<P: ?Sized + RenderPlatform, T: Graphic<P>> Graphic<P> for &mut T {
     fn draw(&mut self, ctx: &mut DrawContext<'_, P>) {
         T::draw(self, ctx)
@@ -0,0 +1,290 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+//! Keyboard events are delivered to widgets in a similar way to pointer
+//! events.
+//!
+//! Each key event describes both the physical key involved (its scancode)
+//! and the logical key it represents in the current keyboard layout.
+//! Widgets which respond to the meaning of a key (e.g. "Enter") should
+//! inspect the logical key, while widgets which care about the position of
+//! a key (e.g. WASD movement) should inspect the scancode.
+//!
+//! Key events are not suitable for accepting typed text, since composed
+//! characters and input methods do not map onto individual keys. Widgets
+//! which accept text should instead request text input with
+//! [`crate::app::start_text_input`] and respond to [`TextEvent`]s.
+
+use std::ops::Range;
+
+/// A physical key on a keyboard, independent of the keyboard layout.
+///
+/// The values correspond to the USB HID usage ids of the keyboard usage
+/// page.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct ScanCode(pub u32);
+
+impl ScanCode {
+    /// The scancode used when the physical key is not known, for instance
+    /// for simulated events.
+    pub const UNKNOWN: Self = Self(0);
+}
+
+/// A logical key, as interpreted by the current keyboard layout.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[non_exhaustive]
+pub enum Key {
+    /// A key which corresponds to a printable character.
+    ///
+    /// Letters are reported in lowercase, regardless of the state of the
+    /// modifier keys.
+    Character(char),
+
+    /// The Enter or Return key.
+    Enter,
+
+    /// The Tab key.
+    Tab,
+
+    /// The space bar.
+    Space,
+
+    /// The Backspace key.
+    Backspace,
+
+    /// The (forward) Delete key.
+    Delete,
+
+    /// The Escape key.
+    Escape,
+
+    /// The Insert key.
+    Insert,
+
+    /// The Home key.
+    Home,
+
+    /// The End key.
+    End,
+
+    /// The Page Up key.
+    PageUp,
+
+    /// The Page Down key.
+    PageDown,
+
+    /// The left arrow key.
+    ArrowLeft,
+
+    /// The right arrow key.
+    ArrowRight,
+
+    /// The up arrow key.
+    ArrowUp,
+
+    /// The down arrow key.
+    ArrowDown,
+
+    /// A function key, e.g. `F(5)` for F5.
+    F(u8),
+
+    /// Either Shift key.
+    Shift,
+
+    /// Either Control key.
+    Control,
+
+    /// Either Alt key.
+    Alt,
+
+    /// Either "logo" key, e.g. the Windows or Command key.
+    Logo,
+
+    /// The Caps Lock key.
+    CapsLock,
+
+    /// A key which Suzy does not have a representation for.
+    Unknown,
+}
+
+/// The state of the modifier keys during a key event.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct Modifiers {
+    /// Either Shift key is held.
+    pub shift: bool,
+    /// Either Control key is held.
+    pub ctrl: bool,
+    /// Either Alt key is held.
+    pub alt: bool,
+    /// Either "logo" key is held, e.g. the Windows or Command key.
+    pub logo: bool,
+}
+
+impl Modifiers {
+    /// No modifier keys are held.
+    pub const NONE: Self = Self {
+        shift: false,
+        ctrl: false,
+        alt: false,
+        logo: false,
+    };
+
+    /// Returns true if no modifier keys are held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+/// A key combined with the modifier keys which must be held with it, e.g.
+/// Ctrl+S.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct KeyChord {
+    /// The logical key which must be pressed.
+    pub key: Key,
+    /// The modifier keys which must be held; other modifier keys must not
+    /// be held.
+    pub modifiers: Modifiers,
+}
+
+impl KeyChord {
+    /// Create a chord of a single key, with no modifier keys held.
+    #[must_use]
+    pub const fn new(key: Key) -> Self {
+        Self {
+            key,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    /// Require that Shift is held.
+    #[must_use]
+    pub const fn shift(mut self) -> Self {
+        self.modifiers.shift = true;
+        self
+    }
+
+    /// Require that Control is held.
+    #[must_use]
+    pub const fn ctrl(mut self) -> Self {
+        self.modifiers.ctrl = true;
+        self
+    }
+
+    /// Require that Alt is held.
+    #[must_use]
+    pub const fn alt(mut self) -> Self {
+        self.modifiers.alt = true;
+        self
+    }
+
+    /// Require that the "logo" key is held, e.g. the Windows or Command key.
+    #[must_use]
+    pub const fn logo(mut self) -> Self {
+        self.modifiers.logo = true;
+        self
+    }
+
+    /// Returns true if `event` is the initial press of this chord.
+    #[must_use]
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        event.is_press_of(self.key) && event.modifiers == self.modifiers
+    }
+}
+
+/// An enum describing the activity that generated a particular key event.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum KeyAction {
+    /// The key was pressed.
+    Down,
+
+    /// The key was released.
+    Up,
+}
+
+/// The data associated with a particular key event.
+///
+/// This will get passed to the [`crate::widget::Content::key_event`] method.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
+pub struct KeyEvent {
+    /// The activity that caused this event.
+    pub action: KeyAction,
+    /// The logical key involved in this event.
+    pub key: Key,
+    /// The physical key involved in this event.
+    pub scancode: ScanCode,
+    /// The modifier keys held during this event.
+    pub modifiers: Modifiers,
+    /// True if this event was generated by the key being held down.
+    pub repeat: bool,
+}
+
+impl KeyEvent {
+    /// Create a new key event, with an unknown scancode and no modifier
+    /// keys held.
+    #[must_use]
+    pub fn new(action: KeyAction, key: Key) -> Self {
+        Self {
+            action,
+            key,
+            scancode: ScanCode::UNKNOWN,
+            modifiers: Modifiers::NONE,
+            repeat: false,
+        }
+    }
+
+    /// Set the physical key involved in this event.
+    #[must_use]
+    pub fn with_scancode(mut self, scancode: ScanCode) -> Self {
+        self.scancode = scancode;
+        self
+    }
+
+    /// Set the modifier keys held during this event.
+    #[must_use]
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Set if this event was generated by the key being held down.
+    #[must_use]
+    pub fn with_repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Returns true if this event is the initial press of `key`, ignoring
+    /// repeats.
+    #[must_use]
+    pub fn is_press_of(&self, key: Key) -> bool {
+        self.action == KeyAction::Down && !self.repeat && self.key == key
+    }
+}
+
+/// An event describing text entered by the user.
+///
+/// Text events are only sent by the platform while text input is active;
+/// see [`crate::app::start_text_input`].
+///
+/// This will get passed to the [`crate::widget::Content::text_event`] method.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TextEvent {
+    /// Text was committed, e.g. a key was typed or an input method
+    /// composition was completed.
+    Input(String),
+
+    /// The input method's pre-edit (composition) string changed.
+    ///
+    /// An empty string indicates the composition was cleared.
+    Editing {
+        /// The current pre-edit string.
+        text: String,
+        /// The byte range of `text` which is currently selected by the
+        /// input method.  If the range is empty, it indicates the position
+        /// of the cursor.
+        cursor: Range<usize>,
+    },
+}
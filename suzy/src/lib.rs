@@ -77,14 +77,21 @@ impl widget::Content for Data {
 pub mod adapter;
 pub mod animation;
 pub mod app;
+pub mod clipboard;
 pub mod dims;
+pub mod drag;
+pub mod gesture;
 pub mod graphics;
+pub mod keyboard;
+pub mod navigation;
 pub mod platform;
 #[macro_use]
 pub mod platforms;
 pub mod pointer;
 pub mod selectable;
+pub mod shortcut;
 pub mod text;
+pub mod tooltip;
 pub mod units;
 pub mod watch;
 pub mod widget;
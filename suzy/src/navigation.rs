@@ -0,0 +1,147 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+//! Directional navigation moves focus between widgets based on their
+//! position on screen, for devices without a pointer such as gamepads or
+//! TV remotes.
+//!
+//! When no widget handles a press of an arrow key, the app moves focus to
+//! the nearest focusable widget in that direction.  Widgets may override
+//! the target of a particular direction with
+//! [`UniqueHandle::set_nav_target`](crate::widget::UniqueHandle::set_nav_target).
+//!
+//! Platforms which support other navigation devices translate their input
+//! into [`NavAction`]s, which are delivered to widgets as the equivalent
+//! key events, so widgets only need to handle keyboard input.
+
+use crate::{dims::Rect, keyboard::Key};
+
+/// A direction in which focus can be moved.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum Direction {
+    /// Towards the left edge of the window.
+    Left,
+
+    /// Towards the right edge of the window.
+    Right,
+
+    /// Towards the top edge of the window.
+    Up,
+
+    /// Towards the bottom edge of the window.
+    Down,
+}
+
+impl Direction {
+    /// Get the arrow key corresponding to this direction.
+    #[must_use]
+    pub fn key(self) -> Key {
+        match self {
+            Self::Left => Key::ArrowLeft,
+            Self::Right => Key::ArrowRight,
+            Self::Up => Key::ArrowUp,
+            Self::Down => Key::ArrowDown,
+        }
+    }
+
+    /// Get the direction corresponding to an arrow key, if the key is an
+    /// arrow key.
+    #[must_use]
+    pub fn from_key(key: Key) -> Option<Self> {
+        match key {
+            Key::ArrowLeft => Some(Self::Left),
+            Key::ArrowRight => Some(Self::Right),
+            Key::ArrowUp => Some(Self::Up),
+            Key::ArrowDown => Some(Self::Down),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn index(self) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Right => 1,
+            Self::Up => 2,
+            Self::Down => 3,
+        }
+    }
+
+    /// Score how good a candidate is as the target of navigating from
+    /// `from` in this direction, lower is better.
+    ///
+    /// Returns None if the candidate is not in this direction at all.
+    pub(crate) fn score<A: Rect, B: Rect>(
+        self,
+        from: &A,
+        candidate: &B,
+    ) -> Option<f32> {
+        // flip everything so that we can consider only rightward and upward
+        // movement
+        let (from_main, cand_main, from_cross, cand_cross) = match self {
+            Self::Left | Self::Right => {
+                (from.x(), candidate.x(), from.y(), candidate.y())
+            }
+            Self::Up | Self::Down => {
+                (from.y(), candidate.y(), from.x(), candidate.x())
+            }
+        };
+        let sign = match self {
+            Self::Left | Self::Down => -1.0,
+            Self::Right | Self::Up => 1.0,
+        };
+        let from_center = sign * from_main.center();
+        let cand_center = sign * cand_main.center();
+        if cand_center <= from_center {
+            return None;
+        }
+        let (from_start, from_end) = if sign > 0.0 {
+            (from_main.start(), from_main.end())
+        } else {
+            (-from_main.end(), -from_main.start())
+        };
+        let cand_start = if sign > 0.0 {
+            cand_main.start()
+        } else {
+            -cand_main.end()
+        };
+        if cand_start < from_start {
+            // the candidate extends behind the current widget, e.g. it
+            // contains it
+            return None;
+        }
+        let main_gap = (cand_start - from_end).max(0.0);
+        let cross_gap = (cand_cross.start() - from_cross.end())
+            .max(from_cross.start() - cand_cross.end())
+            .max(0.0);
+        let cross_offset = (cand_cross.center() - from_cross.center()).abs();
+        // staying in line is strongly preferred over being close
+        Some(main_gap + 2.0 * cross_gap + 0.1 * cross_offset)
+    }
+}
+
+/// A high-level navigation action, produced by navigation devices like
+/// gamepads.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[non_exhaustive]
+pub enum NavAction {
+    /// Move focus in a direction.
+    Navigate(Direction),
+
+    /// Activate the focused widget, e.g. click a button.
+    Activate,
+
+    /// Go back, e.g. dismiss a popup.
+    Back,
+}
+
+impl NavAction {
+    /// Get the key which widgets should treat as equivalent to this action.
+    #[must_use]
+    pub fn key(self) -> Key {
+        match self {
+            Self::Navigate(direction) => direction.key(),
+            Self::Activate => Key::Enter,
+            Self::Back => Key::Escape,
+        }
+    }
+}
@@ -31,4 +31,24 @@ pub trait Text<Style> {
     fn clear(&mut self);
     fn push_span(&mut self, style: Style, text: &str);
     fn finish(&mut self);
+
+    /// Get the position of a caret placed before the byte at `index` of
+    /// the text pushed since the last call to `clear`.  An index equal to
+    /// the length of the text places the caret after the last character.
+    ///
+    /// This is only valid after `finish` has been called.  Platforms which
+    /// are unable to measure text return `None`.
+    fn caret(&self, index: usize) -> Option<text::Caret> {
+        let _unused = index;
+        None
+    }
+
+    /// Find the byte index of the caret position nearest to a point.
+    ///
+    /// This is only valid after `finish` has been called.  Platforms which
+    /// are unable to measure text return `None`.
+    fn caret_at(&self, point: [f32; 2]) -> Option<usize> {
+        let _unused = point;
+        None
+    }
 }
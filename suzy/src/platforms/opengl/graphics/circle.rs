@@ -1,6 +1,11 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2025 Violet Leonard */
 
+use std::{
+    convert::TryFrom,
+    f32::consts::{FRAC_PI_4, TAU},
+};
+
 use crate::{
     graphics::{Color, DrawContext, Graphic},
     platforms::opengl,
@@ -22,6 +27,13 @@ pub struct Circle {
     pub center: [f32; 2],
     pub outer_radius: f32,
     pub inner_radius: f32,
+    /// The angle the arc starts at, in radians counterclockwise from the
+    /// positive x axis.
+    pub start_angle: f32,
+    /// The angle the arc covers, in radians counterclockwise from
+    /// `start_angle`; negative values go clockwise.  The whole circle is
+    /// drawn if this is at least a full turn.
+    pub sweep: f32,
 }
 
 impl Default for Circle {
@@ -32,6 +44,8 @@ impl Default for Circle {
             center: [50.0, 50.0],
             outer_radius: 50.0,
             inner_radius: 0.0,
+            start_angle: 0.0,
+            sweep: TAU,
         }
     }
 }
@@ -51,6 +65,10 @@ impl Graphic<OpenGlRenderPlatform> for Circle {
             bottom: self.center[1] - self.outer_radius - 0.5,
             top: self.center[1] + self.outer_radius + 0.5,
         };
+        if self.sweep.abs() < TAU {
+            self.draw_arc(ctx, bbox);
+            return;
+        }
         let Some(BatchRef { batch, mut uv_rect }) =
             ctx.find_batch(&self.texture, 9, &[bbox])
         else {
@@ -107,6 +125,89 @@ impl Graphic<OpenGlRenderPlatform> for Circle {
 }
 
 impl Circle {
+    /// Draw part of the circle, as a fan of triangles around the center.
+    fn draw_arc(
+        &self,
+        ctx: &mut DrawContext<'_, OpenGlRenderPlatform>,
+        bbox: BoundingBox,
+    ) {
+        if self.sweep == 0.0 {
+            return;
+        }
+        let points = arc_points(self.start_angle, self.sweep);
+        let num_vertices = u16::try_from(points.len() + 1)
+            .expect("an arc should have a small number of vertices");
+        let Some(BatchRef { batch, uv_rect }) =
+            ctx.find_batch(&self.texture, num_vertices, &[bbox])
+        else {
+            return;
+        };
+        let uv_rect = match uv_rect {
+            UvRect::SolidColor(u, v) => UvRectValues {
+                left: u.to_f32(),
+                right: u.to_f32(),
+                bottom: v.to_f32(),
+                top: v.to_f32(),
+            },
+            UvRect::F32(uv_rect_f32) => uv_rect_f32,
+            UvRect::U16(uv_rect_u16) => UvRectValues {
+                left: uv_rect_u16.left.to_f32(),
+                right: uv_rect_u16.right.to_f32(),
+                bottom: uv_rect_u16.bottom.to_f32(),
+                top: uv_rect_u16.top.to_f32(),
+            },
+        };
+        let index_offset: u16 = batch.vertices.len_u16();
+        let color = self.color.rgba8();
+        let (config, smoothing) = self.alpha_config();
+        let half_size = self.outer_radius + 0.5;
+        let center = std::iter::once([0.0, 0.0]);
+        for [sx, sy] in center.chain(points.iter().copied()) {
+            let fx = sx.midpoint(1.0);
+            let fy = sy.midpoint(1.0);
+            batch.vertices.push(Vertex {
+                xy: [
+                    self.center[0] + sx * half_size,
+                    self.center[1] + sy * half_size,
+                ],
+                uv: [
+                    uv_rect.left + fx * (uv_rect.right - uv_rect.left),
+                    uv_rect.bottom + fy * (uv_rect.top - uv_rect.bottom),
+                ],
+                color,
+                config: config.distance(sx.abs(), sy.abs()),
+                smoothing,
+            });
+        }
+        for index in 1..(num_vertices - 1) {
+            batch.indices.extend([
+                index_offset,
+                index_offset + index,
+                index_offset + index + 1,
+            ]);
+        }
+    }
+
+    fn alpha_config(&self) -> (VertexConfig, f32) {
+        // add half a pixel so the middle of the antialiased edge is at the
+        // outer radius
+        let outer_radius_aa = self.outer_radius + 0.5;
+        let peak = if self.inner_radius == 0.0 {
+            1.0
+        } else {
+            // the peak needs to be half-way between the outer edge and the
+            // inner edge, mesured backwards because the computed alpha
+            // decreases with more distance.
+            let outer_radius_norm =
+                1.0 - (self.outer_radius / outer_radius_aa);
+            let inner_radius_norm =
+                1.0 - (self.inner_radius / outer_radius_aa);
+            inner_radius_norm.midpoint(outer_radius_norm)
+        };
+        let config = VertexConfig::new().alpha_base(0.0).alpha_peak(peak);
+        (config, outer_radius_aa)
+    }
+
     fn push_vertices<Uv>(
         &self,
         batch: &mut Batch,
@@ -128,23 +229,7 @@ impl Circle {
             (bbox.top, uv_rect.top, false),
         ];
         let color = self.color.rgba8();
-        // add half a pixel so the middle of the antialiased edge is at the
-        // outer radius
-        let outer_radius_aa = self.outer_radius + 0.5;
-        let peak = if self.inner_radius == 0.0 {
-            1.0
-        } else {
-            // the peak needs to be half-way between the outer edge and the
-            // inner edge, mesured backwards because the computed alpha
-            // decreases with more distance.
-            let outer_radius_norm =
-                1.0 - (self.outer_radius / outer_radius_aa);
-            let inner_radius_norm =
-                1.0 - (self.inner_radius / outer_radius_aa);
-            inner_radius_norm.midpoint(outer_radius_norm)
-        };
-        let config = VertexConfig::new().alpha_base(0.0).alpha_peak(peak);
-        let smoothing = outer_radius_aa;
+        let (config, smoothing) = self.alpha_config();
         for (y, v, y_inside) in vertical_values {
             for &(x, u, x_inside) in &horiz_values {
                 let config = config.vector(x_inside, y_inside);
@@ -179,6 +264,37 @@ static INDICES: [u8; 8 * 3] = [
     5, 8, 7,
 ];
 
+/// Find the points around the edge of a fan of triangles covering an arc.
+///
+/// The points are on the edge of a square from -1.0 to 1.0.  Besides the
+/// ends of the arc, every corner and the middle of every side of the square
+/// which the arc passes is included, so that no triangle crosses an axis,
+/// and the distance from the center can be interpolated across each one.
+fn arc_points(start: f32, sweep: f32) -> Vec<[f32; 2]> {
+    let end = start + sweep;
+    let step = FRAC_PI_4.copysign(sweep);
+    // the first multiple of an eighth of a turn past the start
+    let mut angle = if sweep > 0.0 {
+        (start / FRAC_PI_4).floor() + 1.0
+    } else {
+        (start / FRAC_PI_4).ceil() - 1.0
+    } * FRAC_PI_4;
+    let mut angles = vec![start];
+    while (end - angle) * sweep.signum() > 0.0 {
+        angles.push(angle);
+        angle += step;
+    }
+    angles.push(end);
+    angles
+        .into_iter()
+        .map(|angle| {
+            let (sin, cos) = angle.sin_cos();
+            let scale = cos.abs().max(sin.abs());
+            [cos / scale, sin / scale]
+        })
+        .collect()
+}
+
 fn precise_midpoint(a: u16, b: u16) -> Option<u16> {
     if a.abs_diff(b).is_multiple_of(2) {
         Some(a.midpoint(b))
@@ -186,3 +302,33 @@ fn precise_midpoint(a: u16, b: u16) -> Option<u16> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use super::arc_points;
+
+    fn assert_points(actual: &[[f32; 2]], expected: &[[f32; 2]]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?}");
+        for (a, e) in actual.iter().zip(expected) {
+            let close =
+                (a[0] - e[0]).abs() < 1e-5 && (a[1] - e[1]).abs() < 1e-5;
+            assert!(close, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn arc_splits_at_corners() {
+        let points = arc_points(0.0, FRAC_PI_2);
+        assert_points(&points, &[[1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+        // clockwise from the top, halfway around
+        let points = arc_points(FRAC_PI_2, -PI);
+        assert_points(
+            &points,
+            &[[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [1.0, -1.0], [0.0, -1.0]],
+        );
+        let points = arc_points(0.1, 0.2);
+        assert_eq!(points.len(), 2);
+    }
+}
@@ -2,7 +2,7 @@
 /* Copyright © 2023 Violet Leonard */
 
 use crate::{
-    graphics::{DrawContext, Graphic},
+    graphics::{Conditional, DrawContext, Graphic},
     platforms::opengl,
     widget::WidgetGraphic,
 };
@@ -37,6 +37,33 @@ where
     }
 }
 
+/// A conditional mask clips the widget's children only while it is
+/// enabled.
+impl<T> WidgetGraphic<OpenGlRenderPlatform> for Conditional<Mask<T>>
+where
+    T: Graphic<OpenGlRenderPlatform>,
+{
+    type BeforeGetter = fn(&mut ()) -> Conditional<Push<'_, T>>;
+
+    type AfterGetter = fn(&mut ()) -> Conditional<Pop>;
+
+    fn before_children(&mut self) -> Conditional<Push<'_, T>> {
+        Conditional {
+            enable: self.enable,
+            graphic: Push {
+                mask: &mut self.graphic,
+            },
+        }
+    }
+
+    fn after_children(&mut self) -> Conditional<Pop> {
+        Conditional {
+            enable: self.enable,
+            graphic: Pop,
+        }
+    }
+}
+
 impl<T> Graphic<OpenGlRenderPlatform> for Push<'_, T>
 where
     T: Graphic<OpenGlRenderPlatform>,
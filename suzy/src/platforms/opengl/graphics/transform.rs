@@ -34,6 +34,10 @@ impl WidgetGraphic<OpenGlRenderPlatform> for Transform {
     fn after_children(&mut self) -> Pop<'_> {
         Pop { trans: self }
     }
+
+    fn map_point_to_children(&self, point: [f32; 2]) -> [f32; 2] {
+        self.matrix.unproject_point(point)
+    }
 }
 
 impl Graphic<OpenGlRenderPlatform> for Push<'_> {
@@ -18,8 +18,6 @@ varying lowp vec2 pass_distance;
 varying mediump float pass_smoothing;
 
 void main() {
-    lowp float dy = float(in_config.z > 0.5);
-    lowp float dx = float(max(in_config.z - 0.5 * dy, 0.0) > 0.25);
     gl_Position = TRANSFORM * vec4(in_xy, 0, 1);
     pass_color = in_color;
     highp float alpha_base = in_config.x;
@@ -29,6 +27,6 @@ void main() {
         in_config.y
     );
     pass_uv = in_uv;
-    pass_distance = vec2(dx, dy);
+    pass_distance = in_config.zw;
     pass_smoothing = in_smoothing;
 }
@@ -72,6 +72,34 @@ impl Mat4 {
     }
 }
 
+impl Mat4 {
+    /// Find the 2D point which this matrix transforms to `point`.
+    ///
+    /// Points are assumed to lie on the plane z = 0.  If the matrix is not
+    /// invertible on that plane, the result will be NaN.
+    #[must_use]
+    pub fn unproject_point(&self, point: [f32; 2]) -> [f32; 2] {
+        // the projective 2D transform this applies to the z = 0 plane
+        let [a, b, c] = [self[(0u8, 0u8)], self[(0u8, 1u8)], self[(0u8, 3u8)]];
+        let [d, e, f] = [self[(1u8, 0u8)], self[(1u8, 1u8)], self[(1u8, 3u8)]];
+        let [g, h, i] = [self[(3u8, 0u8)], self[(3u8, 1u8)], self[(3u8, 3u8)]];
+        let cofactors = [
+            [e * i - f * h, c * h - b * i, b * f - c * e],
+            [f * g - d * i, a * i - c * g, c * d - a * f],
+            [d * h - e * g, b * g - a * h, a * e - b * d],
+        ];
+        let det =
+            a * cofactors[0][0] + b * cofactors[1][0] + c * cofactors[2][0];
+        if det == 0.0 {
+            return [f32::NAN; 2];
+        }
+        let [x, y] = point;
+        let apply = |row: [f32; 3]| row[0] * x + row[1] * y + row[2];
+        let w = apply(cofactors[2]);
+        [apply(cofactors[0]) / w, apply(cofactors[1]) / w]
+    }
+}
+
 impl AsRef<[f32; 16]> for Mat4 {
     fn as_ref(&self) -> &[f32; 16] {
         &self.data
@@ -317,6 +345,19 @@ mod test {
         assert_eq!(MAT_ANS, MAT_A * MAT_B);
     }
 
+    #[test]
+    fn check_unproject() {
+        let mat = Mat4::translate(30.0, -20.0)
+            * Mat4::rotate(0.5)
+            * Mat4::scale(2.0, 3.0);
+        let point = [7.0, -4.0];
+        let [x, y, _, w] = mat * [point[0], point[1], 0.0, 1.0];
+        let result = mat.unproject_point([x / w, y / w]);
+        assert!((result[0] - point[0]).abs() <= 1e-4);
+        assert!((result[1] - point[1]).abs() <= 1e-4);
+        assert!(Mat4::scale(0.0, 1.0).unproject_point(point)[0].is_nan());
+    }
+
     #[test]
     fn check_rotate() {
         let result = Mat4::rotate(std::f32::consts::PI) * SOME_VEC;
@@ -99,11 +99,20 @@ impl VertexConfig {
 
     #[must_use]
     pub fn vector(self, x_inside: bool, y_inside: bool) -> Self {
-        let Self([x, y, _, w]) = self;
-        let dx = if x_inside { 0 } else { 64 };
-        let dy = if y_inside { 0 } else { 128 };
-        let z = dx + dy;
-        Self([x, y, z, w])
+        let dx = if x_inside { 0.0 } else { 1.0 };
+        let dy = if y_inside { 0.0 } else { 1.0 };
+        self.distance(dx, dy)
+    }
+
+    // The distance is interpolated between vertices, and its length is used
+    // to fade the alpha towards the edge of a shape, e.g. 0.0 at the center
+    // of a circle and 1.0 at its edge.  Each component ranges from 0.0 to
+    // 1.0, and is quantized to a byte.
+
+    #[must_use]
+    pub fn distance(self, dx: f32, dy: f32) -> Self {
+        let Self([x, y, _, _]) = self;
+        Self([x, y, dx.quantize_u8(), dy.quantize_u8()])
     }
 }
 
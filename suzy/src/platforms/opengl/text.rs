@@ -91,6 +91,41 @@ pub struct Text {
     vertices: Vec<VertexSet>,
     layout_changed: WatchedMeta<'static>,
     calc: FontCharCalc,
+    carets: CaretSet,
+}
+
+/// The positions of each character, used to place carets.
+#[derive(Default)]
+struct CaretSet {
+    carets: Vec<CaretPos>,
+    line_start_index: usize,
+    line: usize,
+    text_len: usize,
+    /// The ascent and descent of the most recent span.
+    metrics: [f32; 2],
+    /// The line spacing of the most recent span.
+    line_spacing: f32,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CaretPos {
+    index: usize,
+    xy: [f32; 2],
+    ascent: f32,
+    descent: f32,
+    line: usize,
+}
+
+impl CaretPos {
+    fn caret(&self) -> text::Caret {
+        let [x, y] = self.xy;
+        text::Caret {
+            x,
+            bottom: y + self.descent,
+            top: y + self.ascent,
+            line: self.line,
+        }
+    }
 }
 
 impl Text {
@@ -104,6 +139,14 @@ impl Text {
             );
             vs.line_start_index = vs.vertices.len();
         }
+        let carets = &mut self.carets;
+        self.calc.align_line(
+            carets.carets[carets.line_start_index..]
+                .iter_mut()
+                .map(|caret| &mut caret.xy),
+        );
+        carets.line_start_index = carets.carets.len();
+        carets.line += 1;
         self.calc.reset_line();
     }
 }
@@ -155,6 +198,10 @@ impl crate::platform::graphics::Text<TextStyle> for Text {
         self.layout_changed.watched_auto();
         self.calc.reset();
         self.vertices.clear();
+        self.carets.carets.clear();
+        self.carets.line_start_index = 0;
+        self.carets.line = 0;
+        self.carets.text_len = 0;
     }
 
     fn push_span(&mut self, style: TextStyle, text: &str) {
@@ -188,11 +235,20 @@ impl crate::platform::graphics::Text<TextStyle> for Text {
                 self.vertices.push(vs);
                 idx
             });
+        let ascent = font.data.ascent * style.font_size;
+        let descent = font.data.descent * style.font_size;
+        self.carets.metrics = [ascent, descent];
+        self.carets.line_spacing = font.data.line_spacing * style.font_size;
         let mut remaining = text;
-        while !remaining.is_empty() {
+        // even an empty span is laid out, so its line has a height for
+        // carets to use
+        loop {
             let vertex_set = &mut self.vertices[vertex_set_index];
             let vertices = &mut vertex_set.vertices;
             let indices = vertex_set.indices.unsorted();
+            let carets = &mut self.carets.carets;
+            let line = self.carets.line;
+            let base = self.carets.text_len + (text.len() - remaining.len());
             let params = CalcParams {
                 font: &font.data,
                 handle_glyph: |glyph: calc::GlyphMetrics| {
@@ -225,6 +281,15 @@ impl crate::platform::graphics::Text<TextStyle> for Text {
                         }
                     }
                 },
+                handle_caret: |offset, xy| {
+                    carets.push(CaretPos {
+                        index: base + offset,
+                        xy,
+                        ascent,
+                        descent,
+                        line,
+                    });
+                },
             };
             let (consumed, line_break) =
                 self.calc.push_span(params, remaining);
@@ -232,16 +297,80 @@ impl crate::platform::graphics::Text<TextStyle> for Text {
             if line_break {
                 self.finish_line();
             }
+            if remaining.is_empty() {
+                break;
+            }
         }
+        self.carets.text_len += text.len();
     }
 
     fn finish(&mut self) {
+        let carets = &mut self.carets;
+        let cursor = &mut self.calc.cursor;
+        if carets.line > 0 && cursor.current_line_height == 0.0 {
+            // the text ended with a line break, give the empty line after
+            // it a height
+            cursor.current_line_height = carets.line_spacing;
+        }
+        let [ascent, descent] = carets.metrics;
+        carets.carets.push(CaretPos {
+            index: carets.text_len,
+            xy: [cursor.x, cursor.y],
+            ascent,
+            descent,
+            line: carets.line,
+        });
         self.finish_line();
         for vs in &mut self.vertices {
             self.calc
                 .align_block(vs.vertices.iter_mut().map(|v| &mut v.xy));
             vs.indices.make_final();
         }
+        self.calc.align_block(
+            self.carets.carets.iter_mut().map(|caret| &mut caret.xy),
+        );
+    }
+
+    fn caret(&self, index: usize) -> Option<text::Caret> {
+        let carets = &self.carets.carets;
+        let found = carets.binary_search_by_key(&index, |caret| caret.index);
+        let position = match found {
+            Ok(position) => position,
+            Err(position) => position.checked_sub(1)?,
+        };
+        carets.get(position).map(CaretPos::caret)
+    }
+
+    fn caret_at(&self, point: [f32; 2]) -> Option<usize> {
+        let [x, y] = point;
+        let distance_to_line = |caret: &CaretPos| {
+            let caret = caret.caret();
+            if y < caret.bottom {
+                caret.bottom - y
+            } else if y > caret.top {
+                y - caret.top
+            } else {
+                0.0
+            }
+        };
+        let line = self
+            .carets
+            .carets
+            .iter()
+            .min_by(|a, b| {
+                distance_to_line(a).total_cmp(&distance_to_line(b))
+            })?
+            .line;
+        self.carets
+            .carets
+            .iter()
+            .filter(|caret| caret.line == line)
+            .min_by(|a, b| {
+                let a = (a.xy[0] - x).abs();
+                let b = (b.xy[0] - x).abs();
+                a.total_cmp(&b)
+            })
+            .map(|caret| caret.index)
     }
 }
 
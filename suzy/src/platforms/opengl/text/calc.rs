@@ -21,9 +21,12 @@ pub(super) struct Cursor {
     pub current_line_height: f32,
 }
 
-pub(super) struct CalcParams<'a, F> {
+pub(super) struct CalcParams<'a, F, C> {
     pub font: &'a font::FontData,
     pub handle_glyph: F,
+    /// Called with the byte offset of each character which is laid out,
+    /// and the position of the cursor before it.
+    pub handle_caret: C,
 }
 
 impl FontCharCalc {
@@ -86,7 +89,11 @@ impl FontCharCalc {
 
     pub fn push_span(
         &mut self,
-        mut params: CalcParams<'_, impl FnMut(GlyphMetrics)>,
+        mut params: CalcParams<
+            '_,
+            impl FnMut(GlyphMetrics),
+            impl FnMut(usize, [f32; 2]),
+        >,
         text: &str,
     ) -> (usize, bool) {
         let mut remaining = text;
@@ -120,7 +127,8 @@ impl FontCharCalc {
             let word_end = remaining.find(is_breaking_space);
             match word_end {
                 None => {
-                    let (_, cons) = self.push_word(&mut params, remaining);
+                    let (_, cons) =
+                        self.push_word(&mut params, remaining, consumed);
                     consumed += cons;
                     break;
                 }
@@ -130,6 +138,10 @@ impl FontCharCalc {
                         "remaining text was not empty,",
                         "but str::chars returned no items"
                     ));
+                    (params.handle_caret)(
+                        consumed,
+                        [self.cursor.x, self.cursor.y],
+                    );
                     consumed +=
                         iter.next().map_or(remaining.len(), |(i, _)| i);
                     if is_line_break(ch) {
@@ -146,7 +158,8 @@ impl FontCharCalc {
                 }
                 Some(index) => {
                     let word = &remaining[..index];
-                    let (lch, cons) = self.push_word(&mut params, word);
+                    let (lch, cons) =
+                        self.push_word(&mut params, word, consumed);
                     last_ch = lch;
                     consumed += cons;
                     if cons < word.len() {
@@ -161,16 +174,23 @@ impl FontCharCalc {
 
     pub fn push_word(
         &mut self,
-        params: &mut CalcParams<'_, impl FnMut(GlyphMetrics)>,
+        params: &mut CalcParams<
+            '_,
+            impl FnMut(GlyphMetrics),
+            impl FnMut(usize, [f32; 2]),
+        >,
         word: &str,
+        offset: usize,
     ) -> (Option<char>, usize) {
         if self.cursor.x == 0.0 {
-            return self.push_word_splitwrap(params, word);
+            return self.push_word_splitwrap(params, word, offset);
         }
         let mut glyphs = Vec::new();
+        let mut carets = Vec::new();
         let mut last_ch = None;
         let mut cursor = self.cursor;
-        for ch in word.chars() {
+        for (i, ch) in word.char_indices() {
+            carets.push((offset + i, [cursor.x, cursor.y]));
             if let Some(glyph) = params.font.glyph(ch) {
                 let kerning = last_ch
                     .and_then(|left| params.font.kerning(left, ch))
@@ -191,13 +211,16 @@ impl FontCharCalc {
         for glyph in glyphs {
             (params.handle_glyph)(glyph);
         }
+        for (index, pos) in carets {
+            (params.handle_caret)(index, pos);
+        }
         self.cursor = cursor;
         (last_ch, word.len())
     }
 
-    pub fn push_whitespace(
+    pub fn push_whitespace<F, C>(
         cursor: &mut Cursor,
-        params: &CalcParams<'_, impl FnMut(GlyphMetrics)>,
+        params: &CalcParams<'_, F, C>,
         last_ch: Option<char>,
         white_char: char,
     ) {
@@ -206,6 +229,7 @@ impl FontCharCalc {
         } else {
             match white_char {
                 ' ' => 0.25,
+                '\t' => 1.0,
                 '\u{2002}' => 0.5,
                 '\u{2003}' => 1.0,
                 '\u{2004}' => 1.0 / 3.0,
@@ -223,11 +247,17 @@ impl FontCharCalc {
 
     fn push_word_splitwrap(
         &mut self,
-        params: &mut CalcParams<'_, impl FnMut(GlyphMetrics)>,
+        params: &mut CalcParams<
+            '_,
+            impl FnMut(GlyphMetrics),
+            impl FnMut(usize, [f32; 2]),
+        >,
         word: &str,
+        offset: usize,
     ) -> (Option<char>, usize) {
         let mut last_ch = None;
         for (i, ch) in word.char_indices() {
+            let caret_pos = [self.cursor.x, self.cursor.y];
             if let Some(glyph) = params.font.glyph(ch) {
                 let kerning = last_ch
                     .and_then(|left| params.font.kerning(left, ch))
@@ -237,14 +267,23 @@ impl FontCharCalc {
                 if self.cursor.x + kerning + advance > self.layout.wrap_width {
                     return (None, i);
                 }
+                (params.handle_caret)(offset + i, caret_pos);
                 self.cursor.x += kerning;
                 (params.handle_glyph)(Self::position_char(
                     &mut self.cursor,
                     *glyph,
                 ));
                 self.cursor.x += advance;
-            } else if ch.is_whitespace() {
-                Self::push_whitespace(&mut self.cursor, params, last_ch, ch);
+            } else {
+                (params.handle_caret)(offset + i, caret_pos);
+                if ch.is_whitespace() {
+                    Self::push_whitespace(
+                        &mut self.cursor,
+                        params,
+                        last_ch,
+                        ch,
+                    );
+                }
             }
             last_ch = Some(ch);
         }
@@ -3,17 +3,25 @@
 
 #![allow(missing_docs)]
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    convert::TryFrom,
+    ops::{Deref, DerefMut},
+};
 
 use crate::{
+    app::TextInputState,
     graphics::Color,
+    keyboard::{Key, KeyAction, KeyEvent, Modifiers, ScanCode, TextEvent},
+    navigation::{Direction, NavAction},
     platforms::opengl::OpenGlRenderPlatform,
     pointer::{AltMouseButton, PointerAction, PointerEventData, PointerId},
     watch::WatchedValueCore,
 };
 
+mod clipboard;
 mod window;
 
+pub use clipboard::SdlClipboard;
 pub use window::{Window, WindowSettings};
 
 pub struct SdlPlatform {
@@ -23,6 +31,9 @@ pub struct SdlPlatform {
 impl SdlPlatform {
     #[must_use]
     pub fn new() -> Self {
+        // touches are delivered as touch pointers, so SDL should not also
+        // deliver them as mouse events
+        sdl2::hint::set("SDL_TOUCH_MOUSE_EVENTS", "0");
         SdlPlatform {
             sdl: sdl2::init().expect("Failed to initialize SDL2"),
         }
@@ -34,6 +45,15 @@ impl SdlPlatform {
         app: &mut crate::app::App<OpenGlRenderPlatform>,
     ) -> Result<(), String> {
         let mut event_pump = self.sdl.event_pump()?;
+        // SDL starts with text input enabled, but suzy expects widgets to
+        // request it
+        let mut text_input = TextInputState::default();
+        window.apply_text_input(&text_input);
+        app.set_clipboard(SdlClipboard::new(&self.sdl.video()?));
+        // game controllers are optional, so failing to initialize them
+        // should not prevent the app from running
+        let controller_subsystem = self.sdl.game_controller().ok();
+        let mut controllers = Vec::new();
         loop {
             use sdl2::event::{Event, WindowEvent};
             app.start_frame(std::time::Instant::now());
@@ -46,6 +66,16 @@ impl SdlPlatform {
                     } => {
                         return Ok(());
                     }
+                    Event::ControllerDeviceAdded { which, .. } => {
+                        if let Some(subsystem) = &controller_subsystem {
+                            controllers.extend(subsystem.open(which).ok());
+                        }
+                    }
+                    Event::ControllerDeviceRemoved { which, .. } => {
+                        controllers.retain(|controller| {
+                            controller.instance_id() != which
+                        });
+                    }
                     event => app.handle_event(window, event, || {
                         let state = event_pump.mouse_state();
                         [state.x() as f32, state.y() as f32]
@@ -53,6 +83,12 @@ impl SdlPlatform {
                 }
             }
             app.update_watches();
+            let requested_text_input =
+                app.state().text_input().get_unwatched();
+            if requested_text_input != text_input {
+                text_input = requested_text_input;
+                window.apply_text_input(&text_input);
+            }
             window.gl_win.clear();
             window.gl_win.draw_app(app);
             window.flip();
@@ -94,18 +130,18 @@ impl AppHandleSdlEvent for crate::app::App<OpenGlRenderPlatform> {
                         window.recalculate_viewport();
                     }
                     WindowEvent::Leave => {
-                        self.pointer_event(PointerEventData {
-                            id: PointerId::Mouse,
-                            action: PointerAction::Hover(f32::NAN, f32::NAN),
-                            x: f32::NAN,
-                            y: f32::NAN,
-                        });
+                        self.pointer_leave(PointerId::Mouse);
                     }
                     _ => {}
                 };
             }
             Event::MouseButtonDown {
-                mouse_btn, x, y, ..
+                timestamp,
+                mouse_btn,
+                clicks,
+                x,
+                y,
+                ..
             } => {
                 let height = self.state().window_height().get_unwatched();
                 let [x, y] = [x as f32, height - y as f32];
@@ -116,15 +152,20 @@ impl AppHandleSdlEvent for crate::app::App<OpenGlRenderPlatform> {
                     }
                     AltMouseButtonResult::Unknown => return,
                 };
-                self.pointer_event(PointerEventData {
-                    id: PointerId::Mouse,
-                    action,
-                    x,
-                    y,
-                });
+                self.pointer_event(
+                    PointerEventData::new(PointerId::Mouse, action, x, y)
+                        .with_modifiers(window.mod_state().to_suzy_modifiers())
+                        .with_click_count(clicks.into())
+                        .with_timestamp(sdl_timestamp(timestamp)),
+                );
             }
             Event::MouseButtonUp {
-                mouse_btn, x, y, ..
+                timestamp,
+                mouse_btn,
+                clicks,
+                x,
+                y,
+                ..
             } => {
                 let height = self.state().window_height().get_unwatched();
                 let [x, y] = [x as f32, height - y as f32];
@@ -135,14 +176,15 @@ impl AppHandleSdlEvent for crate::app::App<OpenGlRenderPlatform> {
                     }
                     AltMouseButtonResult::Unknown => return,
                 };
-                self.pointer_event(PointerEventData {
-                    id: PointerId::Mouse,
-                    action,
-                    x,
-                    y,
-                });
+                self.pointer_event(
+                    PointerEventData::new(PointerId::Mouse, action, x, y)
+                        .with_modifiers(window.mod_state().to_suzy_modifiers())
+                        .with_click_count(clicks.into())
+                        .with_timestamp(sdl_timestamp(timestamp)),
+                );
             }
             Event::MouseMotion {
+                timestamp,
                 mousestate,
                 x,
                 y,
@@ -153,35 +195,151 @@ impl AppHandleSdlEvent for crate::app::App<OpenGlRenderPlatform> {
                 let height = self.state().window_height().get_unwatched();
                 let [x, y] = [x as f32, height - y as f32];
                 let [xrel, yrel] = [xrel as f32, -(yrel as f32)];
-                let pointer_event = if mousestate.left() {
-                    PointerEventData {
-                        id: PointerId::Mouse,
-                        action: PointerAction::Move(xrel, yrel),
-                        x,
-                        y,
-                    }
+                let action = if mousestate.left() {
+                    PointerAction::Move(xrel, yrel)
                 } else {
-                    PointerEventData {
-                        id: PointerId::Mouse,
-                        action: PointerAction::Hover(xrel, yrel),
-                        x,
-                        y,
-                    }
+                    PointerAction::Hover(xrel, yrel)
                 };
-                self.pointer_event(pointer_event);
+                self.pointer_event(
+                    PointerEventData::new(PointerId::Mouse, action, x, y)
+                        .with_modifiers(window.mod_state().to_suzy_modifiers())
+                        .with_timestamp(sdl_timestamp(timestamp)),
+                );
             }
-            Event::MouseWheel { x, y, .. } => {
+            Event::MouseWheel {
+                timestamp, x, y, ..
+            } => {
                 let height = self.state().window_height().get_unwatched();
                 let [mouse_x, mouse_y] = mouse_pos();
                 let xrel = x as f32 * 125.0;
                 let yrel = -(y as f32 * 125.0);
-                let x = mouse_x;
-                let y = height - mouse_y;
-                self.pointer_event(PointerEventData {
-                    id: PointerId::Mouse,
-                    action: PointerAction::Wheel(xrel, yrel),
-                    x,
-                    y,
+                let action = PointerAction::Wheel(xrel, yrel);
+                self.pointer_event(
+                    PointerEventData::new(
+                        PointerId::Mouse,
+                        action,
+                        mouse_x,
+                        height - mouse_y,
+                    )
+                    .with_modifiers(window.mod_state().to_suzy_modifiers())
+                    .with_timestamp(sdl_timestamp(timestamp)),
+                );
+            }
+            Event::FingerDown {
+                timestamp,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            } => finger_event(
+                self,
+                window,
+                FingerEvent {
+                    timestamp,
+                    id: touch_pointer_id(touch_id, finger_id),
+                    action: PointerAction::Down,
+                    pos: [x, y],
+                    pressure,
+                },
+            ),
+            Event::FingerMotion {
+                timestamp,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                dx,
+                dy,
+                pressure,
+            } => finger_event(
+                self,
+                window,
+                FingerEvent {
+                    timestamp,
+                    id: touch_pointer_id(touch_id, finger_id),
+                    action: PointerAction::Move(dx, dy),
+                    pos: [x, y],
+                    pressure,
+                },
+            ),
+            Event::FingerUp {
+                timestamp,
+                touch_id,
+                finger_id,
+                x,
+                y,
+                pressure,
+                ..
+            } => finger_event(
+                self,
+                window,
+                FingerEvent {
+                    timestamp,
+                    id: touch_pointer_id(touch_id, finger_id),
+                    action: PointerAction::Up,
+                    pos: [x, y],
+                    pressure,
+                },
+            ),
+            Event::KeyDown {
+                keycode,
+                scancode,
+                keymod,
+                repeat,
+                ..
+            } => {
+                self.key_event(
+                    KeyEvent::new(KeyAction::Down, keycode.to_suzy_key())
+                        .with_scancode(scancode.to_suzy_scancode())
+                        .with_modifiers(keymod.to_suzy_modifiers())
+                        .with_repeat(repeat),
+                );
+            }
+            Event::KeyUp {
+                keycode,
+                scancode,
+                keymod,
+                ..
+            } => {
+                self.key_event(
+                    KeyEvent::new(KeyAction::Up, keycode.to_suzy_key())
+                        .with_scancode(scancode.to_suzy_scancode())
+                        .with_modifiers(keymod.to_suzy_modifiers()),
+                );
+            }
+            Event::ControllerButtonDown { button, .. } => {
+                if let Some(nav) = button.to_suzy_nav_action() {
+                    self.nav_event(nav, KeyAction::Down);
+                }
+            }
+            Event::ControllerButtonUp { button, .. } => {
+                if let Some(nav) = button.to_suzy_nav_action() {
+                    self.nav_event(nav, KeyAction::Up);
+                }
+            }
+            Event::TextInput { text, .. } => {
+                self.text_event(TextEvent::Input(text));
+            }
+            Event::TextEditing {
+                text,
+                start,
+                length,
+                ..
+            } => {
+                // SDL reports the cursor in characters, suzy uses bytes
+                let byte_offset = |chars: i32| {
+                    let chars = usize::try_from(chars).unwrap_or(0);
+                    text.char_indices()
+                        .nth(chars)
+                        .map_or(text.len(), |(index, _)| index)
+                };
+                let cursor_start = byte_offset(start);
+                let cursor_end = byte_offset(start.saturating_add(length));
+                self.text_event(TextEvent::Editing {
+                    cursor: cursor_start..cursor_end,
+                    text,
                 });
             }
             _ => {}
@@ -189,6 +347,57 @@ impl AppHandleSdlEvent for crate::app::App<OpenGlRenderPlatform> {
     }
 }
 
+/// The fields of an SDL finger event, in SDL's normalized coordinates.
+struct FingerEvent {
+    timestamp: u32,
+    id: PointerId,
+    action: PointerAction,
+    pos: [f32; 2],
+    pressure: f32,
+}
+
+/// Send a touch pointer event for an SDL finger event, converting its
+/// normalized coordinates to window coordinates.
+fn finger_event(
+    app: &mut crate::app::App<OpenGlRenderPlatform>,
+    window: &window::Window,
+    finger: FingerEvent,
+) {
+    let width = app.state().window_width().get_unwatched();
+    let height = app.state().window_height().get_unwatched();
+    let action = match finger.action {
+        PointerAction::Move(dx, dy) => {
+            PointerAction::Move(dx * width, -dy * height)
+        }
+        action => action,
+    };
+    let [x, y] = finger.pos;
+    let [x, y] = [x * width, height - y * height];
+    app.pointer_event(
+        PointerEventData::new(finger.id, action, x, y)
+            .with_modifiers(window.mod_state().to_suzy_modifiers())
+            .with_pressure(finger.pressure)
+            .with_timestamp(sdl_timestamp(finger.timestamp)),
+    );
+}
+
+/// Get the pointer id for a finger on a touch device.
+///
+/// SDL finger ids are only unique for a particular touch device, so the
+/// device id is combined into the pointer id.
+fn touch_pointer_id(touch_id: i64, finger_id: i64) -> PointerId {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    (touch_id, finger_id).hash(&mut hasher);
+    PointerId::Touch(hasher.finish() as i64)
+}
+
+/// Convert an SDL event timestamp, in milliseconds since SDL was
+/// initialized.
+fn sdl_timestamp(timestamp: u32) -> std::time::Duration {
+    std::time::Duration::from_millis(timestamp.into())
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AltMouseButtonResult {
     Primary,
@@ -214,6 +423,110 @@ impl ToSuzyMouseButton for sdl2::mouse::MouseButton {
     }
 }
 
+pub trait ToSuzyKey {
+    fn to_suzy_key(self) -> Key;
+}
+
+impl ToSuzyKey for Option<sdl2::keyboard::Keycode> {
+    fn to_suzy_key(self) -> Key {
+        self.map_or(Key::Unknown, ToSuzyKey::to_suzy_key)
+    }
+}
+
+impl ToSuzyKey for sdl2::keyboard::Keycode {
+    fn to_suzy_key(self) -> Key {
+        use sdl2::keyboard::Keycode;
+        let f1 = Keycode::F1.into_i32();
+        let f12 = Keycode::F12.into_i32();
+        let f13 = Keycode::F13.into_i32();
+        let f24 = Keycode::F24.into_i32();
+        match self {
+            Keycode::RETURN | Keycode::RETURN2 | Keycode::KP_ENTER => {
+                Key::Enter
+            }
+            Keycode::TAB => Key::Tab,
+            Keycode::SPACE => Key::Space,
+            Keycode::BACKSPACE => Key::Backspace,
+            Keycode::DELETE => Key::Delete,
+            Keycode::ESCAPE => Key::Escape,
+            Keycode::INSERT => Key::Insert,
+            Keycode::HOME => Key::Home,
+            Keycode::END => Key::End,
+            Keycode::PAGEUP => Key::PageUp,
+            Keycode::PAGEDOWN => Key::PageDown,
+            Keycode::LEFT => Key::ArrowLeft,
+            Keycode::RIGHT => Key::ArrowRight,
+            Keycode::UP => Key::ArrowUp,
+            Keycode::DOWN => Key::ArrowDown,
+            Keycode::LSHIFT | Keycode::RSHIFT => Key::Shift,
+            Keycode::LCTRL | Keycode::RCTRL => Key::Control,
+            Keycode::LALT | Keycode::RALT => Key::Alt,
+            Keycode::LGUI | Keycode::RGUI => Key::Logo,
+            Keycode::CAPSLOCK => Key::CapsLock,
+            _ => match self.into_i32() {
+                code if (f1..=f12).contains(&code) => {
+                    Key::F((code - f1 + 1) as u8)
+                }
+                code if (f13..=f24).contains(&code) => {
+                    Key::F((code - f13 + 13) as u8)
+                }
+                // keycodes without bit 30 set are unicode codepoints
+                code if (0..(1 << 30)).contains(&code) => {
+                    char::from_u32(code as u32)
+                        .filter(|ch| !ch.is_control())
+                        .map_or(Key::Unknown, Key::Character)
+                }
+                _ => Key::Unknown,
+            },
+        }
+    }
+}
+
+pub trait ToSuzyScanCode {
+    fn to_suzy_scancode(self) -> ScanCode;
+}
+
+impl ToSuzyScanCode for Option<sdl2::keyboard::Scancode> {
+    fn to_suzy_scancode(self) -> ScanCode {
+        self.map_or(ScanCode::UNKNOWN, |code| ScanCode(code as u32))
+    }
+}
+
+pub trait ToSuzyModifiers {
+    fn to_suzy_modifiers(self) -> Modifiers;
+}
+
+impl ToSuzyModifiers for sdl2::keyboard::Mod {
+    fn to_suzy_modifiers(self) -> Modifiers {
+        use sdl2::keyboard::Mod;
+        Modifiers {
+            shift: self.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD),
+            ctrl: self.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD),
+            alt: self.intersects(Mod::LALTMOD | Mod::RALTMOD),
+            logo: self.intersects(Mod::LGUIMOD | Mod::RGUIMOD),
+        }
+    }
+}
+
+pub trait ToSuzyNavAction {
+    fn to_suzy_nav_action(self) -> Option<NavAction>;
+}
+
+impl ToSuzyNavAction for sdl2::controller::Button {
+    fn to_suzy_nav_action(self) -> Option<NavAction> {
+        use sdl2::controller::Button;
+        match self {
+            Button::DPadLeft => Some(NavAction::Navigate(Direction::Left)),
+            Button::DPadRight => Some(NavAction::Navigate(Direction::Right)),
+            Button::DPadUp => Some(NavAction::Navigate(Direction::Up)),
+            Button::DPadDown => Some(NavAction::Navigate(Direction::Down)),
+            Button::A => Some(NavAction::Activate),
+            Button::B | Button::Back => Some(NavAction::Back),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub(super) struct TestEnvironment;
 
@@ -0,0 +1,34 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use crate::clipboard::Clipboard;
+
+/// A clipboard backed by SDL's clipboard API.
+///
+/// SDL only supports text on the clipboard.
+pub struct SdlClipboard {
+    util: sdl2::clipboard::ClipboardUtil,
+}
+
+impl SdlClipboard {
+    #[must_use]
+    pub fn new(video: &sdl2::VideoSubsystem) -> Self {
+        Self {
+            util: video.clipboard(),
+        }
+    }
+}
+
+impl Clipboard for SdlClipboard {
+    fn text(&self) -> Option<String> {
+        if self.util.has_clipboard_text() {
+            self.util.clipboard_text().ok()
+        } else {
+            None
+        }
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), String> {
+        self.util.set_clipboard_text(text)
+    }
+}
@@ -5,7 +5,9 @@ use std::convert::TryInto;
 
 use sdl2::video::WindowBuildError;
 
-use crate::{graphics::Color, platforms::opengl};
+use crate::{
+    app::TextInputState, dims::Rect, graphics::Color, platforms::opengl,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct WindowSettings<'a> {
@@ -95,6 +97,28 @@ impl Window {
         self.gl_win.clear_color(color);
     }
 
+    pub(super) fn apply_text_input(&self, state: &TextInputState) {
+        let text_input = self.window.subsystem().text_input();
+        if let Some(rect) = &state.rect {
+            let [_, height] = self.logical_size();
+            text_input.set_rect(sdl2::rect::Rect::new(
+                rect.left() as i32,
+                (height - rect.top()) as i32,
+                rect.width().max(1.0) as u32,
+                rect.height().max(1.0) as u32,
+            ));
+        }
+        if state.active {
+            text_input.start();
+        } else {
+            text_input.stop();
+        }
+    }
+
+    pub(super) fn mod_state(&self) -> sdl2::keyboard::Mod {
+        self.window.subsystem().sdl().keyboard().mod_state()
+    }
+
     pub(super) fn dpi(&self) -> [f32; 2] {
         let (_, hdpi, vdpi) = self
             .window
@@ -8,9 +8,9 @@
 //! Each unique pointer has a concept of being "grabbed" by a widget, in which
 //! case other widgets should generally ignore it.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
-use crate::widget::UniqueHandleId;
+use crate::{keyboard::Modifiers, widget::UniqueHandleId};
 
 /// A unique id for a particular pointer
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
@@ -75,7 +75,11 @@ pub enum PointerAction {
 
 mod internal {
     /// The data associated with a particular pointer event.
+    ///
+    /// Create it with [`PointerEvent::new`], and set the optional details
+    /// with the `with_` methods.  More details may be added in the future.
     #[derive(Copy, Clone, Debug)]
+    #[non_exhaustive]
     pub struct PointerEvent {
         /// The pointer involved in this event
         pub id: super::PointerId,
@@ -85,11 +89,81 @@ mod internal {
         pub x: f32,
         /// The vertical position of the pointer
         pub y: f32,
+        /// The keyboard modifier keys held during this event
+        pub modifiers: crate::keyboard::Modifiers,
+        /// For presses and releases, the number of clicks in quick
+        /// succession this is part of, e.g. 2 for a double click
+        pub click_count: u32,
+        /// The pressure applied by a pen or touch, from 0.0 to 1.0, if the
+        /// device reports it
+        pub pressure: Option<f32>,
+        /// The tilt of a pen in degrees, along the horizontal and vertical
+        /// axes, if the device reports it
+        pub tilt: Option<[f32; 2]>,
+        /// When the event occurred, relative to an arbitrary point in time
+        /// chosen by the platform, if it is known
+        pub timestamp: Option<std::time::Duration>,
     }
 }
 
 pub use internal::PointerEvent as PointerEventData;
 
+impl PointerEventData {
+    /// Create a new pointer event, with no modifier keys held, a click
+    /// count of 1, and no other details.
+    #[must_use]
+    pub fn new(id: PointerId, action: PointerAction, x: f32, y: f32) -> Self {
+        Self {
+            id,
+            action,
+            x,
+            y,
+            modifiers: Modifiers::NONE,
+            click_count: 1,
+            pressure: None,
+            tilt: None,
+            timestamp: None,
+        }
+    }
+
+    /// Set the keyboard modifier keys held during this event.
+    #[must_use]
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Set the number of clicks in quick succession this event is part of.
+    #[must_use]
+    pub fn with_click_count(mut self, click_count: u32) -> Self {
+        self.click_count = click_count;
+        self
+    }
+
+    /// Set the pressure applied by a pen or touch, from 0.0 to 1.0.
+    #[must_use]
+    pub fn with_pressure(mut self, pressure: f32) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+
+    /// Set the tilt of a pen in degrees, along the horizontal and vertical
+    /// axes.
+    #[must_use]
+    pub fn with_tilt(mut self, tilt: [f32; 2]) -> Self {
+        self.tilt = Some(tilt);
+        self
+    }
+
+    /// Set when the event occurred, relative to an arbitrary point in time
+    /// chosen by the platform.
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: Duration) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
 /// This struct will get passed to [`crate::widget::Content::pointer_event`] method.
 pub struct PointerEvent<'a> {
     data: PointerEventData,
@@ -112,6 +186,38 @@ impl<'a> PointerEvent<'a> {
 }
 
 impl PointerEvent<'_> {
+    /// Map the position of the pointer (and the previous position, for
+    /// movement) to a different coordinate space, returning the original
+    /// event data so it can be restored.
+    pub(crate) fn map_points<F>(&mut self, map_fn: F) -> PointerEventData
+    where
+        F: FnOnce(&mut [[f32; 2]]),
+    {
+        let original = self.data;
+        let PointerEventData { action, x, y, .. } = &mut self.data;
+        match action {
+            PointerAction::Move(dx, dy) | PointerAction::Hover(dx, dy) => {
+                let mut points = [[*x, *y], [*x - *dx, *y - *dy]];
+                map_fn(&mut points);
+                let [[new_x, new_y], [prev_x, prev_y]] = points;
+                *x = new_x;
+                *y = new_y;
+                *dx = new_x - prev_x;
+                *dy = new_y - prev_y;
+            }
+            _ => {
+                let mut points = [[*x, *y]];
+                map_fn(&mut points);
+                [[*x, *y]] = points;
+            }
+        }
+        original
+    }
+
+    pub(crate) fn restore(&mut self, data: PointerEventData) {
+        self.data = data;
+    }
+
     /// Get the pointer involved in this event
     #[must_use]
     pub fn id(&self) -> PointerId {
@@ -142,6 +248,40 @@ impl PointerEvent<'_> {
         [self.data.x, self.data.y]
     }
 
+    /// Get the keyboard modifier keys held during this event
+    #[must_use]
+    pub fn modifiers(&self) -> Modifiers {
+        self.data.modifiers
+    }
+
+    /// Get the number of clicks in quick succession this event is part of,
+    /// e.g. 2 for the second press of a double click
+    #[must_use]
+    pub fn click_count(&self) -> u32 {
+        self.data.click_count
+    }
+
+    /// Get the pressure applied by a pen or touch, from 0.0 to 1.0, if the
+    /// device reports it
+    #[must_use]
+    pub fn pressure(&self) -> Option<f32> {
+        self.data.pressure
+    }
+
+    /// Get the tilt of a pen in degrees, along the horizontal and vertical
+    /// axes, if the device reports it
+    #[must_use]
+    pub fn tilt(&self) -> Option<[f32; 2]> {
+        self.data.tilt
+    }
+
+    /// Get when the event occurred, relative to an arbitrary point in time
+    /// chosen by the platform, if it is known
+    #[must_use]
+    pub fn timestamp(&self) -> Option<Duration> {
+        self.data.timestamp
+    }
+
     /// Try to "grab" the pointer, indicating that the identified handle
     /// should be the primary handler of this pointer.
     ///
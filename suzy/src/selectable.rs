@@ -374,9 +374,10 @@ mod extra_impls {
     use super::*;
     use crate::{
         graphics::{DrawContext, Graphic},
+        keyboard::{KeyEvent, TextEvent},
         platform::RenderPlatform,
         pointer::PointerEvent,
-        widget::{self, WidgetRect},
+        widget::{self, UniqueHandle, WidgetRect},
     };
 
     impl<T, P> widget::Content<P> for SelectableIgnored<T>
@@ -398,6 +399,22 @@ mod extra_impls {
         ) -> bool {
             self.data.pointer_event(rect, event)
         }
+
+        fn key_event(&mut self, rect: &WidgetRect, event: &KeyEvent) -> bool {
+            self.data.key_event(rect, event)
+        }
+
+        fn text_event(
+            &mut self,
+            rect: &WidgetRect,
+            event: &TextEvent,
+        ) -> bool {
+            self.data.text_event(rect, event)
+        }
+
+        fn handle(&self) -> Option<&UniqueHandle> {
+            self.data.handle()
+        }
     }
 
     impl<T, P> Graphic<P> for SelectableIgnored<T>
@@ -0,0 +1,173 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+//! Keyboard shortcuts trigger an action when a key chord is pressed,
+//! regardless of which widget is focused.
+//!
+//! A widget owns a [`Shortcut`], and registers it with a [`KeyChord`] from a
+//! watch closure in its `desc`.  A shortcut is either global, or scoped to
+//! the subtree of a widget, in which case it is only active while focus is
+//! within that subtree.  Subtrees are identified by a [`UniqueHandle`],
+//! which must be exposed with
+//! [`Content::handle`](crate::widget::Content::handle).
+//!
+//! A key event is first delivered to the widgets, so a focused widget which
+//! handles a chord itself, such as a text field handling Ctrl+C, takes
+//! precedence over any shortcut.  If no widget handles it, the app looks
+//! for a matching shortcut scoped to the focused widget, then to each of
+//! its ancestors in turn, and finally for a matching global shortcut.  The
+//! first one found is triggered.  Shortcuts with the same chord and scope
+//! are resolved in favor of the one registered first.
+//!
+//! While a [modal](crate::app::Layer::Modal) is shown, global shortcuts do
+//! not trigger; only shortcuts scoped to widgets in the modal do.
+
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    app::AppState,
+    keyboard::{KeyChord, KeyEvent},
+    watch::WatchedQueue,
+    widget::{UniqueHandle, UniqueHandleId},
+};
+
+#[derive(Clone)]
+struct Binding {
+    chord: KeyChord,
+    scope: Option<UniqueHandleId>,
+}
+
+#[derive(Default)]
+pub(crate) struct ShortcutContents {
+    binding: RefCell<Option<Binding>>,
+    triggered: RefCell<WatchedQueue<'static, ()>>,
+}
+
+impl ShortcutContents {
+    pub(crate) fn trigger(&self) {
+        self.triggered.borrow_mut().push_external(());
+    }
+}
+
+/// A keyboard shortcut; see the [module-level documentation](self) for
+/// details.
+#[derive(Default)]
+pub struct Shortcut {
+    ptr: Rc<ShortcutContents>,
+}
+
+impl Shortcut {
+    /// Create a shortcut, which is not registered with any chord.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register this shortcut with the current app, to be triggered by
+    /// `chord` no matter which widget is focused.
+    ///
+    /// This replaces any previous registration of this shortcut.  It has
+    /// no effect if called outside the context of a suzy app.
+    pub fn register(&self, chord: KeyChord) {
+        self.bind(Binding { chord, scope: None });
+    }
+
+    /// Register this shortcut with the current app, to be triggered by
+    /// `chord` only while the widget `scope` belongs to, or one of its
+    /// descendants, is focused.
+    ///
+    /// This replaces any previous registration of this shortcut.  It has
+    /// no effect if called outside the context of a suzy app.
+    pub fn register_scoped(&self, chord: KeyChord, scope: &UniqueHandle) {
+        self.bind(Binding {
+            chord,
+            scope: Some(scope.id()),
+        });
+    }
+
+    /// Stop this shortcut from being triggered.
+    ///
+    /// Shortcuts are also unregistered when they are dropped.
+    pub fn unregister(&self) {
+        self.ptr.binding.replace(None);
+    }
+
+    /// Get the chord this shortcut is registered with, if any.
+    #[must_use]
+    pub fn chord(&self) -> Option<KeyChord> {
+        self.ptr
+            .binding
+            .borrow()
+            .as_ref()
+            .map(|binding| binding.chord)
+    }
+
+    /// Handle this shortcut being triggered.
+    ///
+    /// This should be called in a watch closure, which will re-run each
+    /// time the shortcut is triggered.
+    pub fn on_trigger<F: FnOnce()>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.ptr.triggered.borrow().handle_item(arg, |()| f());
+        });
+    }
+
+    fn bind(&self, binding: Binding) {
+        let registered = AppState::try_with_current(|state| {
+            state.shortcuts.add(Rc::downgrade(&self.ptr));
+        });
+        if registered.is_some() {
+            self.ptr.binding.replace(Some(binding));
+        }
+    }
+}
+
+/// The shortcuts registered with an app.
+#[derive(Default)]
+pub(crate) struct ShortcutRegistry {
+    entries: RefCell<Vec<Weak<ShortcutContents>>>,
+}
+
+impl ShortcutRegistry {
+    fn add(&self, shortcut: Weak<ShortcutContents>) {
+        let mut entries = self.entries.borrow_mut();
+        if !entries.iter().any(|entry| entry.ptr_eq(&shortcut)) {
+            entries.push(shortcut);
+        }
+    }
+
+    /// Find the scopes of the registered shortcuts matching a key event;
+    /// `None` indicates a global shortcut.
+    pub(crate) fn matching_scopes(
+        &self,
+        event: &KeyEvent,
+    ) -> Vec<Option<UniqueHandleId>> {
+        let mut entries = self.entries.borrow_mut();
+        entries.retain(|entry| entry.strong_count() > 0);
+        entries
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter_map(|shortcut| shortcut.binding.borrow().clone())
+            .filter(|binding| binding.chord.matches(event))
+            .map(|binding| binding.scope)
+            .collect()
+    }
+
+    /// Find the first registered shortcut matching a key event with a
+    /// specific scope.
+    pub(crate) fn find(
+        &self,
+        event: &KeyEvent,
+        scope: Option<&UniqueHandleId>,
+    ) -> Option<Rc<ShortcutContents>> {
+        let entries = self.entries.borrow();
+        entries.iter().filter_map(Weak::upgrade).find(|shortcut| {
+            shortcut.binding.borrow().as_ref().is_some_and(|binding| {
+                binding.chord.matches(event) && binding.scope.as_ref() == scope
+            })
+        })
+    }
+}
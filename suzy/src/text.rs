@@ -74,6 +74,21 @@ impl Default for Layout {
     }
 }
 
+/// The position of a caret within laid out text.
+///
+/// See [`Text::caret`](crate::platform::graphics::Text::caret).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Caret {
+    /// The horizontal position of the caret.
+    pub x: f32,
+    /// The bottom of the caret, at the descent of the text.
+    pub bottom: f32,
+    /// The top of the caret, at the ascent of the text.
+    pub top: f32,
+    /// The line the caret is on, starting from zero.
+    pub line: usize,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct RichTextTagParseError {
     pub msg: String,
@@ -0,0 +1,528 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+//! Tooltips show a short description of a widget while a pointer rests
+//! over it.
+//!
+//! Wrap the content of a widget in a [`TooltipBehavior`] to give it a
+//! tooltip, and set what the tooltip shows from a watch closure in the
+//! parent's `desc`, with [`TooltipBehavior::set_text`] or
+//! [`TooltipBehavior::tooltip_mut`].
+//!
+//! With a mouse, the tooltip is shown once the pointer has rested over the
+//! widget for [`TooltipSettings::delay`]; it is placed next to the pointer,
+//! inside the window, in the app's [`Tooltip`](crate::app::Layer::Tooltip)
+//! layer.  It is hidden when the pointer leaves the widget or is pressed,
+//! and is not shown again until the pointer leaves and returns.
+//!
+//! With a touchscreen, holding a touch on the widget without moving it for
+//! [`TooltipSettings::long_press_duration`] shows the tooltip until the
+//! touch is released.  Once the tooltip is shown, the touch is taken from
+//! any widget which grabbed it, so a long press on a button shows its
+//! tooltip without clicking it.
+
+use std::{
+    cell::{Ref, RefMut},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    app::{AppState, Layer, Overlay},
+    dims::{Padding2d, Rect},
+    graphics::{Color, CornerStyle},
+    keyboard::{KeyEvent, TextEvent},
+    platform::{
+        graphics::{SlicedImage, Text, TextStyle},
+        RenderPlatform,
+    },
+    pointer::{PointerAction, PointerEvent, PointerId},
+    text,
+    watch::{Watched, WatchedMeta, WatchedValue},
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+/// The space between the edge of a text tooltip and its text.
+const PADDING: f32 = 6.0;
+
+const FONT_SIZE: f32 = 14.0;
+
+/// Configurable timing and placement of tooltips.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TooltipSettings {
+    /// How long the mouse must rest over a widget before its tooltip is
+    /// shown.
+    pub delay: Duration,
+
+    /// How long a touch must be held without moving before the tooltip is
+    /// shown.
+    pub long_press_duration: Duration,
+
+    /// The distance, in pixels, a touch may move and still be considered a
+    /// long press.
+    pub slop: f32,
+
+    /// How far, in pixels, the tooltip is placed to the right of and below
+    /// the pointer.
+    pub offset: [f32; 2],
+}
+
+impl Default for TooltipSettings {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(500),
+            long_press_duration: Duration::from_millis(500),
+            slop: 10.0,
+            offset: [12.0, 16.0],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum TouchStatus {
+    /// The touch is being timed, but may belong to another widget.
+    Pending,
+    /// The tooltip was shown, and the touch should be grabbed the next time
+    /// an event for it arrives.
+    Claimed,
+    /// The touch has been grabbed.
+    Grabbed,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TrackedTouch {
+    pointer: PointerId,
+    status: TouchStatus,
+    start: [f32; 2],
+}
+
+/// A Widget which shows a tooltip while a pointer rests over its content.
+pub struct TooltipBehavior<T, Tip> {
+    settings: TooltipSettings,
+    tooltip: Overlay<Tip>,
+    tooltip_size: Watched<[f32; 2]>,
+    anchor: Watched<[f32; 2]>,
+    deadline: Option<(Instant, [f32; 2])>,
+    deadline_flag: WatchedMeta<'static>,
+    touch: Option<TrackedTouch>,
+    suppressed: bool,
+    handle: UniqueHandle,
+    content: T,
+}
+
+impl<T, Tip> TooltipBehavior<T, Tip> {
+    /// Get a reference to the content of this widget.
+    pub fn content(&self) -> &T {
+        &self.content
+    }
+
+    /// Get a mutable reference to the content of this widget.
+    pub fn content_mut(&mut self) -> &mut T {
+        &mut self.content
+    }
+
+    /// Get the timing and placement of the tooltip.
+    pub fn settings(&self) -> &TooltipSettings {
+        &self.settings
+    }
+
+    /// Get a mutable reference to the timing and placement of the tooltip.
+    pub fn settings_mut(&mut self) -> &mut TooltipSettings {
+        &mut self.settings
+    }
+
+    /// Get a reference to the tooltip's widget.
+    pub fn tooltip(&self) -> Ref<'_, Widget<Tip>> {
+        self.tooltip.widget()
+    }
+
+    /// Get a mutable reference to the tooltip's widget, to change what it
+    /// shows.
+    ///
+    /// The tooltip is positioned when it is shown; use
+    /// [`set_tooltip_size`](Self::set_tooltip_size) to change its size, or
+    /// `set_text` to size a text tooltip to fit its text.
+    pub fn tooltip_mut(&self) -> RefMut<'_, Widget<Tip>> {
+        self.tooltip.widget_mut()
+    }
+
+    /// Get the size of the tooltip.
+    pub fn tooltip_size(&self) -> [f32; 2] {
+        *self.tooltip_size
+    }
+
+    /// Set the size of the tooltip.
+    pub fn set_tooltip_size(&mut self, width: f32, height: f32) {
+        *self.tooltip_size = [width, height];
+    }
+
+    /// Check if the tooltip is shown.
+    ///
+    /// This will bind a current watch function to the state of the
+    /// tooltip.
+    pub fn is_shown(&self) -> bool {
+        self.tooltip.is_shown()
+    }
+
+    /// Hide the tooltip, if it is shown, and stop waiting to show it.
+    pub fn hide(&mut self) {
+        self.deadline = None;
+        self.tooltip.dismiss();
+    }
+
+    fn schedule(&mut self, duration: Duration, pos: [f32; 2]) {
+        let now = crate::app::time_unwatched();
+        self.deadline = Some((now + duration, pos));
+        self.deadline_flag.trigger_auto();
+    }
+
+    fn check_deadline<P>(&mut self)
+    where
+        P: 'static + RenderPlatform,
+        Tip: widget::Content<P>,
+    {
+        self.deadline_flag.watched_auto();
+        let Some((deadline, pos)) = self.deadline else {
+            return;
+        };
+        if crate::app::time() >= deadline {
+            self.deadline = None;
+            if let Some(touch) = &mut self.touch {
+                touch.status = TouchStatus::Claimed;
+            }
+            *self.anchor = pos;
+            self.tooltip.show::<P>();
+        }
+    }
+
+    /// The handle hover is tracked with: the content's, if it has one.
+    fn hover_handle<P>(&self) -> &UniqueHandle
+    where
+        T: widget::Content<P>,
+    {
+        self.content.handle().unwrap_or(&self.handle)
+    }
+
+    fn touch_event(
+        &mut self,
+        mut touch: TrackedTouch,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        if touch.status == TouchStatus::Claimed {
+            event.force_grab(self.handle.id());
+            touch.status = TouchStatus::Grabbed;
+        }
+        let grabbed = touch.status == TouchStatus::Grabbed;
+        match event.action() {
+            PointerAction::Move(_, _) => {
+                let [x, y] = event.pos();
+                let moved = (x - touch.start[0]).hypot(y - touch.start[1]);
+                if !grabbed && moved > self.settings.slop {
+                    self.touch = None;
+                    self.hide();
+                } else {
+                    self.touch = Some(touch);
+                }
+                grabbed
+            }
+            PointerAction::Up => {
+                self.touch = None;
+                self.hide();
+                grabbed && event.try_ungrab(self.handle.id())
+            }
+            _ => {
+                self.touch = Some(touch);
+                grabbed
+            }
+        }
+    }
+}
+
+impl<T, P> TooltipBehavior<T, TooltipText<P>>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// Set the text a text tooltip shows, and size the tooltip to fit it.
+    ///
+    /// On platforms which are unable to measure text, the size of the
+    /// tooltip is not changed.
+    pub fn set_text(&mut self, text: &str) {
+        let size = {
+            let mut tooltip = self.tooltip.widget_mut();
+            if *tooltip.text == text {
+                return;
+            }
+            *tooltip.text = text.to_owned();
+            tooltip.fit_text()
+        };
+        if let Some(size) = size {
+            *self.tooltip_size = size;
+        }
+    }
+}
+
+impl<T, Tip, P> widget::Content<P> for TooltipBehavior<T, Tip>
+where
+    T: widget::Content<P>,
+    Tip: widget::Content<P>,
+    P: 'static + RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.bare_child(|this| &mut this.content);
+        desc.watch(|this, _rect| {
+            this.check_deadline::<P>();
+        });
+        desc.watch(|this, _rect| {
+            if !this.hover_handle::<P>().is_hovered() {
+                this.suppressed = false;
+                if this.touch.is_none() {
+                    this.hide();
+                }
+            }
+        });
+        desc.watch(|this, _rect| {
+            let mut stolen = false;
+            this.handle.handle_pointer_grab_stolen(|_pointer_id| {
+                stolen = true;
+            });
+            if stolen {
+                this.touch = None;
+                this.hide();
+            }
+        });
+        desc.watch(|this, _rect| {
+            let [anchor_x, anchor_y] = *this.anchor;
+            let [width, height] = *this.tooltip_size;
+            let [offset_x, offset_y] = this.settings.offset;
+            let window = AppState::try_with_current(|state| {
+                [
+                    state.window_width().get_auto(),
+                    state.window_height().get_auto(),
+                ]
+            });
+            let [window_width, window_height] =
+                window.unwrap_or([f32::INFINITY; 2]);
+            // below the pointer, unless that would go past the bottom of
+            // the window
+            let mut bottom = anchor_y - offset_y - height;
+            if bottom < 0.0 {
+                bottom = anchor_y + offset_y;
+            }
+            let bottom = bottom.min(window_height - height).max(0.0);
+            let left =
+                (anchor_x + offset_x).min(window_width - width).max(0.0);
+            let mut tooltip = this.tooltip.widget_mut();
+            tooltip.set_width(width);
+            tooltip.set_height(height);
+            tooltip.set_left(left);
+            tooltip.set_bottom(bottom);
+        });
+    }
+
+    fn hittest(&self, rect: &WidgetRect, point: [f32; 2]) -> bool {
+        self.content.hittest(rect, point)
+    }
+
+    fn pointer_event_before(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        if let Some(touch) = self.touch {
+            if touch.pointer == event.id() && self.touch_event(touch, event) {
+                return true;
+            }
+        }
+        let pos = event.pos();
+        let is_touch = matches!(event.id(), PointerId::Touch(_));
+        match event.action() {
+            PointerAction::Hover(_, _) => {
+                if !self.hittest(rect, pos) {
+                    self.suppressed = false;
+                    self.hide();
+                } else if !self.suppressed && !self.tooltip.is_shown() {
+                    // wait for the pointer to rest before showing
+                    self.schedule(self.settings.delay, pos);
+                }
+            }
+            PointerAction::Down if is_touch => {
+                if self.touch.is_none() && self.hittest(rect, pos) {
+                    self.touch = Some(TrackedTouch {
+                        pointer: event.id(),
+                        status: TouchStatus::Pending,
+                        start: pos,
+                    });
+                    self.schedule(self.settings.long_press_duration, pos);
+                } else if self.touch.is_some() {
+                    // a second touch is not a long press
+                    self.touch = None;
+                    self.hide();
+                }
+            }
+            PointerAction::Down | PointerAction::AltDown(_) => {
+                self.suppressed = self.hittest(rect, pos);
+                self.hide();
+            }
+            _ => {}
+        }
+        self.content.pointer_event_before(rect, event)
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        self.content.pointer_event(rect, event)
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(self.hover_handle::<P>())
+    }
+
+    fn key_event(&mut self, rect: &WidgetRect, event: &KeyEvent) -> bool {
+        self.content.key_event(rect, event)
+    }
+
+    fn key_event_before(
+        &mut self,
+        rect: &WidgetRect,
+        event: &KeyEvent,
+    ) -> bool {
+        self.content.key_event_before(rect, event)
+    }
+
+    fn text_event(&mut self, rect: &WidgetRect, event: &TextEvent) -> bool {
+        self.content.text_event(rect, event)
+    }
+}
+
+impl<T: Default, Tip: Default> Default for TooltipBehavior<T, Tip> {
+    fn default() -> Self {
+        Self {
+            settings: TooltipSettings::default(),
+            tooltip: Overlay::new(Layer::Tooltip),
+            tooltip_size: Watched::new([120.0, 24.0]),
+            anchor: Watched::new([0.0, 0.0]),
+            deadline: None,
+            deadline_flag: WatchedMeta::default(),
+            touch: None,
+            suppressed: false,
+            handle: UniqueHandle::default(),
+            content: T::default(),
+        }
+    }
+}
+
+/// A widget with a tooltip.
+///
+/// Use `TooltipArea::set_text` to set the text the tooltip shows.
+#[cfg(feature = "platform-opengl")]
+pub type TooltipArea<
+    T,
+    Tip = TooltipText<crate::platforms::DefaultRenderPlatform>,
+> = Widget<TooltipBehavior<T, Tip>>;
+
+/// A widget with a tooltip.
+///
+/// Use `TooltipArea::set_text` to set the text the tooltip shows.
+#[cfg(not(feature = "platform-opengl"))]
+pub type TooltipArea<T, Tip> = Widget<TooltipBehavior<T, Tip>>;
+
+/// The content of a tooltip which shows a line of text.
+pub struct TooltipText<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// The text the tooltip shows, on a single line.
+    ///
+    /// Unlike `TooltipBehavior::set_text`, changing this directly does not
+    /// resize the tooltip.
+    pub text: Watched<String>,
+    pub text_color: Watched<Color>,
+    pub background_color: Watched<Color>,
+    background: P::SlicedImage,
+    text_graphic: P::Text,
+    scratch: P::Text,
+}
+
+impl<P> Default for TooltipText<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        Self {
+            text: Watched::default(),
+            text_color: Watched::new(Color::BLACK),
+            background_color: Watched::new(Color::LIGHT_YELLOW),
+            background: P::SlicedImage::default(),
+            text_graphic: P::Text::default(),
+            scratch: P::Text::default(),
+        }
+    }
+}
+
+impl<P> TooltipText<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// Measure the size the tooltip needs to show its text on one line,
+    /// if the platform is able to.
+    fn fit_text(&mut self) -> Option<[f32; 2]> {
+        self.scratch.set_layout(text::Layout {
+            alignment: text::Alignment::Left,
+            line: text::Line::BetweenBaseAndCap,
+            flow: text::Flow::Out,
+            origin_x: 0.0,
+            origin_y: 0.0,
+            wrap_width: f32::INFINITY,
+            vertical_limit: text::VerticalLimit::Lines(1),
+            overflow_mode: text::OverflowMode::Truncate,
+        });
+        let style = P::TextStyle::with_size_and_color(FONT_SIZE, Color::BLACK);
+        self.scratch.clear();
+        self.scratch.push_span(style, &self.text);
+        self.scratch.finish();
+        let start = self.scratch.caret(0)?;
+        let end = self.scratch.caret(self.text.len())?;
+        let width = end.x - start.x;
+        let height = start.top - start.bottom;
+        Some([width + 2.0 * PADDING, height + 2.0 * PADDING])
+    }
+}
+
+impl<P> widget::Content<P> for TooltipText<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, rect| {
+            this.background.set_fill(rect, &Padding2d::zero());
+            this.background.set_slice_padding(Padding2d::uniform(4.0));
+            this.background.set_corners(CornerStyle::Rounded);
+        });
+        desc.watch(|this, _rect| {
+            this.background.set_color(*this.background_color);
+        });
+        desc.watch(|this, rect| {
+            this.text_graphic.set_layout(text::Layout {
+                alignment: text::Alignment::Center,
+                line: text::Line::BetweenBaseAndCap,
+                flow: text::Flow::Out,
+                origin_x: rect.center_x(),
+                origin_y: rect.center_y(),
+                wrap_width: rect.width() - 2.0 * PADDING,
+                vertical_limit: text::VerticalLimit::Lines(1),
+                overflow_mode: text::OverflowMode::Ellipsis,
+            });
+        });
+        desc.watch(|this, _rect| {
+            let style =
+                P::TextStyle::with_size_and_color(FONT_SIZE, *this.text_color);
+            this.text_graphic.clear();
+            this.text_graphic.push_span(style, &this.text);
+            this.text_graphic.finish();
+        });
+        desc.graphic(|this| &mut this.background);
+        desc.graphic(|this| &mut this.text_graphic);
+    }
+}
@@ -7,8 +7,12 @@
 use std::ops::{Deref, DerefMut};
 
 use crate::{
-    adapter::Adaptable, dims::Rect, graphics::DrawContext,
-    platform::RenderPlatform, pointer::PointerEvent,
+    adapter::Adaptable,
+    dims::Rect,
+    graphics::DrawContext,
+    keyboard::{KeyEvent, TextEvent},
+    platform::RenderPlatform,
+    pointer::{PointerAction, PointerEvent},
 };
 
 mod anon;
@@ -26,7 +30,8 @@ mod unique_handle;
 use internal::WidgetInternal;
 use receivers::{
     DrawChildReceiver, DrawGraphicBeforeReceiver, DrawGraphicOrderedReceiver,
-    DrawGraphicUnorderedReceiver, PointerEventChildReceiver,
+    DrawGraphicUnorderedReceiver, KeyEventChildReceiver, MapPointsReceiver,
+    PointerEventChildReceiver, TextEventChildReceiver, VisitChildReceiver,
 };
 
 pub use anon::AnonWidget;
@@ -37,6 +42,7 @@ pub use coroutine::Coroutine;
 pub use desc::{ChildIter, Desc};
 pub use ephemeral::Ephemeral;
 pub use graphic::WidgetGraphic;
+pub(crate) use receivers::{VisitedWidget, WidgetVisitor};
 pub use rect::WidgetRect;
 pub use unique_handle::{UniqueHandle, UniqueHandleId};
 
@@ -206,14 +212,27 @@ impl<T: ?Sized> Widget<T> {
         let wid_int = &mut this.internal;
         let content = &mut wid_int.content;
         let rect = &wid_int.rect;
+        let reaches_children = match event.action() {
+            PointerAction::Down
+            | PointerAction::AltDown(_)
+            | PointerAction::Hover(..)
+            | PointerAction::Wheel(..) => {
+                T::children_hittest(content, rect, event.pos())
+            }
+            _ => true,
+        };
         T::pointer_event_before(content, rect, event)
-            || {
+            || reaches_children && {
+                let original = event.map_points(|points| {
+                    T::desc(MapPointsReceiver { content, points });
+                });
                 let mut handled_by_child = false;
                 T::desc(PointerEventChildReceiver {
                     content,
                     event,
                     handled: &mut handled_by_child,
                 });
+                event.restore(original);
                 handled_by_child
             }
             || T::pointer_event(content, rect, event)
@@ -232,6 +251,78 @@ impl<T: ?Sized> Widget<T> {
         T::pointer_event(content, rect, event)
     }
 
+    pub(crate) fn key_event<P>(this: &mut Self, event: &KeyEvent) -> bool
+    where
+        T: Content<P>,
+    {
+        let wid_int = &mut this.internal;
+        let content = &mut wid_int.content;
+        let rect = &wid_int.rect;
+        T::key_event_before(content, rect, event)
+            || {
+                let mut handled_by_child = false;
+                T::desc(KeyEventChildReceiver {
+                    content,
+                    event,
+                    handled: &mut handled_by_child,
+                });
+                handled_by_child
+            }
+            || T::key_event(content, rect, event)
+    }
+
+    pub(crate) fn text_event<P>(this: &mut Self, event: &TextEvent) -> bool
+    where
+        T: Content<P>,
+    {
+        let wid_int = &mut this.internal;
+        let content = &mut wid_int.content;
+        let rect = &wid_int.rect;
+        let mut handled_by_child = false;
+        T::desc(TextEventChildReceiver {
+            content,
+            event,
+            handled: &mut handled_by_child,
+        });
+        handled_by_child || T::text_event(content, rect, event)
+    }
+
+    pub(crate) fn visit<P>(
+        this: &mut Self,
+        visitor: &mut dyn WidgetVisitor,
+        point: Option<[f32; 2]>,
+    ) where
+        T: Content<P>,
+    {
+        let wid_int = &mut this.internal;
+        let content = &mut wid_int.content;
+        let rect = &wid_int.rect;
+        let hittest = |point| T::hittest(content, rect, point);
+        visitor.visit(&VisitedWidget {
+            type_name: std::any::type_name::<T>(),
+            rect,
+            handle: T::handle(content),
+            point,
+            hittest: &hittest,
+        });
+        let point =
+            point.filter(|&point| T::children_hittest(content, rect, point));
+        let point = point.map(|point| {
+            let mut points = [point];
+            T::desc(MapPointsReceiver {
+                content,
+                points: &mut points,
+            });
+            points[0]
+        });
+        T::desc(VisitChildReceiver {
+            content,
+            visitor,
+            point,
+        });
+        visitor.leave();
+    }
+
     fn proxy_rect<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&WidgetRect) -> R,
@@ -8,8 +8,10 @@ use super::{Content, Widget};
 mod private {
     use super::Content;
     use crate::graphics::DrawContext;
+    use crate::keyboard::{KeyEvent, TextEvent};
     use crate::platform::RenderPlatform;
     use crate::pointer::PointerEvent;
+    use crate::widget::WidgetVisitor;
 
     pub trait Widget<P> {
         fn draw(&mut self, ctx: &mut DrawContext<'_, P>)
@@ -18,6 +20,9 @@ mod private {
         fn pointer_event(&mut self, event: &mut PointerEvent<'_>) -> bool;
         fn pointer_event_self(&mut self, event: &mut PointerEvent<'_>)
             -> bool;
+        fn key_event(&mut self, event: &KeyEvent) -> bool;
+        fn text_event(&mut self, event: &TextEvent) -> bool;
+        fn visit(&mut self, visitor: &mut dyn WidgetVisitor);
         fn as_any(self: Box<Self>) -> Box<dyn std::any::Any>;
         fn as_any_ref(&self) -> &dyn std::any::Any;
         fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
@@ -46,6 +51,19 @@ mod private {
             super::Widget::pointer_event_self(self, event)
         }
 
+        fn key_event(&mut self, event: &KeyEvent) -> bool {
+            super::Widget::key_event(self, event)
+        }
+
+        fn text_event(&mut self, event: &TextEvent) -> bool {
+            super::Widget::text_event(self, event)
+        }
+
+        fn visit(&mut self, visitor: &mut dyn WidgetVisitor) {
+            let point = visitor.query_point();
+            super::Widget::visit(self, visitor, point);
+        }
+
         fn as_any(self: Box<Self>) -> Box<dyn std::any::Any> {
             self
         }
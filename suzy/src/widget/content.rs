@@ -2,8 +2,9 @@
 /* Copyright © 2021 Violet Leonard */
 
 use crate::{
+    keyboard::{KeyEvent, TextEvent},
     pointer::PointerEvent,
-    widget::{self, WidgetRect},
+    widget::{self, UniqueHandle, WidgetRect},
 };
 
 with_default_render_platform! {
@@ -79,6 +80,23 @@ impl widget::Content for MyWidgetData {
             rect.contains(point)
         }
 
+        /// Override this method to restrict where the widget's children can
+        /// be reached by the pointer, e.g. for a widget which clips its
+        /// children.
+        ///
+        /// Presses, hovers, and wheel events at a point outside of this are
+        /// not passed to the children, and point queries such as
+        /// `App::widgets_at` will not find them there.  The default allows
+        /// children to be reached anywhere.
+        fn children_hittest(
+            &self,
+            rect: &WidgetRect,
+            point: [f32; 2],
+        ) -> bool {
+            let _unused = (rect, point);
+            true
+        }
+
         /// Override this method to handle pointer events directly by a custom
         /// widget.
         ///
@@ -104,6 +122,52 @@ impl widget::Content for MyWidgetData {
             false
         }
 
+        /// Override this method to expose a handle which identifies this
+        /// widget to the app.
+        ///
+        /// This is required for the widget to be able to receive focus; see
+        /// [`UniqueHandle::set_focusable`].
+        fn handle(&self) -> Option<&UniqueHandle> {
+            None
+        }
+
+        /// Override this method to handle key events directly by a custom
+        /// widget.
+        ///
+        /// Return true if this successfully handled the event.
+        fn key_event(&mut self, rect: &WidgetRect, event: &KeyEvent) -> bool {
+            let _unused = (rect, event);
+            false
+        }
+
+        /// This is the same as `key_event`, except that it runs before
+        /// passing the event to children, rather than after.  This is only
+        /// recomended for special cases.
+        fn key_event_before(
+            &mut self,
+            rect: &WidgetRect,
+            event: &KeyEvent,
+        ) -> bool {
+            let _unused = (rect, event);
+            false
+        }
+
+        /// Override this method to handle text input events directly by a
+        /// custom widget.
+        ///
+        /// Text events are only sent while text input is active; see
+        /// [`start_text_input`](crate::app::start_text_input).
+        ///
+        /// Return true if this successfully handled the event.
+        fn text_event(
+            &mut self,
+            rect: &WidgetRect,
+            event: &TextEvent,
+        ) -> bool {
+            let _unused = (rect, event);
+            false
+        }
+
         /// If this widget is used with RunAsApp, defines the window title.
         #[must_use]
         fn app_title() -> String {
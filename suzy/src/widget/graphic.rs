@@ -55,6 +55,17 @@ where
     fn ordered() -> bool {
         true
     }
+
+    /// Map a point from the coordinate space of the widget to the
+    /// coordinate space of its children.
+    ///
+    /// Graphics which change where children are drawn, such as a transform,
+    /// should override this so pointer input reaches children where they
+    /// appear.  The default leaves the point unchanged.
+    #[must_use]
+    fn map_point_to_children(&self, point: [f32; 2]) -> [f32; 2] {
+        point
+    }
 }
 
 impl<P, T> WidgetGraphic<P> for T
@@ -9,12 +9,15 @@ use std::{
 use crate::{
     app::{self, AppState},
     graphics::{DrawContext, Graphic},
+    keyboard::{KeyEvent, TextEvent},
     platform::RenderPlatform,
     pointer::PointerEvent,
     watch::{self, DefaultOwner, WatchArg, WatchName, WatchedMeta},
 };
 
-use super::{ChildIter, Desc, Widget, WidgetGraphic, WidgetRect};
+use super::{
+    ChildIter, Desc, UniqueHandle, Widget, WidgetGraphic, WidgetRect,
+};
 
 macro_rules! impl_empty {
     ($T:ident; $P:ident; watch) => {
@@ -406,6 +409,274 @@ where
     }
 }
 
+/// Maps points into the coordinate space of a widget's children, using its
+/// graphics; see [`WidgetGraphic::map_point_to_children`].
+pub(super) struct MapPointsReceiver<'a, T: ?Sized> {
+    pub content: &'a mut T,
+    pub points: &'a mut [[f32; 2]],
+}
+
+impl<'a, T, P> Desc<T, P> for MapPointsReceiver<'a, T>
+where
+    T: ?Sized + super::Content<P>,
+{
+    impl_empty! { T; P; watch child iter_children }
+
+    fn graphic<F, Gr>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Gr,
+        Gr: WidgetGraphic<P>,
+        P: RenderPlatform,
+    {
+        let graphic = map_fn(self.content);
+        for point in self.points.iter_mut() {
+            *point = graphic.map_point_to_children(*point);
+        }
+    }
+
+    fn bare_child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Child,
+        Child: super::Content<P>,
+    {
+        Child::desc(MapPointsReceiver {
+            content: map_fn(self.content),
+            points: self.points,
+        });
+    }
+}
+
+/// A visitor for app-level traversals of the widget tree, such as finding
+/// the next widget to focus.
+///
+/// Widgets are visited in the order they are described, with parents before
+/// their children.
+pub trait WidgetVisitor {
+    fn visit(&mut self, widget: &VisitedWidget<'_>);
+
+    /// Called after the children of the most recently visited widget (which
+    /// has not yet been left) have all been visited.
+    fn leave(&mut self) {}
+
+    /// A point in window coordinates which the traversal should hit-test
+    /// widgets against; see [`VisitedWidget::contains_point`].
+    fn query_point(&self) -> Option<[f32; 2]> {
+        None
+    }
+}
+
+/// The information about a widget available to a [`WidgetVisitor`].
+pub struct VisitedWidget<'a> {
+    pub type_name: &'static str,
+    pub rect: &'a WidgetRect,
+    pub handle: Option<&'a UniqueHandle>,
+    pub(super) point: Option<[f32; 2]>,
+    pub(super) hittest: &'a dyn Fn([f32; 2]) -> bool,
+}
+
+impl VisitedWidget<'_> {
+    /// Check if the visitor's query point is within the shape of the
+    /// widget; see [`Content::hittest`](super::Content::hittest).
+    ///
+    /// The query point is mapped into the widget's coordinate space, so
+    /// this respects graphics such as transforms on the widget's ancestors.
+    pub fn contains_point(&self) -> bool {
+        self.point.is_some_and(|point| (self.hittest)(point))
+    }
+}
+
+pub(super) struct VisitChildReceiver<'a, T: ?Sized> {
+    pub content: &'a mut T,
+    pub visitor: &'a mut dyn WidgetVisitor,
+    pub point: Option<[f32; 2]>,
+}
+
+impl<'a, T, P> Desc<T, P> for VisitChildReceiver<'a, T>
+where
+    T: ?Sized + super::Content<P>,
+{
+    impl_empty! { T; P; watch graphic }
+
+    fn child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Widget<Child>,
+        Child: super::Content<P>,
+    {
+        Widget::visit(map_fn(self.content), self.visitor, self.point);
+    }
+
+    fn iter_children<F, Child>(&mut self, iter_fn: F)
+    where
+        F: 'static,
+        F: for<'i> Fn(&'i mut T) -> ChildIter<'i, Child>,
+        Child: super::Content<P>,
+    {
+        self.iter_children_explicit(move |leaf, _arg| iter_fn(leaf));
+    }
+
+    fn iter_children_explicit<F, Child>(&mut self, iter_fn: F)
+    where
+        F: for<'i> Fn(
+            &'i mut T,
+            Option<WatchArg<'_, 'static, DefaultOwner>>,
+        ) -> ChildIter<'i, Child>,
+        Child: super::Content<P>,
+    {
+        let Self {
+            content,
+            visitor,
+            point,
+        } = self;
+        for child in iter_fn(content, None).inner {
+            child.access_mut(|widget| Widget::visit(widget, *visitor, *point));
+        }
+    }
+
+    fn bare_child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Child,
+        Child: super::Content<P>,
+    {
+        Child::desc(VisitChildReceiver {
+            content: map_fn(self.content),
+            visitor: self.visitor,
+            point: self.point,
+        });
+    }
+}
+
+pub(super) struct KeyEventChildReceiver<'a, 'b, T: ?Sized> {
+    pub content: &'a mut T,
+    pub event: &'a KeyEvent,
+    pub handled: &'b mut bool,
+}
+
+impl<'a, 'b, T, P> Desc<T, P> for KeyEventChildReceiver<'a, 'b, T>
+where
+    T: ?Sized + super::Content<P>,
+{
+    impl_empty! { T; P; watch graphic }
+
+    fn child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Widget<Child>,
+        Child: super::Content<P>,
+    {
+        if !*self.handled {
+            *self.handled =
+                Widget::key_event(map_fn(self.content), self.event);
+        }
+    }
+
+    fn iter_children<F, Child>(&mut self, iter_fn: F)
+    where
+        F: 'static,
+        F: for<'i> Fn(&'i mut T) -> ChildIter<'i, Child>,
+        Child: super::Content<P>,
+    {
+        self.iter_children_explicit(move |leaf, _arg| iter_fn(leaf));
+    }
+
+    fn iter_children_explicit<F, Child>(&mut self, iter_fn: F)
+    where
+        F: for<'i> Fn(
+            &'i mut T,
+            Option<WatchArg<'_, 'static, DefaultOwner>>,
+        ) -> ChildIter<'i, Child>,
+        Child: super::Content<P>,
+    {
+        let Self {
+            content,
+            event,
+            handled,
+        } = self;
+        for child in iter_fn(content, None).inner {
+            if !**handled {
+                **handled = child
+                    .access_mut(|widget| Widget::key_event(widget, event));
+            }
+        }
+    }
+
+    fn bare_child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Child,
+        Child: super::Content<P>,
+    {
+        Child::desc(KeyEventChildReceiver {
+            content: map_fn(self.content),
+            event: self.event,
+            handled: self.handled,
+        });
+    }
+}
+
+pub(super) struct TextEventChildReceiver<'a, 'b, T: ?Sized> {
+    pub content: &'a mut T,
+    pub event: &'a TextEvent,
+    pub handled: &'b mut bool,
+}
+
+impl<'a, 'b, T, P> Desc<T, P> for TextEventChildReceiver<'a, 'b, T>
+where
+    T: ?Sized + super::Content<P>,
+{
+    impl_empty! { T; P; watch graphic }
+
+    fn child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Widget<Child>,
+        Child: super::Content<P>,
+    {
+        if !*self.handled {
+            *self.handled =
+                Widget::text_event(map_fn(self.content), self.event);
+        }
+    }
+
+    fn iter_children<F, Child>(&mut self, iter_fn: F)
+    where
+        F: 'static,
+        F: for<'i> Fn(&'i mut T) -> ChildIter<'i, Child>,
+        Child: super::Content<P>,
+    {
+        self.iter_children_explicit(move |leaf, _arg| iter_fn(leaf));
+    }
+
+    fn iter_children_explicit<F, Child>(&mut self, iter_fn: F)
+    where
+        F: for<'i> Fn(
+            &'i mut T,
+            Option<WatchArg<'_, 'static, DefaultOwner>>,
+        ) -> ChildIter<'i, Child>,
+        Child: super::Content<P>,
+    {
+        let Self {
+            content,
+            event,
+            handled,
+        } = self;
+        for child in iter_fn(content, None).inner {
+            if !**handled {
+                **handled = child
+                    .access_mut(|widget| Widget::text_event(widget, event));
+            }
+        }
+    }
+
+    fn bare_child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Child,
+        Child: super::Content<P>,
+    {
+        Child::desc(TextEventChildReceiver {
+            content: map_fn(self.content),
+            event: self.event,
+            handled: self.handled,
+        });
+    }
+}
+
 pub(super) struct DrawGraphicBeforeReceiver<'a, 'b, T, P>
 where
     T: ?Sized,
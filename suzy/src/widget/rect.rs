@@ -13,7 +13,7 @@ pub struct WidgetRect {
 }
 
 impl WidgetRect {
-    pub(super) fn from_rect<R>(rect: &R) -> Self
+    pub(crate) fn from_rect<R>(rect: &R) -> Self
     where
         R: Rect + ?Sized,
     {
@@ -2,15 +2,30 @@
 /* Copyright © 2021 Violet Leonard */
 
 use std::{
-    cell::RefCell,
+    any::TypeId,
+    cell::{Cell, RefCell},
     rc::{Rc, Weak},
 };
 
-use crate::{pointer::PointerId, watch::WatchedQueue};
+use crate::{
+    app::AppState,
+    drag::DragEvent,
+    navigation::Direction,
+    pointer::PointerId,
+    watch::{DefaultOwner, WatchedCellCore, WatchedQueue},
+};
 
 #[derive(Default)]
 struct HandleContents {
     grab_stolen: RefCell<WatchedQueue<'static, PointerId>>,
+    focusable: Cell<bool>,
+    tab_index: Cell<i32>,
+    focused: WatchedCellCore<'static, bool, DefaultOwner>,
+    nav_targets: RefCell<[Option<UniqueHandleId>; 4]>,
+    hover_count: Cell<usize>,
+    hovered: WatchedCellCore<'static, bool, DefaultOwner>,
+    drop_types: RefCell<Vec<TypeId>>,
+    drag_events: RefCell<WatchedQueue<'static, DragEvent>>,
 }
 
 #[derive(Default)]
@@ -38,6 +53,138 @@ impl UniqueHandle {
             });
         });
     }
+
+    /// Set if the widget this handle belongs to can receive focus.
+    ///
+    /// If the widget is currently focused and is made unfocusable, it will
+    /// lose focus.
+    pub fn set_focusable(&self, focusable: bool) {
+        self.ptr.focusable.set(focusable);
+        if !focusable {
+            self.release_focus();
+        }
+    }
+
+    /// Check if the widget this handle belongs to can receive focus.
+    #[must_use]
+    pub fn is_focusable(&self) -> bool {
+        self.ptr.focusable.get()
+    }
+
+    /// Set the tab index of the widget this handle belongs to.
+    ///
+    /// Like in HTML, focusable widgets with a positive tab index are
+    /// visited first when traversing with Tab, in increasing order, followed
+    /// by widgets with a tab index of zero (the default) in the order they
+    /// are described.  Widgets with a negative tab index can still be
+    /// focused, but are skipped by tab traversal.
+    pub fn set_tab_index(&self, tab_index: i32) {
+        self.ptr.tab_index.set(tab_index);
+    }
+
+    /// Get the tab index of the widget this handle belongs to.
+    #[must_use]
+    pub fn tab_index(&self) -> i32 {
+        self.ptr.tab_index.get()
+    }
+
+    /// Override which widget directional navigation moves focus to, when
+    /// the widget this handle belongs to is focused.
+    ///
+    /// Passing `None` restores the default behavior, of picking the nearest
+    /// focusable widget in that direction.
+    pub fn set_nav_target(
+        &self,
+        direction: Direction,
+        target: Option<UniqueHandleId>,
+    ) {
+        self.ptr.nav_targets.borrow_mut()[direction.index()] = target;
+    }
+
+    /// Check if the widget this handle belongs to is currently focused.
+    ///
+    /// This will bind a current watch function to the focus state.
+    #[must_use]
+    pub fn is_focused(&self) -> bool {
+        self.ptr.focused.get_auto()
+    }
+
+    /// Check if any pointer is currently over the widget this handle
+    /// belongs to.
+    ///
+    /// The app tracks which widgets are under each pointer using
+    /// [`Content::hittest`](crate::widget::Content::hittest); only widgets
+    /// which expose this handle with
+    /// [`Content::handle`](crate::widget::Content::handle) are tracked.
+    ///
+    /// This will bind a current watch function to the hover state.
+    #[must_use]
+    pub fn is_hovered(&self) -> bool {
+        self.ptr.hovered.get_auto()
+    }
+
+    /// Declare that the widget this handle belongs to accepts drag-and-drop
+    /// payloads of type `T`.
+    ///
+    /// See the [`drag`](crate::drag) module for details.
+    pub fn accept_drops<T: 'static>(&self) {
+        let type_id = TypeId::of::<T>();
+        let mut drop_types = self.ptr.drop_types.borrow_mut();
+        if !drop_types.contains(&type_id) {
+            drop_types.push(type_id);
+        }
+    }
+
+    /// Stop accepting any drag-and-drop payloads.
+    pub fn clear_accepted_drops(&self) {
+        self.ptr.drop_types.borrow_mut().clear();
+    }
+
+    /// Handle an event for a drag this widget started, or a drag over this
+    /// widget.
+    ///
+    /// This should be called in a watch closure, which will re-run for each
+    /// event.
+    pub fn handle_drag_event<F: FnOnce(&DragEvent)>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.ptr.drag_events.borrow().handle_item(arg, f);
+        });
+    }
+
+    /// Move focus to the widget this handle belongs to, if it is focusable.
+    ///
+    /// This has no effect if called outside the context of a suzy app.
+    pub fn request_focus(&self) {
+        if self.is_focusable() {
+            AppState::try_with_current(|state| {
+                state.set_focus(Some(self.id()))
+            });
+        }
+    }
+
+    /// Check if the widget this handle belongs to should respond to keys
+    /// which activate widgets, like Enter.
+    ///
+    /// This is true if the widget is focused, or if `is_default` is true
+    /// and no widget is focused.
+    pub(crate) fn is_activation_target(&self, is_default: bool) -> bool {
+        self.ptr.focused.get_unwatched()
+            || (is_default
+                && AppState::try_with_current(|state| {
+                    state.focused().is_none()
+                })
+                .unwrap_or(false))
+    }
+
+    /// Remove focus from the widget this handle belongs to, if it is
+    /// currently focused.
+    ///
+    /// This has no effect if called outside the context of a suzy app.
+    pub fn release_focus(&self) {
+        if self.ptr.focused.get_unwatched() {
+            AppState::try_with_current(|state| state.set_focus(None));
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -58,6 +205,53 @@ impl UniqueHandleId {
             }
         }
     }
+
+    pub(crate) fn is_focusable(&self) -> bool {
+        self.ptr
+            .upgrade()
+            .is_some_and(|strong| strong.focusable.get())
+    }
+
+    pub(crate) fn nav_target(
+        &self,
+        direction: Direction,
+    ) -> Option<UniqueHandleId> {
+        let strong = self.ptr.upgrade()?;
+        let target = strong.nav_targets.borrow()[direction.index()].clone();
+        target
+    }
+
+    pub(crate) fn set_focused(&self, focused: bool) {
+        if let Some(strong) = self.ptr.upgrade() {
+            strong.focused.set_if_neq_auto(focused);
+        }
+    }
+
+    pub(crate) fn accepts_drop(&self, type_id: TypeId) -> bool {
+        self.ptr.upgrade().is_some_and(|strong| {
+            strong.drop_types.borrow().contains(&type_id)
+        })
+    }
+
+    pub(crate) fn notify_drag(&self, event: DragEvent) {
+        if let Some(strong) = self.ptr.upgrade() {
+            strong.drag_events.borrow_mut().push_auto(event);
+        }
+    }
+
+    /// Record that a pointer has moved over the widget, or away from it.
+    pub(crate) fn pointer_over(&self, over: bool) {
+        if let Some(strong) = self.ptr.upgrade() {
+            let count = strong.hover_count.get();
+            let count = if over {
+                count + 1
+            } else {
+                count.saturating_sub(1)
+            };
+            strong.hover_count.set(count);
+            strong.hovered.set_if_neq_auto(count > 0);
+        }
+    }
 }
 
 impl Eq for UniqueHandleId {}
@@ -4,11 +4,43 @@
 //! Suzy comes with a set of built-in widgets.
 
 mod button;
+mod dropdown;
+mod editor;
 mod label;
+mod progress;
+#[cfg(feature = "platform-opengl")]
+mod scrollview;
+mod slider;
+#[cfg(feature = "platform-opengl")]
+mod tabview;
+mod textarea;
+mod textfield;
 mod togglebutton;
 
 pub use button::{Button, ButtonBehavior, DefaultButtonContent};
 
+pub use dropdown::{Dropdown, DropdownContent};
+
 pub use label::{Label, LabelContent};
 
+pub use progress::{ProgressBar, ProgressBarContent};
+
+#[cfg(feature = "platform-opengl")]
+pub use progress::{ProgressCircle, ProgressCircleContent};
+
+#[cfg(feature = "platform-opengl")]
+pub use scrollview::{ScrollAxes, ScrollView, ScrollViewContent};
+
+pub use slider::{
+    DefaultSliderContent, Orientation, RangeSlider, Slider, SliderBehavior,
+    SliderContent,
+};
+
+#[cfg(feature = "platform-opengl")]
+pub use tabview::{TabView, TabViewContent};
+
+pub use textarea::{TextArea, TextAreaContent};
+
+pub use textfield::{TextField, TextFieldContent};
+
 pub use togglebutton::{ToggleButton, ToggleButtonGroup, ToggleButtonValue};
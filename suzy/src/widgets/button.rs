@@ -5,9 +5,10 @@ use drying_paint::{Watched, WatchedQueue};
 
 use crate::{
     graphics::Color,
+    keyboard::{Key, KeyAction, KeyEvent},
     platform::RenderPlatform,
-    pointer::{PointerAction, PointerEvent, PointerId},
-    selectable::{Selectable, SelectionState, SelectionStateV1},
+    pointer::{PointerAction, PointerEvent},
+    selectable::{Selectable, SelectionState},
     widget::{self, UniqueHandle, Widget, WidgetRect},
 };
 
@@ -17,6 +18,8 @@ pub struct ButtonBehavior<T> {
     state: Watched<SelectionState>,
     interactable: Watched<bool>,
     pointers_down: usize,
+    key_down: Option<Key>,
+    is_default: bool,
     handle: UniqueHandle,
     content: T,
 }
@@ -34,7 +37,36 @@ impl<T> ButtonBehavior<T> {
 
     /// Get the current button selection state.
     pub fn state(&self) -> SelectionState {
-        *self.state
+        let state = *self.state;
+        if state != SelectionState::normal() {
+            state
+        } else if self.handle.is_focused() {
+            SelectionState::focus()
+        } else if *self.interactable && self.handle.is_hovered() {
+            SelectionState::hover()
+        } else {
+            state
+        }
+    }
+
+    /// Set the tab index of this button.
+    ///
+    /// See [`UniqueHandle::set_tab_index`] for details.
+    pub fn set_tab_index(&self, tab_index: i32) {
+        self.handle.set_tab_index(tab_index);
+    }
+
+    /// Move focus to this button.
+    pub fn request_focus(&self) {
+        self.handle.request_focus();
+    }
+
+    /// Set if this is a default button.
+    ///
+    /// Buttons normally respond to Enter or Space only when focused.  A
+    /// default button will also respond to Enter when no widget is focused.
+    pub fn set_default(&mut self, is_default: bool) {
+        self.is_default = is_default;
     }
 
     pub fn on_click<F: FnOnce()>(&self, f: F) {
@@ -51,9 +83,11 @@ where
     fn desc(mut desc: impl widget::Desc<Self, P>) {
         desc.bare_child(|button| &mut button.content);
         desc.watch(|button, _rect| {
-            button.content.selection_changed(*button.state);
+            let state = button.state();
+            button.content.selection_changed(state);
         });
         desc.watch(|button, _rect| {
+            button.handle.set_focusable(*button.interactable);
             if !*button.interactable {
                 *button.state = SelectionState::normal();
             }
@@ -78,6 +112,10 @@ where
         self.content.hittest(rect, point)
     }
 
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
     fn pointer_event(
         &mut self,
         rect: &WidgetRect,
@@ -91,6 +129,7 @@ where
                     self.pointers_down += 1;
                     if *self.interactable {
                         *self.state = SelectionState::active();
+                        self.handle.request_focus();
                     }
                 }
                 grabbed
@@ -111,30 +150,38 @@ where
                 if ungrabbed {
                     self.pointers_down -= 1;
                     if self.pointers_down == 0 {
-                        *self.state = if event.id() == PointerId::Mouse {
-                            SelectionState::hover()
-                        } else {
-                            SelectionState::normal()
-                        };
+                        *self.state = SelectionState::normal();
                         self.on_click.push_external(());
                     }
                 }
                 ungrabbed
             }
-            PointerAction::Hover(_, _) => {
-                match (self.state.v1(), self.hittest(rect, event.pos())) {
-                    (SelectionStateV1::Normal, true) => {
-                        if *self.interactable {
-                            *self.state = SelectionState::hover();
-                        }
-                        true
-                    }
-                    (SelectionStateV1::Hover, false) => {
-                        *self.state = SelectionState::normal();
-                        true
-                    }
-                    _ => false,
+            _ => false,
+        }
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        match (event.action, event.key) {
+            (KeyAction::Down, key @ (Key::Enter | Key::Space)) => {
+                let is_default = self.is_default && key == Key::Enter;
+                if !*self.interactable
+                    || !self.handle.is_activation_target(is_default)
+                {
+                    return false;
+                }
+                if self.key_down.is_none() && self.pointers_down == 0 {
+                    self.key_down = Some(key);
+                    *self.state = SelectionState::pressed();
                 }
+                true
+            }
+            (KeyAction::Up, key) if self.key_down == Some(key) => {
+                self.key_down = None;
+                if self.pointers_down == 0 {
+                    *self.state = SelectionState::normal();
+                    self.on_click.push_external(());
+                }
+                true
             }
             _ => false,
         }
@@ -143,12 +190,16 @@ where
 
 impl<T: Default> Default for ButtonBehavior<T> {
     fn default() -> Self {
+        let handle = UniqueHandle::default();
+        handle.set_focusable(true);
         Self {
             on_click: WatchedQueue::default(),
             state: Watched::default(),
             interactable: Watched::new(true),
             pointers_down: 0,
-            handle: UniqueHandle::default(),
+            key_down: None,
+            is_default: false,
+            handle,
             content: T::default(),
         }
     }
@@ -207,14 +258,18 @@ where
 {
     fn selection_changed(&mut self, state: SelectionState) {
         use crate::{
-            platform::graphics::SlicedImage, selectable::SelectionStateV0,
+            platform::graphics::SlicedImage, selectable::SelectionStateV2,
         };
-        match state.v0() {
-            SelectionStateV0::Active => {
+        match state.v2() {
+            SelectionStateV2::Active | SelectionStateV2::Pressed => {
                 *self.text_color = Color::BLACK;
                 self.image.set_color(Color::LAVENDER);
             }
-            _ => {
+            SelectionStateV2::Focus => {
+                *self.text_color = Color::WHITE;
+                self.image.set_color(Color::MEDIUM_SLATE_BLUE);
+            }
+            SelectionStateV2::Normal | SelectionStateV2::Hover => {
                 *self.text_color = Color::LAVENDER;
                 self.image.set_color(Color::SLATE_BLUE);
             }
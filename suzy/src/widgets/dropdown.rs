@@ -0,0 +1,653 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{
+    fmt::Display,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use drying_paint::{Watched, WatchedCell};
+
+use crate::{
+    adapter::{
+        Adaptable, AdapterLayout, AdapterLayoutInterface, AdapterView,
+        DownwardVecLayout,
+    },
+    app::Overlay,
+    dims::{Padding2d, Rect},
+    graphics::{Color, Conditional, CornerStyle},
+    keyboard::{Key, KeyAction, KeyEvent},
+    platform::{
+        graphics::{SlicedImage, Text, TextStyle},
+        RenderPlatform,
+    },
+    pointer::{PointerAction, PointerEvent},
+    text,
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+/// The space between the edge of a dropdown and its text.
+const PADDING: f32 = 6.0;
+
+const FONT_SIZE: f32 = 16.0;
+
+/// The height of each option in the popup list.
+const ROW_HEIGHT: f32 = 28.0;
+
+/// The most options the popup list shows at once; it scrolls to show the
+/// rest.
+const MAX_VISIBLE_ROWS: usize = 8;
+
+/// How many options Page Up and Page Down move by.
+const PAGE_ROWS: usize = MAX_VISIBLE_ROWS - 1;
+
+/// How long after the last key press typed characters are still used to
+/// find an option.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The state shared between a dropdown and the options in its popup list.
+#[derive(Default)]
+struct ListState {
+    highlighted: WatchedCell<Option<usize>>,
+    chosen: WatchedCell<Option<usize>>,
+}
+
+/// An option, as displayed in the popup list.
+#[derive(Clone)]
+struct Choice {
+    index: usize,
+    label: String,
+    list: Rc<ListState>,
+}
+
+/// Lays out the options in the popup list downwards, each filling the
+/// width of the list.
+#[derive(Default)]
+struct ChoiceLayout {
+    rows: DownwardVecLayout<Choice>,
+}
+
+impl AdapterLayout for ChoiceLayout {
+    type ElementKey = usize;
+    type Collection = Vec<Choice>;
+    type ElementData = Choice;
+
+    fn data(&self) -> &Vec<Choice> {
+        self.rows.data()
+    }
+
+    fn data_mut(&mut self) -> &mut Vec<Choice> {
+        self.rows.data_mut()
+    }
+
+    fn layout(
+        &mut self,
+        interface: impl AdapterLayoutInterface<usize, Choice>,
+    ) {
+        self.rows.layout(RowSizer { interface });
+    }
+
+    fn element_location(
+        &mut self,
+        item: &usize,
+        reference_position: [f32; 2],
+    ) -> Option<[f32; 2]> {
+        self.rows.element_location(item, reference_position)
+    }
+}
+
+/// Sizes each element before the wrapped layout positions it.
+struct RowSizer<I> {
+    interface: I,
+}
+
+impl<I> AdapterLayoutInterface<usize, Choice> for RowSizer<I>
+where
+    I: AdapterLayoutInterface<usize, Choice>,
+{
+    type Bounds = I::Bounds;
+    type Element = I::Element;
+
+    fn reference_position(&self) -> [f32; 2] {
+        self.interface.reference_position()
+    }
+
+    fn bounds(&self) -> &I::Bounds {
+        self.interface.bounds()
+    }
+
+    fn update_positions(
+        &mut self,
+        reference_position: [f32; 2],
+        rest_position: [f32; 2],
+    ) {
+        self.interface
+            .update_positions(reference_position, rest_position);
+    }
+
+    fn num_active_elements(&self) -> usize {
+        self.interface.num_active_elements()
+    }
+
+    fn get_element(&mut self, key: usize, data: &Choice) -> &mut I::Element {
+        let bounds = self.interface.bounds();
+        let [left, right] = [bounds.left(), bounds.right()];
+        let element = self.interface.get_element(key, data);
+        element.set_horizontal_stretch(left, right);
+        element.set_height(ROW_HEIGHT);
+        element
+    }
+}
+
+/// An option in the popup list.
+struct ChoiceRow<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    choice: Watched<Choice>,
+    pressed: bool,
+    handle: UniqueHandle,
+    highlight: Conditional<P::SlicedImage>,
+    text_graphic: P::Text,
+}
+
+impl<P> Adaptable<Choice> for ChoiceRow<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn adapt(&mut self, data: &Choice) {
+        *self.choice = data.clone();
+    }
+
+    fn from(data: &Choice) -> Self {
+        Self {
+            choice: Watched::new(data.clone()),
+            pressed: false,
+            handle: UniqueHandle::default(),
+            highlight: Conditional::default(),
+            text_graphic: P::Text::default(),
+        }
+    }
+}
+
+impl<P> widget::Content<P> for ChoiceRow<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, _rect| {
+            if this.handle.is_hovered() {
+                this.choice.list.highlighted.set(Some(this.choice.index));
+            }
+        });
+        desc.watch(|this, rect| {
+            let highlighted = this.choice.list.highlighted.get();
+            this.highlight.enable = highlighted == Some(this.choice.index);
+            this.highlight.graphic.set_color(Color::LIGHT_SKY_BLUE);
+            this.highlight.graphic.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|this, rect| {
+            this.text_graphic.set_layout(text::Layout {
+                alignment: text::Alignment::Left,
+                line: text::Line::BetweenBaseAndCap,
+                flow: text::Flow::Out,
+                origin_x: rect.left() + PADDING,
+                origin_y: rect.center_y(),
+                wrap_width: rect.width() - 2.0 * PADDING,
+                vertical_limit: text::VerticalLimit::Lines(1),
+                overflow_mode: text::OverflowMode::Ellipsis,
+            });
+        });
+        desc.watch(|this, _rect| {
+            let style =
+                P::TextStyle::with_size_and_color(FONT_SIZE, Color::BLACK);
+            this.text_graphic.clear();
+            this.text_graphic.push_span(style, &this.choice.label);
+            this.text_graphic.finish();
+        });
+        desc.watch(|this, _rect| {
+            let pressed = &mut this.pressed;
+            this.handle.handle_pointer_grab_stolen(|_pointer_id| {
+                *pressed = false;
+            });
+        });
+        desc.graphic(|this| &mut this.highlight);
+        desc.graphic(|this| &mut this.text_graphic);
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down => {
+                self.pressed = self.hittest(rect, event.pos())
+                    && event.try_grab(self.handle.id());
+                self.pressed
+            }
+            PointerAction::Up if self.pressed => {
+                self.pressed = false;
+                let ungrabbed = event.try_ungrab(self.handle.id());
+                if ungrabbed && self.hittest(rect, event.pos()) {
+                    self.choice.list.chosen.set(Some(self.choice.index));
+                }
+                ungrabbed
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The popup list of a dropdown.
+struct PopupContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    list: Widget<AdapterView<ChoiceLayout, ChoiceRow<P>>>,
+    background: P::SlicedImage,
+}
+
+impl<P> Default for PopupContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        Self {
+            list: Widget::default(),
+            background: P::SlicedImage::default(),
+        }
+    }
+}
+
+impl<P> widget::Content<P> for PopupContent<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, rect| {
+            this.background.set_fill(rect, &Padding2d::zero());
+            this.background.set_slice_padding(Padding2d::uniform(4.0));
+            this.background.set_corners(CornerStyle::Rounded);
+            this.background.set_color(Color::WHITE);
+            this.list.set_fill(rect, &Padding2d::zero());
+        });
+        desc.graphic(|this| &mut this.background);
+        desc.child(|this| &mut this.list);
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        // don't let presses or scrolling reach the widgets under the popup
+        match event.action() {
+            PointerAction::Down | PointerAction::Wheel(_, _) => {
+                self.hittest(rect, event.pos())
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A widget which shows the current choice from a list of options, and
+/// opens a popup list to choose a different one.
+#[cfg(feature = "platform-opengl")]
+pub type Dropdown<T, P = crate::platforms::DefaultRenderPlatform> =
+    Widget<DropdownContent<T, P>>;
+
+/// A widget which shows the current choice from a list of options, and
+/// opens a popup list to choose a different one.
+#[cfg(not(feature = "platform-opengl"))]
+pub type Dropdown<T, P> = Widget<DropdownContent<T, P>>;
+
+/// The content for a widget which shows the current choice from a list of
+/// options.
+///
+/// Clicking the dropdown opens a popup list of the options, drawn above
+/// every other widget; the list scrolls if there are too many options to
+/// show at once.  Choosing an option, or pressing outside the list, closes
+/// it.
+///
+/// While the dropdown is focused, Enter or Space opens the list, and the
+/// arrow keys, Home, End, Page Up and Page Down move through the options.
+/// Typing the start of an option's text moves to that option.  While the
+/// list is open these keys move the highlighted option instead, Enter or
+/// Space chooses it, and Escape closes the list.
+///
+/// Options are displayed using their [`Display`] implementation.
+pub struct DropdownContent<T, P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// The option currently chosen, if any.
+    pub selected: Watched<Option<T>>,
+    /// Text displayed while no option is chosen.
+    pub placeholder: Watched<String>,
+    pub text_color: Watched<Color>,
+    pub placeholder_color: Watched<Color>,
+    pub background_color: Watched<Color>,
+    options: Watched<Vec<T>>,
+    list: Rc<ListState>,
+    popup: Overlay<PopupContent<P>>,
+    pressed: bool,
+    type_ahead: String,
+    last_typed: Option<Instant>,
+    handle: UniqueHandle,
+    background: P::SlicedImage,
+    text_graphic: P::Text,
+}
+
+impl<T, P> Default for DropdownContent<T, P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        let handle = UniqueHandle::default();
+        handle.set_focusable(true);
+        Self {
+            selected: Watched::new(None),
+            placeholder: Watched::default(),
+            text_color: Watched::new(Color::BLACK),
+            placeholder_color: Watched::new(Color::GRAY),
+            background_color: Watched::new(Color::WHITE),
+            options: Watched::default(),
+            list: Rc::default(),
+            popup: Overlay::default(),
+            pressed: false,
+            type_ahead: String::new(),
+            last_typed: None,
+            handle,
+            background: P::SlicedImage::default(),
+            text_graphic: P::Text::default(),
+        }
+    }
+}
+
+impl<T, P> DropdownContent<T, P>
+where
+    T: 'static + Clone + PartialEq + Display,
+    P: 'static + RenderPlatform,
+{
+    /// Get the options which can be chosen.
+    pub fn options(&self) -> &[T] {
+        &self.options
+    }
+
+    /// Replace the options which can be chosen.
+    ///
+    /// This does not change the selected value.
+    pub fn set_options(&mut self, options: Vec<T>) {
+        *self.options = options;
+    }
+
+    /// Check if the popup list is open.
+    ///
+    /// This will bind a current watch function to the state of the list.
+    pub fn is_open(&self) -> bool {
+        self.popup.is_shown()
+    }
+
+    /// Open the popup list, and move focus to the dropdown.
+    pub fn open(&self) {
+        self.highlight(self.selected_index());
+        self.popup.show::<P>();
+        self.handle.request_focus();
+    }
+
+    /// Close the popup list without choosing an option.
+    pub fn close(&self) {
+        self.popup.dismiss();
+    }
+
+    /// Set the tab index of this dropdown.
+    ///
+    /// See [`UniqueHandle::set_tab_index`] for details.
+    pub fn set_tab_index(&self, tab_index: i32) {
+        self.handle.set_tab_index(tab_index);
+    }
+
+    /// Move focus to this dropdown.
+    pub fn request_focus(&self) {
+        self.handle.request_focus();
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = Watched::get_unwatched(&self.selected).as_ref()?;
+        self.options.iter().position(|option| option == selected)
+    }
+
+    /// The option keys currently move from: the highlighted option while
+    /// the list is open, otherwise the selected one.
+    fn current_index(&self) -> Option<usize> {
+        if self.popup.is_shown() {
+            self.list.highlighted.get()
+        } else {
+            self.selected_index()
+        }
+    }
+
+    /// Highlight an option in the popup list, scrolling the list to show
+    /// it.
+    fn highlight(&self, index: Option<usize>) {
+        self.list.highlighted.set(index);
+        if let Some(index) = index {
+            self.popup.widget_mut().list.scroll_to(index);
+        }
+    }
+
+    fn move_to(&mut self, index: usize) {
+        let Some(option) = self.options.get(index) else {
+            return;
+        };
+        if self.popup.is_shown() {
+            self.highlight(Some(index));
+        } else {
+            *self.selected = Some(option.clone());
+        }
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        let last = self.options.len().saturating_sub(1);
+        let index = match self.current_index() {
+            Some(current) => current.saturating_add_signed(delta).min(last),
+            None if delta < 0 => last,
+            None => 0,
+        };
+        self.move_to(index);
+    }
+
+    /// Move to the next option which starts with the text typed recently.
+    fn type_ahead(&mut self, ch: char) {
+        let now = crate::app::time_unwatched();
+        let expired = self.last_typed.is_none_or(|last| {
+            now.saturating_duration_since(last) > TYPE_AHEAD_TIMEOUT
+        });
+        if expired {
+            self.type_ahead.clear();
+        }
+        self.last_typed = Some(now);
+        self.type_ahead.extend(ch.to_lowercase());
+        // typing the same character repeatedly cycles through the options
+        // starting with it
+        let mut chars = self.type_ahead.chars();
+        let first = chars.next();
+        let repeated = chars.all(|c| Some(c) == first);
+        let (prefix, skip) = if repeated {
+            (&self.type_ahead[..first.map_or(0, char::len_utf8)], 1)
+        } else {
+            (&self.type_ahead[..], 0)
+        };
+        let count = self.options.len();
+        let start = self.current_index().map_or(0, |current| current + skip);
+        let found = (0..count).map(|i| (start + i) % count).find(|&index| {
+            let label = self.options[index].to_string().to_lowercase();
+            label.starts_with(prefix)
+        });
+        if let Some(index) = found {
+            self.move_to(index);
+        }
+    }
+
+    fn handle_key(&mut self, event: &KeyEvent) -> bool {
+        let open = self.popup.is_shown();
+        match event.key {
+            Key::Enter | Key::Space if open => {
+                if let Some(index) = self.list.highlighted.get() {
+                    self.list.chosen.set(Some(index));
+                }
+                self.close();
+            }
+            Key::Enter | Key::Space => self.open(),
+            Key::Escape if open => self.close(),
+            Key::ArrowUp => self.move_by(-1),
+            Key::ArrowDown => self.move_by(1),
+            Key::PageUp => self.move_by(-(PAGE_ROWS as isize)),
+            Key::PageDown => self.move_by(PAGE_ROWS as isize),
+            Key::Home => self.move_to(0),
+            Key::End => self.move_to(self.options.len().saturating_sub(1)),
+            Key::Character(ch) => self.type_ahead(ch),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl<T, P> widget::Content<P> for DropdownContent<T, P>
+where
+    T: 'static + Clone + PartialEq + Display,
+    P: 'static + RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, rect| {
+            this.background.set_fill(rect, &Padding2d::zero());
+            this.background.set_slice_padding(Padding2d::uniform(4.0));
+            this.background.set_corners(CornerStyle::Rounded);
+        });
+        desc.watch(|this, _rect| {
+            this.background.set_color(*this.background_color);
+        });
+        desc.watch(|this, rect| {
+            this.text_graphic.set_layout(text::Layout {
+                alignment: text::Alignment::Left,
+                line: text::Line::BetweenBaseAndCap,
+                flow: text::Flow::Out,
+                origin_x: rect.left() + PADDING,
+                origin_y: rect.center_y(),
+                wrap_width: rect.width() - 2.0 * PADDING,
+                vertical_limit: text::VerticalLimit::Lines(1),
+                overflow_mode: text::OverflowMode::Ellipsis,
+            });
+        });
+        desc.watch(|this, _rect| {
+            let (display, color) = match &*this.selected {
+                Some(selected) => (selected.to_string(), *this.text_color),
+                None => (this.placeholder.clone(), *this.placeholder_color),
+            };
+            let style = P::TextStyle::with_size_and_color(FONT_SIZE, color);
+            this.text_graphic.clear();
+            this.text_graphic.push_span(style, &display);
+            this.text_graphic.finish();
+        });
+        desc.watch(|this, _rect| {
+            let choices = this
+                .options
+                .iter()
+                .enumerate()
+                .map(|(index, option)| Choice {
+                    index,
+                    label: option.to_string(),
+                    list: Rc::clone(&this.list),
+                })
+                .collect();
+            let mut popup = this.popup.widget_mut();
+            *popup.list.data_mut() = choices;
+        });
+        desc.watch(|this, rect| {
+            if !this.popup.is_shown() {
+                return;
+            }
+            let rows = this.options.len().clamp(1, MAX_VISIBLE_ROWS);
+            let height = rows as f32 * ROW_HEIGHT;
+            let mut popup = this.popup.widget_mut();
+            popup.set_horizontal_stretch(rect.left(), rect.right());
+            popup.set_height(height);
+            // open downwards, unless that would go past the bottom of the
+            // window
+            if rect.bottom() - height >= 0.0 {
+                popup.set_top(rect.bottom());
+            } else {
+                popup.set_bottom(rect.top());
+            }
+        });
+        desc.watch(|this, _rect| {
+            if let Some(index) = this.list.chosen.take() {
+                if let Some(option) = this.options.get(index) {
+                    *this.selected = Some(option.clone());
+                }
+                this.popup.dismiss();
+            }
+        });
+        desc.watch(|this, _rect| {
+            if !this.handle.is_focused() {
+                this.popup.dismiss();
+            }
+        });
+        desc.watch(|this, _rect| {
+            let pressed = &mut this.pressed;
+            this.handle.handle_pointer_grab_stolen(|_pointer_id| {
+                *pressed = false;
+            });
+        });
+        desc.graphic(|this| &mut this.background);
+        desc.graphic(|this| &mut this.text_graphic);
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down => {
+                self.pressed = self.hittest(rect, event.pos())
+                    && event.try_grab(self.handle.id());
+                if self.pressed {
+                    self.handle.request_focus();
+                }
+                self.pressed
+            }
+            PointerAction::Up if self.pressed => {
+                self.pressed = false;
+                let ungrabbed = event.try_ungrab(self.handle.id());
+                if ungrabbed && self.hittest(rect, event.pos()) {
+                    self.open();
+                }
+                ungrabbed
+            }
+            _ => false,
+        }
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        let mods = event.modifiers;
+        if event.action != KeyAction::Down
+            || mods.ctrl
+            || mods.alt
+            || !self.handle.is_focused()
+        {
+            return false;
+        }
+        self.handle_key(event)
+    }
+}
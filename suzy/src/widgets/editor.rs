@@ -0,0 +1,445 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::ops::Range;
+
+/// The most changes a [`History`] keeps to undo.
+const HISTORY_LIMIT: usize = 100;
+
+/// A way the caret can be moved through the text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Movement {
+    Left,
+    Right,
+    WordLeft,
+    WordRight,
+    Home,
+    End,
+}
+
+/// The text being edited by a text widget, along with the caret and
+/// selection.
+///
+/// All indices are byte offsets, which always lie on character boundaries.
+/// The selection extends from the anchor to the caret.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct TextEditor {
+    text: String,
+    caret: usize,
+    anchor: usize,
+}
+
+/// Text which an input method is composing.  It is displayed in place of
+/// the selection, but is not part of the text until it is committed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct Composition {
+    pub text: String,
+    /// The byte range of `text` selected by the input method.
+    pub cursor: Range<usize>,
+}
+
+impl Composition {
+    /// Get the composition described by an editing event, or `None` if it
+    /// was cleared.
+    pub fn from_editing(text: &str, cursor: &Range<usize>) -> Option<Self> {
+        // input methods are not trusted to report valid indices
+        let valid = |index: usize| {
+            if text.is_char_boundary(index) {
+                index
+            } else {
+                text.len()
+            }
+        };
+        let end = valid(cursor.end);
+        let start = valid(cursor.start).min(end);
+        (!text.is_empty()).then(|| Self {
+            text: text.to_owned(),
+            cursor: start..end,
+        })
+    }
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+fn char_count(text: &str) -> usize {
+    text.chars().count()
+}
+
+impl TextEditor {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    pub fn selection(&self) -> Range<usize> {
+        self.caret.min(self.anchor)..self.caret.max(self.anchor)
+    }
+
+    pub fn has_selection(&self) -> bool {
+        self.caret != self.anchor
+    }
+
+    pub fn selected_text(&self) -> &str {
+        &self.text[self.selection()]
+    }
+
+    /// Replace the text, moving the caret to the end.
+    pub fn set_text(&mut self, text: &str) {
+        text.clone_into(&mut self.text);
+        self.caret = self.text.len();
+        self.anchor = self.caret;
+    }
+
+    /// Select from `anchor` to `caret`, snapping each to a character
+    /// boundary.
+    pub fn select(&mut self, anchor: usize, caret: usize) {
+        self.anchor = self.snap(anchor);
+        self.caret = self.snap(caret);
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = 0;
+        self.caret = self.text.len();
+    }
+
+    /// Select the word, or run of other characters, at `index`.
+    pub fn select_word(&mut self, index: usize) {
+        let index = self.snap(index);
+        let class = |ch: char| (is_word_char(ch), ch.is_whitespace());
+        let Some(target) = self.text[index..]
+            .chars()
+            .next()
+            .or_else(|| self.text[..index].chars().next_back())
+            .map(class)
+        else {
+            return;
+        };
+        let start = self.text[..index]
+            .char_indices()
+            .rev()
+            .take_while(|(_, ch)| class(*ch) == target)
+            .last()
+            .map_or(index, |(i, _)| i);
+        let end = self.text[index..]
+            .char_indices()
+            .find(|(_, ch)| class(*ch) != target)
+            .map_or(self.text.len(), |(i, _)| index + i);
+        self.anchor = start;
+        self.caret = end;
+    }
+
+    /// Move the caret to `index`.  If `extend` is true, the selection is
+    /// extended to it, otherwise the selection is cleared.
+    pub fn move_to(&mut self, index: usize, extend: bool) {
+        self.caret = self.snap(index);
+        if !extend {
+            self.anchor = self.caret;
+        }
+    }
+
+    /// Move the caret.
+    ///
+    /// If there is a selection and it is not being extended, moving left or
+    /// right collapses it to the respective edge instead.
+    pub fn apply(&mut self, movement: Movement, extend: bool) {
+        let target = if self.has_selection() && !extend {
+            match movement {
+                Movement::Left => self.selection().start,
+                Movement::Right => self.selection().end,
+                _ => self.target(movement),
+            }
+        } else {
+            self.target(movement)
+        };
+        self.move_to(target, extend);
+    }
+
+    /// Find where a movement would place the caret.
+    pub fn target(&self, movement: Movement) -> usize {
+        let before = &self.text[..self.caret];
+        let after = &self.text[self.caret..];
+        match movement {
+            Movement::Left => {
+                before.char_indices().next_back().map_or(0, |(i, _)| i)
+            }
+            Movement::Right => after
+                .chars()
+                .next()
+                .map_or(self.caret, |ch| self.caret + ch.len_utf8()),
+            Movement::WordLeft => {
+                let mut iter = before.char_indices().rev().peekable();
+                while iter.next_if(|(_, ch)| !is_word_char(*ch)).is_some() {}
+                let mut start = iter.peek().map_or(0, |(i, _)| *i);
+                for (i, ch) in iter {
+                    if !is_word_char(ch) {
+                        break;
+                    }
+                    start = i;
+                }
+                start
+            }
+            Movement::WordRight => {
+                let mut iter = after.char_indices().peekable();
+                while iter.next_if(|(_, ch)| !is_word_char(*ch)).is_some() {}
+                let end = iter
+                    .find(|(_, ch)| !is_word_char(*ch))
+                    .map_or(after.len(), |(i, _)| i);
+                self.caret + end
+            }
+            Movement::Home => 0,
+            Movement::End => self.text.len(),
+        }
+    }
+
+    /// Insert text at the caret, replacing the selection.
+    ///
+    /// If `max_length` is set, the inserted text is truncated so the text
+    /// has at most that many characters.  Returns true if the text changed.
+    pub fn insert(&mut self, text: &str, max_length: Option<usize>) -> bool {
+        let selection = self.selection();
+        let text = match max_length {
+            Some(max_length) => {
+                let kept = char_count(&self.text)
+                    - char_count(&self.text[selection.clone()]);
+                let available = max_length.saturating_sub(kept);
+                let end = text
+                    .char_indices()
+                    .nth(available)
+                    .map_or(text.len(), |(i, _)| i);
+                &text[..end]
+            }
+            None => text,
+        };
+        if text.is_empty() && selection.is_empty() {
+            return false;
+        }
+        self.text.replace_range(selection.clone(), text);
+        self.caret = selection.start + text.len();
+        self.anchor = self.caret;
+        true
+    }
+
+    /// Delete the selection, or if there is no selection, the text between
+    /// the caret and where `movement` would move it.  Returns true if the
+    /// text changed.
+    pub fn delete(&mut self, movement: Movement) -> bool {
+        if !self.has_selection() {
+            self.anchor = self.target(movement);
+        }
+        self.insert("", None)
+    }
+
+    fn snap(&self, index: usize) -> usize {
+        let mut index = index.min(self.text.len());
+        while !self.text.is_char_boundary(index) {
+            index -= 1;
+        }
+        index
+    }
+}
+
+/// The kind of a change to the text.  Consecutive changes of the same kind
+/// are undone together.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum EditKind {
+    Typing,
+    Deletion,
+    Other,
+}
+
+/// The undo and redo history of a [`TextEditor`].
+#[derive(Clone, Debug, Default)]
+pub(super) struct History {
+    undo: Vec<TextEditor>,
+    redo: Vec<TextEditor>,
+    last: Option<EditKind>,
+}
+
+impl History {
+    /// Change the text in an editor, recording the change.  Returns true
+    /// if the text changed.
+    ///
+    /// Only the state from before each group of changes is kept, so the
+    /// editor is copied only when the change starts a new group.
+    pub fn edit<F>(
+        &mut self,
+        editor: &mut TextEditor,
+        kind: EditKind,
+        f: F,
+    ) -> bool
+    where
+        F: FnOnce(&mut TextEditor) -> bool,
+    {
+        let starts_group = kind == EditKind::Other || self.last != Some(kind);
+        let before = starts_group.then(|| editor.clone());
+        if !f(editor) {
+            return false;
+        }
+        if let Some(before) = before {
+            if self.undo.len() == HISTORY_LIMIT {
+                self.undo.remove(0);
+            }
+            self.undo.push(before);
+        }
+        self.redo.clear();
+        self.last = Some(kind);
+        true
+    }
+
+    /// Start a new group, so the next change is undone separately from the
+    /// previous one.
+    pub fn break_group(&mut self) {
+        self.last = None;
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.last = None;
+    }
+
+    /// Undo the last change.  Returns true if there was a change to undo.
+    pub fn undo(&mut self, editor: &mut TextEditor) -> bool {
+        self.last = None;
+        Self::restore(&mut self.undo, &mut self.redo, editor)
+    }
+
+    /// Redo the last undone change.  Returns true if there was a change to
+    /// redo.
+    pub fn redo(&mut self, editor: &mut TextEditor) -> bool {
+        self.last = None;
+        Self::restore(&mut self.redo, &mut self.undo, editor)
+    }
+
+    fn restore(
+        from: &mut Vec<TextEditor>,
+        to: &mut Vec<TextEditor>,
+        editor: &mut TextEditor,
+    ) -> bool {
+        let Some(state) = from.pop() else {
+            return false;
+        };
+        to.push(std::mem::replace(editor, state));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EditKind, History, Movement, TextEditor};
+
+    fn editor(text: &str) -> TextEditor {
+        let mut editor = TextEditor::default();
+        editor.set_text(text);
+        editor
+    }
+
+    #[test]
+    fn move_by_character() {
+        let mut editor = editor("añb");
+        editor.apply(Movement::Left, false);
+        assert_eq!(editor.caret(), 3);
+        editor.apply(Movement::Left, false);
+        assert_eq!(editor.caret(), 1);
+        editor.apply(Movement::Right, true);
+        assert_eq!(editor.selected_text(), "ñ");
+        // moving without extending collapses the selection
+        editor.apply(Movement::Left, false);
+        assert_eq!(editor.caret(), 1);
+        assert!(!editor.has_selection());
+        editor.move_to(2, false);
+        assert_eq!(editor.caret(), 1);
+    }
+
+    #[test]
+    fn move_by_word() {
+        let mut editor = editor("one, two_2  three");
+        editor.apply(Movement::WordLeft, false);
+        assert_eq!(editor.caret(), 12);
+        editor.apply(Movement::WordLeft, false);
+        assert_eq!(editor.caret(), 5);
+        editor.apply(Movement::WordLeft, false);
+        assert_eq!(editor.caret(), 0);
+        editor.apply(Movement::WordRight, true);
+        assert_eq!(editor.selected_text(), "one");
+        editor.apply(Movement::WordRight, true);
+        assert_eq!(editor.selected_text(), "one, two_2");
+        editor.apply(Movement::End, false);
+        editor.apply(Movement::WordRight, false);
+        assert_eq!(editor.caret(), 17);
+    }
+
+    #[test]
+    fn select_word() {
+        let mut editor = editor("hello  world");
+        editor.select_word(8);
+        assert_eq!(editor.selected_text(), "world");
+        editor.select_word(6);
+        assert_eq!(editor.selected_text(), "  ");
+        editor.select_word(12);
+        assert_eq!(editor.selected_text(), "world");
+    }
+
+    #[test]
+    fn insert_and_delete() {
+        let mut editor = editor("hello world");
+        editor.select(0, 5);
+        assert!(editor.insert("goodbye", None));
+        assert_eq!(editor.text(), "goodbye world");
+        assert_eq!(editor.caret(), 7);
+        assert!(editor.delete(Movement::WordLeft));
+        assert_eq!(editor.text(), " world");
+        assert!(!editor.delete(Movement::Left));
+        assert!(editor.delete(Movement::Right));
+        assert_eq!(editor.text(), "world");
+        editor.select_all();
+        assert!(editor.delete(Movement::Left));
+        assert_eq!(editor.text(), "");
+    }
+
+    #[test]
+    fn max_length() {
+        let mut editor = editor("abc");
+        assert!(editor.insert("déf", Some(5)));
+        assert_eq!(editor.text(), "abcdé");
+        assert!(!editor.insert("g", Some(5)));
+        editor.select(0, 2);
+        assert!(editor.insert("xyz", Some(5)));
+        assert_eq!(editor.text(), "xycdé");
+    }
+
+    #[test]
+    fn undo_groups_changes() {
+        let mut editor = editor("");
+        let mut history = History::default();
+        for ch in ["a", "b", "c"] {
+            history
+                .edit(&mut editor, EditKind::Typing, |e| e.insert(ch, None));
+        }
+        history.break_group();
+        history.edit(&mut editor, EditKind::Typing, |e| e.insert("d", None));
+        history.edit(&mut editor, EditKind::Deletion, |e| {
+            e.delete(Movement::Left)
+        });
+        assert_eq!(editor.text(), "abc");
+        assert!(history.undo(&mut editor));
+        assert_eq!(editor.text(), "abcd");
+        assert!(history.undo(&mut editor));
+        assert_eq!(editor.text(), "abc");
+        assert!(history.undo(&mut editor));
+        assert_eq!(editor.text(), "");
+        assert!(!history.undo(&mut editor));
+        assert!(history.redo(&mut editor));
+        assert_eq!(editor.text(), "abc");
+        assert_eq!(editor.caret(), 3);
+        // a new change discards what could be redone
+        history.edit(&mut editor, EditKind::Typing, |e| e.insert("!", None));
+        assert!(!history.redo(&mut editor));
+        assert_eq!(editor.text(), "abc!");
+    }
+}
@@ -0,0 +1,238 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    animation::{eases, Animation},
+    dims::{Padding2d, Rect},
+    graphics::{Color, Conditional, CornerStyle},
+    platform::{graphics::SlicedImage, RenderPlatform},
+    watch::Watched,
+    widget::{self, Widget},
+};
+
+/// How long an indeterminate indicator takes to complete one cycle.
+const INDETERMINATE_PERIOD: Duration = Duration::from_millis(1500);
+
+/// How much of the track an indeterminate indicator fills.
+const INDETERMINATE_LENGTH: f32 = 0.3;
+
+/// How fast the indicator moves to a new progress value, in fractions of
+/// the track per second.
+const SMOOTHING_SPEED: f32 = 2.0;
+
+/// The animated state shared by the progress indicators.
+struct Indicator {
+    animation: Animation<f32>,
+    shown: f32,
+    cycle_start: Option<Instant>,
+}
+
+impl Default for Indicator {
+    fn default() -> Self {
+        let mut animation = Animation::new();
+        animation.set_speed(SMOOTHING_SPEED);
+        animation.set_ease(eases::EASE_OUT_QUAD.get());
+        Self {
+            animation,
+            shown: 0.0,
+            cycle_start: None,
+        }
+    }
+}
+
+impl Indicator {
+    fn animate_to(&mut self, progress: f32) {
+        self.animation.animate_to(progress.clamp(0.0, 1.0));
+    }
+
+    /// Update the animation, and find the start and end of the part of the
+    /// track which should be filled, from 0.0 to 1.0.
+    ///
+    /// A watch closure calling this re-runs every frame while the indicator
+    /// is moving.
+    fn update(&mut self, indeterminate: bool) -> [f32; 2] {
+        self.animation.apply(&mut self.shown);
+        if !indeterminate {
+            self.cycle_start = None;
+            return [0.0, self.shown];
+        }
+        let now = crate::app::time();
+        let cycle_start = *self.cycle_start.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(cycle_start);
+        let period = INDETERMINATE_PERIOD.as_secs_f32();
+        let phase = (elapsed.as_secs_f32() % period) / period;
+        // the filled part slides in from the start, and out past the end
+        let end = phase * (1.0 + INDETERMINATE_LENGTH);
+        let start = end - INDETERMINATE_LENGTH;
+        [start.max(0.0), end.min(1.0)]
+    }
+}
+
+/// A linear progress indicator.
+///
+/// Set `progress` to a value from 0.0 to 1.0; the bar moves smoothly to
+/// fill that much of its track.  While `indeterminate` is set, the bar
+/// shows an animation instead, for when the progress is unknown.
+#[cfg(feature = "platform-opengl")]
+pub type ProgressBar<P = crate::platforms::DefaultRenderPlatform> =
+    Widget<ProgressBarContent<P>>;
+
+/// A linear progress indicator.
+///
+/// Set `progress` to a value from 0.0 to 1.0; the bar moves smoothly to
+/// fill that much of its track.  While `indeterminate` is set, the bar
+/// shows an animation instead, for when the progress is unknown.
+#[cfg(not(feature = "platform-opengl"))]
+pub type ProgressBar<P> = Widget<ProgressBarContent<P>>;
+
+/// The content for a linear progress indicator.
+pub struct ProgressBarContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// How much of the work is done, from 0.0 to 1.0.
+    pub progress: Watched<f32>,
+    /// If set, an animation is shown instead of the progress.
+    pub indeterminate: Watched<bool>,
+    /// The color of the filled part of the bar.
+    pub color: Watched<Color>,
+    indicator: Indicator,
+    track: P::SlicedImage,
+    fill: Conditional<P::SlicedImage>,
+}
+
+impl<P> Default for ProgressBarContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        Self {
+            progress: Watched::new(0.0),
+            indeterminate: Watched::new(false),
+            color: Watched::new(Color::MEDIUM_SLATE_BLUE),
+            indicator: Indicator::default(),
+            track: P::SlicedImage::default(),
+            fill: Conditional::default(),
+        }
+    }
+}
+
+impl<P> widget::Content<P> for ProgressBarContent<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, _rect| {
+            this.track.set_color(Color::LIGHT_GRAY);
+            this.track.set_corners(CornerStyle::Rounded);
+            this.fill.graphic.set_corners(CornerStyle::Rounded);
+        });
+        desc.watch(|this, _rect| {
+            this.fill.graphic.set_color(*this.color);
+        });
+        desc.watch(|this, _rect| {
+            this.indicator.animate_to(*this.progress);
+        });
+        desc.watch(|this, rect| {
+            let padding = Padding2d::uniform(rect.height() / 2.0);
+            this.track.set_slice_padding(padding);
+            this.fill.graphic.set_slice_padding(padding);
+            this.track.set_fill(rect, &Padding2d::zero());
+            let [start, end] = this.indicator.update(*this.indeterminate);
+            let at = |fraction: f32| rect.left() + fraction * rect.width();
+            this.fill.enable = end > start;
+            this.fill.graphic.set_horizontal_stretch(at(start), at(end));
+            this.fill
+                .graphic
+                .set_vertical_stretch(rect.bottom(), rect.top());
+        });
+        desc.graphic(|this| &mut this.track);
+        desc.graphic(|this| &mut this.fill);
+    }
+}
+
+#[cfg(feature = "platform-opengl")]
+mod circle {
+    use std::f32::consts::{FRAC_PI_2, TAU};
+
+    use crate::{
+        dims::Rect,
+        graphics::Color,
+        platforms::opengl::{Circle, OpenGlRenderPlatform},
+        watch::Watched,
+        widget::{self, Widget},
+    };
+
+    use super::Indicator;
+
+    /// A circular progress indicator.
+    ///
+    /// Set `progress` to a value from 0.0 to 1.0; the arc moves smoothly
+    /// to fill that much of the ring, clockwise from the top.  While
+    /// `indeterminate` is set, the arc spins instead, for when the progress
+    /// is unknown.
+    pub type ProgressCircle = Widget<ProgressCircleContent>;
+
+    /// The content for a circular progress indicator.
+    pub struct ProgressCircleContent {
+        /// How much of the work is done, from 0.0 to 1.0.
+        pub progress: Watched<f32>,
+        /// If set, an animation is shown instead of the progress.
+        pub indeterminate: Watched<bool>,
+        /// The color of the filled part of the ring.
+        pub color: Watched<Color>,
+        /// The width of the ring.
+        pub thickness: Watched<f32>,
+        indicator: Indicator,
+        track: Circle,
+        arc: Circle,
+    }
+
+    impl Default for ProgressCircleContent {
+        fn default() -> Self {
+            Self {
+                progress: Watched::new(0.0),
+                indeterminate: Watched::new(false),
+                color: Watched::new(Color::MEDIUM_SLATE_BLUE),
+                thickness: Watched::new(6.0),
+                indicator: Indicator::default(),
+                track: Circle::new(),
+                arc: Circle::new(),
+            }
+        }
+    }
+
+    impl widget::Content<OpenGlRenderPlatform> for ProgressCircleContent {
+        fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+            desc.watch(|this, _rect| {
+                this.track.color = Color::LIGHT_GRAY;
+                this.arc.color = *this.color;
+            });
+            desc.watch(|this, _rect| {
+                this.indicator.animate_to(*this.progress);
+            });
+            desc.watch(|this, rect| {
+                let outer_radius = rect.width().min(rect.height()) / 2.0;
+                let inner_radius = (outer_radius - *this.thickness).max(0.0);
+                for circle in [&mut this.track, &mut this.arc] {
+                    circle.center = rect.center();
+                    circle.outer_radius = outer_radius;
+                    circle.inner_radius = inner_radius;
+                }
+                let [start, end] = this.indicator.update(*this.indeterminate);
+                // while indeterminate, the whole arc also turns, so it
+                // appears to spin
+                let turn = if *this.indeterminate { end } else { 0.0 };
+                this.arc.start_angle = FRAC_PI_2 - (start + turn) * TAU;
+                this.arc.sweep = -(end - start) * TAU;
+            });
+            desc.graphic(|this| &mut this.track);
+            desc.graphic(|this| &mut this.arc);
+        }
+    }
+}
+
+#[cfg(feature = "platform-opengl")]
+pub use circle::{ProgressCircle, ProgressCircleContent};
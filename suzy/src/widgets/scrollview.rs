@@ -0,0 +1,416 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::time::{Duration, Instant};
+
+use drying_paint::Watched;
+
+use crate::{
+    dims::{Padding, Padding2d, Rect, SimpleRect},
+    graphics::{Color, Conditional, CornerStyle},
+    platform::graphics::SlicedImage as _,
+    platforms::opengl::{Mask, OpenGlRenderPlatform, SlicedImage},
+    pointer::{PointerAction, PointerEvent, PointerId},
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+/// How long the scrollbars stay visible after the view stops scrolling.
+const AUTO_HIDE_DELAY: Duration = Duration::from_millis(800);
+
+/// How long the scrollbars take to fade out once they start hiding.
+const FADE_DURATION: Duration = Duration::from_millis(300);
+
+const THUMB_THICKNESS: f32 = 6.0;
+const THUMB_MARGIN: f32 = 2.0;
+const MIN_THUMB_LENGTH: f32 = 20.0;
+
+/// The axes a [`ScrollView`] scrolls along.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ScrollAxes {
+    /// The content scrolls left and right, and is stretched to the height
+    /// of the view.
+    Horizontal,
+    /// The content scrolls up and down, and is stretched to the width of
+    /// the view.
+    #[default]
+    Vertical,
+    /// The content scrolls in both directions.
+    Both,
+}
+
+impl ScrollAxes {
+    fn scrolls(self, axis: usize) -> bool {
+        match self {
+            Self::Horizontal => axis == 0,
+            Self::Vertical => axis == 1,
+            Self::Both => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ThumbDrag {
+    pointer: PointerId,
+    axis: usize,
+}
+
+#[derive(Default)]
+struct Scrollbars {
+    thumbs: [Conditional<SlicedImage>; 2],
+    /// How far the content scrolls when a thumb is moved by one pixel.
+    scale: [f32; 2],
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Scrollbars {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|this, _rect| {
+            for thumb in &mut this.thumbs {
+                thumb.graphic.set_slice_padding(Padding2d::uniform(
+                    THUMB_THICKNESS / 2.0,
+                ));
+                thumb.graphic.set_corners(CornerStyle::Rounded);
+            }
+        });
+        desc.graphic(|this| &mut this.thumbs[0]);
+        desc.graphic(|this| &mut this.thumbs[1]);
+    }
+}
+
+/// The content of a [`ScrollView`].
+///
+/// The content widget keeps the size it is given along the axes the view
+/// scrolls, and is stretched to fill the view along the others.  Anything
+/// outside the view is clipped with a [`Mask`], so scroll views can not be
+/// nested, or placed inside other masks.
+///
+/// The view scrolls with the mouse wheel, or by dragging its scrollbars.
+/// By default, the scrollbars are hidden when the view is not scrolling;
+/// see [`ScrollViewContent::set_auto_hide`].
+pub struct ScrollViewContent<T> {
+    content: Widget<T>,
+    bars: Widget<Scrollbars>,
+    mask: Mask<SlicedImage>,
+    axes: Watched<ScrollAxes>,
+    offset: Watched<[f32; 2]>,
+    viewport: SimpleRect,
+    auto_hide: Watched<bool>,
+    activity: Watched<Option<Instant>>,
+    drag: Watched<Option<ThumbDrag>>,
+    handle: UniqueHandle,
+}
+
+impl<T> ScrollViewContent<T> {
+    /// Get a reference to the scrolled content.
+    pub fn content(&self) -> &Widget<T> {
+        &self.content
+    }
+
+    /// Get a mutable reference to the scrolled content.
+    pub fn content_mut(&mut self) -> &mut Widget<T> {
+        &mut self.content
+    }
+
+    /// Get the axes the view scrolls along.
+    pub fn axes(&self) -> ScrollAxes {
+        *self.axes
+    }
+
+    /// Set the axes the view scrolls along.
+    pub fn set_axes(&mut self, axes: ScrollAxes) {
+        *self.axes = axes;
+    }
+
+    /// Get how far the view is scrolled: to the right of the left edge of
+    /// the content, and below the top edge of the content.
+    ///
+    /// This will bind a current watch function to the offset.
+    pub fn scroll_offset(&self) -> [f32; 2] {
+        *self.offset
+    }
+
+    /// Set how far the view is scrolled: to the right of the left edge of
+    /// the content, and below the top edge of the content.
+    ///
+    /// The offset is limited so the content stays within the view.
+    pub fn set_scroll_offset(&mut self, offset: [f32; 2]) {
+        if *self.offset != offset {
+            *self.offset = offset;
+        }
+    }
+
+    /// Scroll the least distance which brings `rect` into view.
+    ///
+    /// `rect` is where something currently is, e.g. the rect of a
+    /// descendant of the content.  If it is larger than the view, its top
+    /// left corner is brought into view.
+    pub fn scroll_to<R: Rect>(&mut self, rect: &R) {
+        let view = &self.viewport;
+        let mut offset = *self.offset;
+        if rect.left() < view.left() || rect.width() > view.width() {
+            offset[0] -= view.left() - rect.left();
+        } else if rect.right() > view.right() {
+            offset[0] += rect.right() - view.right();
+        }
+        if rect.top() > view.top() || rect.height() > view.height() {
+            offset[1] -= rect.top() - view.top();
+        } else if rect.bottom() < view.bottom() {
+            offset[1] += view.bottom() - rect.bottom();
+        }
+        self.set_scroll_offset(offset);
+    }
+
+    /// Check if the scrollbars hide when the view is not scrolling.
+    pub fn auto_hide(&self) -> bool {
+        *self.auto_hide
+    }
+
+    /// Set if the scrollbars hide when the view is not scrolling.
+    pub fn set_auto_hide(&mut self, auto_hide: bool) {
+        *self.auto_hide = auto_hide;
+    }
+
+    /// Scroll the view by a distance, and show the scrollbars.  Returns
+    /// false if the view could not scroll any further.
+    fn scroll_by(&mut self, dx: f32, dy: f32) -> bool {
+        let [x, y] = *self.offset;
+        let axes = *self.axes;
+        let offset = [
+            if axes.scrolls(0) { x + dx } else { x },
+            if axes.scrolls(1) { y + dy } else { y },
+        ];
+        let offset = self.clamp(offset);
+        if offset == [x, y] {
+            return false;
+        }
+        *self.offset = offset;
+        *self.activity = Some(crate::app::time_unwatched());
+        true
+    }
+
+    /// Limit an offset so the content stays within the view.
+    fn clamp(&self, offset: [f32; 2]) -> [f32; 2] {
+        let view = [self.viewport.width(), self.viewport.height()];
+        let content = [self.content.width(), self.content.height()];
+        std::array::from_fn(|axis| {
+            let max = (content[axis] - view[axis]).max(0.0);
+            offset[axis].min(max).max(0.0)
+        })
+    }
+
+    /// Check if the scrollbars are showing, and so may be dragged.
+    fn scrollbars_shown(&self) -> bool {
+        !*self.auto_hide || self.activity.is_some()
+    }
+
+    /// How visible the scrollbars are, from 0.0 to 1.0.
+    fn scrollbar_alpha(&mut self) -> f32 {
+        if !*self.auto_hide || self.drag.is_some() {
+            return 1.0;
+        }
+        let Some(activity) = *self.activity else {
+            return 0.0;
+        };
+        let elapsed = crate::app::time().saturating_duration_since(activity);
+        let fading = elapsed.saturating_sub(AUTO_HIDE_DELAY);
+        if fading >= FADE_DURATION {
+            *self.activity = None;
+            0.0
+        } else {
+            1.0 - fading.as_secs_f32() / FADE_DURATION.as_secs_f32()
+        }
+    }
+}
+
+impl<T: Default> Default for ScrollViewContent<T> {
+    fn default() -> Self {
+        Self {
+            content: Widget::default(),
+            bars: Widget::default(),
+            mask: Mask::default(),
+            axes: Watched::default(),
+            offset: Watched::new([0.0, 0.0]),
+            viewport: SimpleRect::default(),
+            auto_hide: Watched::new(true),
+            activity: Watched::new(None),
+            drag: Watched::new(None),
+            handle: UniqueHandle::default(),
+        }
+    }
+}
+
+impl<T> widget::Content<OpenGlRenderPlatform> for ScrollViewContent<T>
+where
+    T: widget::Content<OpenGlRenderPlatform>,
+{
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|this, rect| {
+            this.mask.graphic.set_fill(rect, &Padding2d::zero());
+            this.bars.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|this, rect| {
+            this.viewport = SimpleRect::from(rect);
+            let axes = *this.axes;
+            if !axes.scrolls(0) {
+                this.content.set_fill_width(rect, Padding::zero());
+            }
+            if !axes.scrolls(1) {
+                this.content.set_fill_height(rect, Padding::zero());
+            }
+            let offset = this.clamp(*this.offset);
+            if offset != *this.offset {
+                *this.offset = offset;
+            }
+            this.content.set_left(rect.left() - offset[0]);
+            this.content.set_top(rect.top() + offset[1]);
+        });
+        desc.watch(|this, rect| {
+            let axes = *this.axes;
+            let offset = *this.offset;
+            let view = [rect.width(), rect.height()];
+            let content = [this.content.width(), this.content.height()];
+            let shown: [bool; 2] = std::array::from_fn(|axis| {
+                axes.scrolls(axis) && content[axis] > view[axis]
+            });
+            let Scrollbars { thumbs, scale } = &mut *this.bars;
+            for axis in 0..2 {
+                thumbs[axis].enable = shown[axis];
+                if !shown[axis] {
+                    continue;
+                }
+                // leave room in the corner when both scrollbars are shown
+                let other = 1 - axis;
+                let corner = if shown[other] {
+                    THUMB_THICKNESS + THUMB_MARGIN
+                } else {
+                    0.0
+                };
+                let track = view[axis] - 2.0 * THUMB_MARGIN - corner;
+                let length =
+                    (track * view[axis] / content[axis]).max(MIN_THUMB_LENGTH);
+                let travel = (track - length).max(0.0);
+                let max_offset = content[axis] - view[axis];
+                scale[axis] = if travel > 0.0 {
+                    max_offset / travel
+                } else {
+                    0.0
+                };
+                let along = travel * offset[axis] / max_offset;
+                let thumb = &mut thumbs[axis].graphic;
+                if axis == 0 {
+                    let left = rect.left() + THUMB_MARGIN + along;
+                    thumb.set_horizontal_stretch(left, left + length);
+                    thumb.set_height(THUMB_THICKNESS);
+                    thumb.set_bottom(rect.bottom() + THUMB_MARGIN);
+                } else {
+                    let top = rect.top() - THUMB_MARGIN - along;
+                    thumb.set_vertical_stretch(top - length, top);
+                    thumb.set_width(THUMB_THICKNESS);
+                    thumb.set_right(rect.right() - THUMB_MARGIN);
+                }
+            }
+        });
+        desc.watch(|this, _rect| {
+            let alpha = this.scrollbar_alpha();
+            let color = Color {
+                a: alpha,
+                ..Color::DIM_GRAY
+            };
+            for thumb in &mut this.bars.thumbs {
+                thumb.graphic.set_color(color);
+            }
+        });
+        desc.watch(|this, _rect| {
+            let drag = &mut this.drag;
+            this.handle.handle_pointer_grab_stolen(|_pointer_id| {
+                **drag = None;
+            });
+        });
+        desc.graphic(|this| &mut this.mask);
+        desc.child(|this| &mut this.content);
+        desc.child(|this| &mut this.bars);
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn children_hittest(&self, rect: &WidgetRect, point: [f32; 2]) -> bool {
+        // the content is clipped by the mask, so it can't be reached from
+        // outside of the view
+        rect.contains(point)
+    }
+
+    fn pointer_event_before(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down
+                if self.drag.is_none() && self.scrollbars_shown() =>
+            {
+                let thumbs = &self.bars.thumbs;
+                let Some(axis) = (0..2).find(|&axis| {
+                    thumbs[axis].enable
+                        && thumbs[axis].graphic.contains(event.pos())
+                }) else {
+                    return false;
+                };
+                let grabbed = event.try_grab(self.handle.id());
+                if grabbed {
+                    *self.drag = Some(ThumbDrag {
+                        pointer: event.id(),
+                        axis,
+                    });
+                }
+                grabbed
+            }
+            PointerAction::Move(x, y) => {
+                let Some(drag) = *self.drag else {
+                    return false;
+                };
+                if drag.pointer != event.id() {
+                    return false;
+                }
+                let scale = self.bars.scale[drag.axis];
+                if drag.axis == 0 {
+                    self.scroll_by(x * scale, 0.0);
+                } else {
+                    self.scroll_by(0.0, -y * scale);
+                }
+                true
+            }
+            PointerAction::Up => {
+                let is_drag =
+                    self.drag.is_some_and(|drag| drag.pointer == event.id());
+                if is_drag {
+                    *self.drag = None;
+                    *self.activity = Some(crate::app::time_unwatched());
+                    event.try_ungrab(self.handle.id());
+                }
+                is_drag
+            }
+            _ => false,
+        }
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            // the view passes on wheel events it can't scroll any further,
+            // so an enclosing widget may scroll instead
+            PointerAction::Wheel(x, y) if self.hittest(rect, event.pos()) => {
+                self.scroll_by(-x, *y)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A widget which scrolls content larger than itself.
+///
+/// See [`ScrollViewContent`].
+pub type ScrollView<T> = Widget<ScrollViewContent<T>>;
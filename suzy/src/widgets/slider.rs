@@ -0,0 +1,565 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use drying_paint::Watched;
+
+use crate::{
+    graphics::Color,
+    keyboard::{Key, KeyAction, KeyEvent},
+    platform::RenderPlatform,
+    pointer::{PointerAction, PointerEvent, PointerId},
+    selectable::{Selectable, SelectionState},
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+/// The number of steps between the minimum and maximum of a slider which
+/// has no step set, when moved with the arrow keys.
+const DEFAULT_KEY_STEPS: f32 = 100.0;
+
+/// The number of key steps PageUp and PageDown move a slider by.
+const PAGE_STEPS: f32 = 10.0;
+
+/// The direction a slider's track runs in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The minimum is at the left, and the maximum at the right.
+    #[default]
+    Horizontal,
+    /// The minimum is at the bottom, and the maximum at the top.
+    Vertical,
+}
+
+/// Custom slider content implements this trait to position the visuals for
+/// the slider's handles.
+pub trait SliderContent: Selectable {
+    /// Notify the content that the handles moved, or the orientation
+    /// changed.
+    ///
+    /// `handles` is the position of each handle along the track, from 0.0
+    /// at the minimum to 1.0 at the maximum.
+    fn handles_changed(&mut self, orientation: Orientation, handles: &[f32]);
+
+    /// The distance from each end of the widget to the ends of the track,
+    /// e.g. half of the length of a handle, so a handle at the end of the
+    /// track stays within the widget.
+    fn track_inset(&self) -> f32 {
+        0.0
+    }
+}
+
+/// A Widget providing the behavior of a slider with `N` handles.
+///
+/// See [`Slider`] and [`RangeSlider`].
+///
+/// The handles are dragged with a pointer, or when the slider is focused,
+/// moved by a step with the arrow keys, by ten steps with PageUp and
+/// PageDown, and to the ends with Home and End.
+pub struct SliderBehavior<T, const N: usize> {
+    values: [Watched<f32>; N],
+    min: Watched<f32>,
+    max: Watched<f32>,
+    step: Watched<f32>,
+    orientation: Watched<Orientation>,
+    active_handle: usize,
+    pointer: Option<PointerId>,
+    dragging: Watched<bool>,
+    handle: UniqueHandle,
+    content: T,
+}
+
+impl<T, const N: usize> SliderBehavior<T, N> {
+    /// Get a reference to the content of this slider.
+    pub fn content(&self) -> &T {
+        &self.content
+    }
+
+    /// Get a mutable reference to the content of this slider.
+    pub fn content_mut(&mut self) -> &mut T {
+        &mut self.content
+    }
+
+    /// Get the current slider selection state.
+    pub fn state(&self) -> SelectionState {
+        if *self.dragging {
+            SelectionState::active()
+        } else if self.handle.is_focused() {
+            SelectionState::focus()
+        } else if self.handle.is_hovered() {
+            SelectionState::hover()
+        } else {
+            SelectionState::normal()
+        }
+    }
+
+    /// Get the minimum value of the slider.
+    pub fn min(&self) -> f32 {
+        *self.min
+    }
+
+    /// Get the maximum value of the slider.
+    pub fn max(&self) -> f32 {
+        *self.max
+    }
+
+    /// Set the minimum and maximum values of the slider.
+    ///
+    /// The slider's values are moved to be within the new bounds.
+    pub fn set_bounds(&mut self, min: f32, max: f32) {
+        *self.min = min;
+        *self.max = max.max(min);
+        self.resnap();
+    }
+
+    /// Get the interval the slider's values are a multiple of, starting
+    /// from the minimum.
+    pub fn step(&self) -> f32 {
+        *self.step
+    }
+
+    /// Set the interval the slider's values are a multiple of, starting
+    /// from the minimum.  A step of zero allows any value.
+    pub fn set_step(&mut self, step: f32) {
+        *self.step = step.max(0.0);
+        self.resnap();
+    }
+
+    /// Get the direction the slider's track runs in.
+    pub fn orientation(&self) -> Orientation {
+        *self.orientation
+    }
+
+    /// Set the direction the slider's track runs in.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        *self.orientation = orientation;
+    }
+
+    /// Set the tab index of this slider.
+    ///
+    /// See [`UniqueHandle::set_tab_index`] for details.
+    pub fn set_tab_index(&self, tab_index: i32) {
+        self.handle.set_tab_index(tab_index);
+    }
+
+    /// Move focus to this slider.
+    pub fn request_focus(&self) {
+        self.handle.request_focus();
+    }
+
+    /// Clamp a value to the slider's bounds, and round it to a step.
+    fn snap(&self, value: f32) -> f32 {
+        let (min, max, step) = (*self.min, *self.max, *self.step);
+        let value = if step > 0.0 {
+            min + ((value - min) / step).round() * step
+        } else {
+            value
+        };
+        value.min(max).max(min)
+    }
+
+    /// Set the value of one handle, keeping the handles in order.
+    fn set_handle(&mut self, index: usize, value: f32) {
+        let mut value = self.snap(value);
+        if let Some(prev) = index.checked_sub(1) {
+            value = value.max(*self.values[prev]);
+        }
+        if let Some(next) = self.values.get(index + 1) {
+            value = value.min(**next);
+        }
+        if value != *self.values[index] {
+            *self.values[index] = value;
+        }
+    }
+
+    fn resnap(&mut self) {
+        for index in 0..N {
+            let value = *self.values[index];
+            self.set_handle(index, value);
+        }
+    }
+
+    /// The amount the arrow keys move a handle by.
+    fn key_step(&self) -> f32 {
+        let step = *self.step;
+        if step > 0.0 {
+            step
+        } else {
+            (*self.max - *self.min) / DEFAULT_KEY_STEPS
+        }
+    }
+
+    fn fraction(&self, value: f32) -> f32 {
+        let range = *self.max - *self.min;
+        if range > 0.0 {
+            (value - *self.min) / range
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<T, const N: usize> SliderBehavior<T, N>
+where
+    T: SliderContent,
+{
+    /// Find the value at a point along the track.
+    fn value_at(&self, rect: &WidgetRect, point: [f32; 2]) -> f32 {
+        use crate::dims::Rect;
+
+        let inset = self.content.track_inset();
+        let (pos, start, length) = match *self.orientation {
+            Orientation::Horizontal => (point[0], rect.left(), rect.width()),
+            Orientation::Vertical => (point[1], rect.bottom(), rect.height()),
+        };
+        let length = length - 2.0 * inset;
+        let fraction = if length > 0.0 {
+            (pos - start - inset) / length
+        } else {
+            0.0
+        };
+        *self.min + fraction * (*self.max - *self.min)
+    }
+
+    /// Choose which handle a pointer pressed at `value` should drag.
+    fn nearest_handle(&self, value: f32) -> usize {
+        let mut nearest = 0;
+        for index in 1..N {
+            let current = *self.values[index];
+            let best = *self.values[nearest];
+            // when handles overlap, drag the one in the direction of the
+            // pointer
+            if (current - value).abs() < (best - value).abs()
+                || (current == best && value > current)
+            {
+                nearest = index;
+            }
+        }
+        nearest
+    }
+}
+
+impl<T> SliderBehavior<T, 1> {
+    /// Get the value of the slider.
+    ///
+    /// This will bind a current watch function to the value.
+    pub fn value(&self) -> f32 {
+        *self.values[0]
+    }
+
+    /// Set the value of the slider, rounded to a step.
+    pub fn set_value(&mut self, value: f32) {
+        self.set_handle(0, value);
+    }
+}
+
+impl<T> SliderBehavior<T, 2> {
+    /// Get the low and high values of the range.
+    ///
+    /// This will bind a current watch function to the values.
+    pub fn range(&self) -> [f32; 2] {
+        [*self.values[0], *self.values[1]]
+    }
+
+    /// Set the low and high values of the range, rounded to a step.
+    pub fn set_range(&mut self, low: f32, high: f32) {
+        let [low, high] = [self.snap(low.min(high)), self.snap(high)];
+        *self.values[1] = high;
+        *self.values[0] = low;
+    }
+}
+
+impl<T, P, const N: usize> widget::Content<P> for SliderBehavior<T, N>
+where
+    T: SliderContent + widget::Content<P>,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.bare_child(|slider| &mut slider.content);
+        desc.watch(|slider, _rect| {
+            let state = slider.state();
+            slider.content.selection_changed(state);
+        });
+        desc.watch(|slider, _rect| {
+            let handles: [f32; N] = std::array::from_fn(|index| {
+                slider.fraction(*slider.values[index])
+            });
+            let orientation = *slider.orientation;
+            slider.content.handles_changed(orientation, &handles);
+        });
+        desc.watch(|slider, _rect| {
+            let Self {
+                pointer,
+                dragging,
+                handle,
+                ..
+            } = slider;
+            handle.handle_pointer_grab_stolen(|_pointer_id| {
+                *pointer = None;
+                **dragging = false;
+            });
+        });
+    }
+
+    fn hittest(&self, rect: &WidgetRect, point: [f32; 2]) -> bool {
+        self.content.hittest(rect, point)
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down if self.pointer.is_none() => {
+                let grabbed = self.hittest(rect, event.pos())
+                    && event.try_grab(self.handle.id());
+                if grabbed {
+                    self.pointer = Some(event.id());
+                    *self.dragging = true;
+                    self.handle.request_focus();
+                    let value = self.value_at(rect, event.pos());
+                    self.active_handle = self.nearest_handle(value);
+                    self.set_handle(self.active_handle, value);
+                }
+                grabbed
+            }
+            PointerAction::Move(_, _) if self.pointer == Some(event.id()) => {
+                let value = self.value_at(rect, event.pos());
+                self.set_handle(self.active_handle, value);
+                true
+            }
+            PointerAction::Up if self.pointer == Some(event.id()) => {
+                self.pointer = None;
+                *self.dragging = false;
+                event.try_ungrab(self.handle.id())
+            }
+            _ => false,
+        }
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        if event.action != KeyAction::Down || !self.handle.is_focused() {
+            return false;
+        }
+        let index = self.active_handle;
+        let value = *self.values[index];
+        let step = self.key_step();
+        let target = match event.key {
+            Key::ArrowLeft | Key::ArrowDown => value - step,
+            Key::ArrowRight | Key::ArrowUp => value + step,
+            Key::PageDown => value - step * PAGE_STEPS,
+            Key::PageUp => value + step * PAGE_STEPS,
+            Key::Home => *self.min,
+            Key::End => *self.max,
+            _ => return false,
+        };
+        self.set_handle(index, target);
+        true
+    }
+}
+
+impl<T: Default, const N: usize> Default for SliderBehavior<T, N> {
+    fn default() -> Self {
+        let handle = UniqueHandle::default();
+        handle.set_focusable(true);
+        Self {
+            values: std::array::from_fn(|_| Watched::new(0.0)),
+            min: Watched::new(0.0),
+            max: Watched::new(1.0),
+            step: Watched::new(0.0),
+            orientation: Watched::default(),
+            active_handle: 0,
+            pointer: None,
+            dragging: Watched::new(false),
+            handle,
+            content: T::default(),
+        }
+    }
+}
+
+/// A slider, for choosing a value between a minimum and maximum.
+///
+/// The value ranges from 0.0 to 1.0 by default; use
+/// `Slider::set_bounds` to change this.
+#[cfg(feature = "platform-opengl")]
+pub type Slider<
+    T = DefaultSliderContent<crate::platforms::DefaultRenderPlatform>,
+> = Widget<SliderBehavior<T, 1>>;
+
+/// A slider, for choosing a value between a minimum and maximum.
+///
+/// The value ranges from 0.0 to 1.0 by default; use
+/// `Slider::set_bounds` to change this.
+#[cfg(not(feature = "platform-opengl"))]
+pub type Slider<T> = Widget<SliderBehavior<T, 1>>;
+
+/// A slider with two handles, for choosing a range between a minimum and
+/// maximum.
+///
+/// The arrow keys move the handle which was last dragged.
+#[cfg(feature = "platform-opengl")]
+pub type RangeSlider<
+    T = DefaultSliderContent<crate::platforms::DefaultRenderPlatform>,
+> = Widget<SliderBehavior<T, 2>>;
+
+/// A slider with two handles, for choosing a range between a minimum and
+/// maximum.
+///
+/// The arrow keys move the handle which was last dragged.
+#[cfg(not(feature = "platform-opengl"))]
+pub type RangeSlider<T> = Widget<SliderBehavior<T, 2>>;
+
+const TRACK_THICKNESS: f32 = 6.0;
+const HANDLE_SIZE: f32 = 20.0;
+
+/// The default look of a slider: a track, filled up to or between its handles.
+pub struct DefaultSliderContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    orientation: Watched<Orientation>,
+    handles: Watched<Vec<f32>>,
+    handle_color: Watched<Color>,
+    track: P::SlicedImage,
+    fill: P::SlicedImage,
+    handle_images: Vec<P::SlicedImage>,
+}
+
+impl<P> Default for DefaultSliderContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        Self {
+            orientation: Watched::default(),
+            handles: Watched::default(),
+            handle_color: Watched::new(Color::SLATE_BLUE),
+            track: P::SlicedImage::default(),
+            fill: P::SlicedImage::default(),
+            handle_images: Vec::new(),
+        }
+    }
+}
+
+impl<P> Selectable for DefaultSliderContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn selection_changed(&mut self, state: SelectionState) {
+        use crate::selectable::SelectionStateV2;
+
+        *self.handle_color = match state.v2() {
+            SelectionStateV2::Active | SelectionStateV2::Pressed => {
+                Color::LAVENDER
+            }
+            SelectionStateV2::Focus => Color::MEDIUM_SLATE_BLUE,
+            SelectionStateV2::Normal | SelectionStateV2::Hover => {
+                Color::SLATE_BLUE
+            }
+        };
+    }
+}
+
+impl<P> SliderContent for DefaultSliderContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn handles_changed(&mut self, orientation: Orientation, handles: &[f32]) {
+        *self.orientation = orientation;
+        if self.handles.as_slice() != handles {
+            *self.handles = handles.to_vec();
+        }
+    }
+
+    fn track_inset(&self) -> f32 {
+        HANDLE_SIZE / 2.0
+    }
+}
+
+impl<P> widget::Content<P> for DefaultSliderContent<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        use crate::{
+            dims::{Padding2d, Rect},
+            graphics::CornerStyle,
+            platform::graphics::SlicedImage,
+        };
+
+        desc.watch(|this, _rect| {
+            this.track.set_color(Color::GRAY);
+            this.fill.set_color(Color::MEDIUM_SLATE_BLUE);
+            for image in [&mut this.track, &mut this.fill] {
+                image.set_slice_padding(Padding2d::uniform(3.0));
+                image.set_corners(CornerStyle::Rounded);
+            }
+        });
+        desc.watch(|this, rect| {
+            let inset = HANDLE_SIZE / 2.0;
+            let handles = this.handles.as_slice();
+            // a single handle fills the track up to it, a pair fills the
+            // track between them
+            let (fill_start, fill_end) = match handles {
+                [] => (0.0, 0.0),
+                [only] => (0.0, *only),
+                [first, .., last] => (*first, *last),
+            };
+            this.handle_images.resize_with(handles.len(), || {
+                let mut image = P::SlicedImage::default();
+                image.set_slice_padding(Padding2d::uniform(HANDLE_SIZE / 2.0));
+                image.set_corners(CornerStyle::Rounded);
+                image
+            });
+            let color = *this.handle_color;
+            for image in &mut this.handle_images {
+                image.set_color(color);
+            }
+            match *this.orientation {
+                Orientation::Horizontal => {
+                    let left = rect.left() + inset;
+                    let length = rect.width() - 2.0 * inset;
+                    let at = |fraction: f32| left + fraction * length;
+                    for image in [&mut this.track, &mut this.fill] {
+                        image.set_height(TRACK_THICKNESS);
+                        image.set_center_y(rect.center_y());
+                    }
+                    this.track.set_horizontal_stretch(left, left + length);
+                    this.fill
+                        .set_horizontal_stretch(at(fill_start), at(fill_end));
+                    for (image, fraction) in
+                        this.handle_images.iter_mut().zip(handles)
+                    {
+                        image.set_width(HANDLE_SIZE);
+                        image.set_height(HANDLE_SIZE);
+                        image.set_center([at(*fraction), rect.center_y()]);
+                    }
+                }
+                Orientation::Vertical => {
+                    let bottom = rect.bottom() + inset;
+                    let length = rect.height() - 2.0 * inset;
+                    let at = |fraction: f32| bottom + fraction * length;
+                    for image in [&mut this.track, &mut this.fill] {
+                        image.set_width(TRACK_THICKNESS);
+                        image.set_center_x(rect.center_x());
+                    }
+                    this.track.set_vertical_stretch(bottom, bottom + length);
+                    this.fill
+                        .set_vertical_stretch(at(fill_start), at(fill_end));
+                    for (image, fraction) in
+                        this.handle_images.iter_mut().zip(handles)
+                    {
+                        image.set_width(HANDLE_SIZE);
+                        image.set_height(HANDLE_SIZE);
+                        image.set_center([rect.center_x(), at(*fraction)]);
+                    }
+                }
+            }
+        });
+        desc.graphic(|this| &mut this.track);
+        desc.graphic(|this| &mut this.fill);
+        desc.graphic(|this| &mut this.handle_images);
+    }
+}
@@ -0,0 +1,500 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::time::Duration;
+
+use crate::{
+    animation::Animation,
+    dims::{Padding, Padding2d, Rect, SimpleRect},
+    graphics::{Color, Conditional},
+    platform::graphics::{SlicedImage as _, Text as _, TextStyle},
+    platforms::opengl::{Mask, OpenGlRenderPlatform, SlicedImage, Text},
+    selectable::{Selectable, SelectionState, SelectionStateV2},
+    text,
+    watch::{Watched, WatchedMeta},
+    widget::{self, Ephemeral, Widget, WidgetRect},
+};
+
+use super::{
+    togglebutton::ToggleButtonContent, ScrollAxes, ScrollView,
+    ToggleButtonGroup, ToggleButtonValue,
+};
+
+/// The height of the row of tab headers.
+const HEADER_HEIGHT: f32 = 32.0;
+
+const DEFAULT_TAB_WIDTH: f32 = 120.0;
+
+/// The space between the edge of a tab header and its title.
+const PADDING: f32 = 8.0;
+
+const FONT_SIZE: f32 = 16.0;
+
+const DEFAULT_SLIDE_DURATION: Duration = Duration::from_millis(250);
+
+/// The header of a tab, which shows its title.
+#[derive(Default)]
+struct TabHeader {
+    title: Watched<String>,
+    index: usize,
+    state: Watched<SelectionState>,
+    image: SlicedImage,
+    text_graphic: Text,
+}
+
+impl Selectable for TabHeader {
+    fn selection_changed(&mut self, state: SelectionState) {
+        *self.state = state;
+    }
+}
+
+impl ToggleButtonValue<usize> for TabHeader {
+    fn get_value(&self, _rect: &WidgetRect) -> usize {
+        self.index
+    }
+}
+
+impl widget::Content<OpenGlRenderPlatform> for TabHeader {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|this, rect| {
+            this.image.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|this, rect| {
+            this.text_graphic.set_layout(text::Layout {
+                alignment: text::Alignment::Center,
+                line: text::Line::BetweenBaseAndCap,
+                flow: text::Flow::Out,
+                origin_x: rect.center_x(),
+                origin_y: rect.center_y(),
+                wrap_width: rect.width() - 2.0 * PADDING,
+                vertical_limit: text::VerticalLimit::Lines(1),
+                overflow_mode: text::OverflowMode::Ellipsis,
+            });
+        });
+        desc.watch(|this, _rect| {
+            let (background, text_color) = match this.state.v2() {
+                SelectionStateV2::Active | SelectionStateV2::Pressed => {
+                    (Color::LAVENDER, Color::BLACK)
+                }
+                SelectionStateV2::Focus => {
+                    (Color::MEDIUM_SLATE_BLUE, Color::WHITE)
+                }
+                SelectionStateV2::Normal | SelectionStateV2::Hover => {
+                    (Color::SLATE_BLUE, Color::LAVENDER)
+                }
+            };
+            this.image.set_color(background);
+            let style = TextStyle::with_size_and_color(FONT_SIZE, text_color);
+            this.text_graphic.clear();
+            this.text_graphic.push_span(style, &this.title);
+            this.text_graphic.finish();
+        });
+        desc.graphic(|this| &mut this.image);
+        desc.graphic(|this| &mut this.text_graphic);
+    }
+}
+
+type Tab = ToggleButtonContent<TabHeader, usize>;
+
+/// The row of tab headers, laid out from left to right.
+struct HeaderRow {
+    tabs: Vec<Ephemeral<Tab>>,
+    tabs_flag: WatchedMeta<'static>,
+    tab_width: Watched<f32>,
+}
+
+impl Default for HeaderRow {
+    fn default() -> Self {
+        Self {
+            tabs: Vec::new(),
+            tabs_flag: WatchedMeta::default(),
+            tab_width: Watched::new(DEFAULT_TAB_WIDTH),
+        }
+    }
+}
+
+impl widget::Content<OpenGlRenderPlatform> for HeaderRow {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|this, rect| {
+            this.tabs_flag.watched_auto();
+            let width = *this.tab_width;
+            for (index, tab) in this.tabs.iter_mut().enumerate() {
+                tab.access_mut(|tab| {
+                    tab.set_width(width);
+                    tab.set_fill_height(rect, Padding::zero());
+                    tab.set_left(rect.left() + index as f32 * width);
+                });
+            }
+        });
+        desc.iter_children(|this| {
+            this.tabs_flag.watched_auto();
+            this.tabs.iter_mut().into()
+        });
+    }
+}
+
+/// The pages of a tab view, showing the active page, and the previously
+/// active one while sliding between them.
+struct PageHost<T> {
+    pages: Vec<Ephemeral<T>>,
+    pages_flag: WatchedMeta<'static>,
+    active: Watched<Option<usize>>,
+    previous: Watched<Option<usize>>,
+    /// How far the active page is from its place, in page widths.
+    slide: Watched<f32>,
+    animation: Animation<f32>,
+    mask: Conditional<Mask<SlicedImage>>,
+}
+
+impl<T> Default for PageHost<T> {
+    fn default() -> Self {
+        let mut animation = Animation::new();
+        animation.set_duration(DEFAULT_SLIDE_DURATION);
+        Self {
+            pages: Vec::new(),
+            pages_flag: WatchedMeta::default(),
+            active: Watched::new(None),
+            previous: Watched::new(None),
+            slide: Watched::new(0.0),
+            animation,
+            mask: Conditional::default(),
+        }
+    }
+}
+
+impl<T> PageHost<T> {
+    /// Stop sliding, leaving only the active page shown.
+    fn stop_slide(&mut self) {
+        self.animation.cancel();
+        Watched::set_if_neq(&mut self.previous, None);
+        Watched::set_if_neq(&mut self.slide, 0.0);
+    }
+}
+
+impl<T> widget::Content<OpenGlRenderPlatform> for PageHost<T>
+where
+    T: widget::Content<OpenGlRenderPlatform>,
+{
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|this, rect| {
+            this.mask.graphic.graphic.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|this, _rect| {
+            // pages are only clipped while sliding, so a page may contain
+            // a scroll view while it is not moving
+            this.mask.enable = this.previous.is_some();
+        });
+        desc.watch(|this, _rect| {
+            this.animation.apply(&mut this.slide);
+        });
+        desc.watch(|this, _rect| {
+            let previous = &mut this.previous;
+            this.animation.on_complete(|| {
+                **previous = None;
+            });
+        });
+        desc.watch(|this, rect| {
+            this.pages_flag.watched_auto();
+            let active = *this.active;
+            let previous = *this.previous;
+            let slide = *this.slide;
+            let width = rect.width();
+            for (index, page) in this.pages.iter_mut().enumerate() {
+                let offset = if Some(index) == active {
+                    slide
+                } else if let (Some(prev), Some(active)) = (previous, active) {
+                    if prev != index {
+                        continue;
+                    }
+                    // the previous page leaves on the side opposite to
+                    // the one the active page comes from
+                    if prev < active {
+                        slide - 1.0
+                    } else {
+                        slide + 1.0
+                    }
+                } else {
+                    continue;
+                };
+                page.access_mut(|page| {
+                    page.set_fill(rect, &Padding2d::zero());
+                    page.set_left(rect.left() + offset * width);
+                });
+            }
+        });
+        desc.graphic(|this| &mut this.mask);
+        desc.iter_children(|this| {
+            this.pages_flag.watched_auto();
+            let active = *this.active;
+            let previous = *this.previous;
+            this.pages
+                .iter_mut()
+                .enumerate()
+                .filter(move |(index, _page)| {
+                    Some(*index) == active || Some(*index) == previous
+                })
+                .map(|(_index, page)| page)
+                .into()
+        });
+    }
+}
+
+/// The content of a [`TabView`].
+///
+/// A tab view shows a row of tab headers above one page of content at a
+/// time.  Clicking a header makes its page the active one.  The headers
+/// are [`ToggleButton`](super::ToggleButton)s sharing a
+/// [`ToggleButtonGroup`], and scroll left and right in a
+/// [`ScrollView`] when there are too many to fit.
+///
+/// Optionally, the view can slide between pages when the active page
+/// changes; see [`TabViewContent::set_animated`].  While sliding, the
+/// pages are clipped with a [`Mask`], so a page which contains a scroll
+/// view, or the tab view itself, can not be placed inside another mask
+/// when sliding is enabled.
+pub struct TabViewContent<T> {
+    headers: ScrollView<HeaderRow>,
+    pages: Widget<PageHost<T>>,
+    group: ToggleButtonGroup<usize>,
+    animated: bool,
+    reveal_pending: bool,
+    reveal_flag: WatchedMeta<'static>,
+}
+
+impl<T> TabViewContent<T> {
+    /// Get the number of tabs.
+    ///
+    /// This will bind a current watch function to the list of tabs.
+    pub fn len(&self) -> usize {
+        self.pages.pages_flag.watched_auto();
+        self.pages.pages.len()
+    }
+
+    /// Check if the view has no tabs.
+    ///
+    /// This will bind a current watch function to the list of tabs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add a tab after the existing ones.
+    ///
+    /// If the view had no tabs, the new tab becomes the active one.
+    pub fn add_tab(&mut self, title: &str, page: T) {
+        let index = self.pages.pages.len();
+        self.insert_tab(index, title, page);
+    }
+
+    /// Insert a tab at a position, shifting the tabs after it to the
+    /// right.
+    ///
+    /// If the view had no tabs, the new tab becomes the active one.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the number of tabs.
+    pub fn insert_tab(&mut self, index: usize, title: &str, page: T) {
+        let mut tab = Tab::default();
+        tab.set_allow_unselect(false);
+        tab.add_to_group(&self.group);
+        *tab.content_mut().title = title.to_string();
+        let row = self.headers.content_mut();
+        row.tabs.insert(index, Ephemeral::new(tab));
+        row.tabs_flag.trigger_auto();
+        let host = &mut *self.pages;
+        host.pages.insert(index, Ephemeral::new(page));
+        host.pages_flag.trigger_auto();
+        let shift = |current: Option<usize>| match current {
+            Some(current) if current >= index => Some(current + 1),
+            current => current,
+        };
+        let active = shift(*Watched::get_unwatched(&host.active));
+        let previous = shift(*Watched::get_unwatched(&host.previous));
+        Watched::set_if_neq(&mut host.active, active.or(Some(0)));
+        Watched::set_if_neq(&mut host.previous, previous);
+    }
+
+    /// Remove a tab.
+    ///
+    /// If the active tab is removed, the tab which takes its place becomes
+    /// the active one, or the last tab if there is none.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove_tab(&mut self, index: usize) {
+        let row = self.headers.content_mut();
+        row.tabs.remove(index);
+        row.tabs_flag.trigger_auto();
+        let host = &mut *self.pages;
+        host.pages.remove(index);
+        host.pages_flag.trigger_auto();
+        host.stop_slide();
+        let len = host.pages.len();
+        let active = match *Watched::get_unwatched(&host.active) {
+            Some(active) if active > index => Some(active - 1),
+            Some(_) if len > 0 => Some(index.min(len - 1)),
+            Some(_) | None => None,
+        };
+        Watched::set_if_neq(&mut host.active, active);
+    }
+
+    /// Set the title of a tab.
+    ///
+    /// Does nothing if `index` is out of bounds.
+    pub fn set_title(&mut self, index: usize, title: &str) {
+        if let Some(tab) = self.headers.content_mut().tabs.get_mut(index) {
+            tab.access_mut(|tab| {
+                *tab.content_mut().title = title.to_string();
+            });
+        }
+    }
+
+    /// Get a reference to the page of a tab.
+    pub fn page(&self, index: usize) -> Option<&Ephemeral<T>> {
+        self.pages.pages.get(index)
+    }
+
+    /// Get a mutable reference to the page of a tab.
+    pub fn page_mut(&mut self, index: usize) -> Option<&mut Ephemeral<T>> {
+        self.pages.pages.get_mut(index)
+    }
+
+    /// Get the index of the active tab, or None if the view has no tabs.
+    ///
+    /// This will bind a current watch function to the active index.
+    pub fn active_index(&self) -> Option<usize> {
+        *self.pages.active
+    }
+
+    /// Make a tab the active one, scrolling its header into view.
+    ///
+    /// Does nothing if `index` is out of bounds.
+    pub fn set_active_index(&mut self, index: usize) {
+        let host = &mut *self.pages;
+        let current = *Watched::get_unwatched(&host.active);
+        if index >= host.pages.len() || current == Some(index) {
+            return;
+        }
+        *host.active = Some(index);
+        match current {
+            Some(current) if self.animated => {
+                *host.previous = Some(current);
+                *host.slide = if index > current { 1.0 } else { -1.0 };
+                host.animation.animate_to(0.0);
+            }
+            _ => host.stop_slide(),
+        }
+        self.reveal_pending = true;
+        self.reveal_flag.trigger_auto();
+    }
+
+    /// Check if the view slides between pages when the active page
+    /// changes.
+    pub fn animated(&self) -> bool {
+        self.animated
+    }
+
+    /// Set if the view slides between pages when the active page changes.
+    ///
+    /// This is disabled by default.
+    pub fn set_animated(&mut self, animated: bool) {
+        self.animated = animated;
+    }
+
+    /// Set how long sliding between pages takes.
+    pub fn set_slide_duration(&mut self, duration: Duration) {
+        self.pages.animation.set_duration(duration);
+    }
+
+    /// Get the width of each tab header.
+    pub fn tab_width(&self) -> f32 {
+        *self.headers.content().tab_width
+    }
+
+    /// Set the width of each tab header.
+    pub fn set_tab_width(&mut self, width: f32) {
+        *self.headers.content_mut().tab_width = width;
+    }
+}
+
+impl<T> Default for TabViewContent<T> {
+    fn default() -> Self {
+        let mut headers = ScrollView::<HeaderRow>::default();
+        headers.set_axes(ScrollAxes::Horizontal);
+        Self {
+            headers,
+            pages: Widget::default(),
+            group: ToggleButtonGroup::new(),
+            animated: false,
+            reveal_pending: false,
+            reveal_flag: WatchedMeta::default(),
+        }
+    }
+}
+
+impl<T> widget::Content<OpenGlRenderPlatform> for TabViewContent<T>
+where
+    T: widget::Content<OpenGlRenderPlatform>,
+{
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|this, rect| {
+            this.headers.set_fill_width(rect, Padding::zero());
+            this.headers.set_height(HEADER_HEIGHT);
+            this.headers.set_top(rect.top());
+            this.pages.set_fill_width(rect, Padding::zero());
+            this.pages.set_vertical_stretch(
+                rect.bottom(),
+                rect.top() - HEADER_HEIGHT,
+            );
+        });
+        desc.watch(|this, _rect| {
+            let row = this.headers.content_mut();
+            row.tabs_flag.watched_auto();
+            let width = row.tabs.len() as f32 * *row.tab_width;
+            row.set_width(width);
+        });
+        desc.watch(|this, _rect| {
+            if let Some(index) = this.group.value() {
+                this.set_active_index(index);
+            }
+        });
+        desc.watch(|this, _rect| {
+            let active = *this.pages.active;
+            let row = this.headers.content_mut();
+            row.tabs_flag.watched_auto();
+            for (index, tab) in row.tabs.iter_mut().enumerate() {
+                tab.access_mut(|tab| {
+                    tab.content_mut().index = index;
+                    // only read to bind this watch to the header's state:
+                    // joining the group unselects the other headers, so
+                    // re-run when that happens to select them again
+                    let _state = tab.state();
+                    tab.set_selected(active == Some(index));
+                });
+            }
+        });
+        desc.watch(|this, _rect| {
+            this.reveal_flag.watched_auto();
+            if !std::mem::take(&mut this.reveal_pending) {
+                return;
+            }
+            let Some(index) = *Watched::get_unwatched(&this.pages.active)
+            else {
+                return;
+            };
+            let row = this.headers.content();
+            let width = *row.tab_width;
+            let mut header = SimpleRect::with_size(width, row.height());
+            header.set_left(row.left() + index as f32 * width);
+            header.set_top(row.top());
+            this.headers.scroll_to(&header);
+        });
+        desc.child(|this| &mut this.headers);
+        desc.child(|this| &mut this.pages);
+    }
+}
+
+/// A widget which shows one page of content at a time, chosen with a row
+/// of tab headers.
+///
+/// See [`TabViewContent`].
+pub type TabView<T> = Widget<TabViewContent<T>>;
@@ -0,0 +1,987 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::ops::Range;
+
+use drying_paint::{Watched, WatchedQueue};
+
+use crate::{
+    dims::{Padding2d, Rect, SimpleRect},
+    graphics::{Color, Conditional, CornerStyle},
+    keyboard::{Key, KeyAction, KeyChord, KeyEvent, TextEvent},
+    platform::{
+        graphics::{SlicedImage, Text, TextStyle},
+        RenderPlatform,
+    },
+    pointer::{PointerAction, PointerEvent, PointerId},
+    text,
+    watch::WatchedMeta,
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+use super::editor::{Composition, EditKind, History, Movement, TextEditor};
+
+/// The space between the edge of a text area and its text.
+const PADDING: f32 = 6.0;
+
+const CARET_WIDTH: f32 = 1.5;
+
+/// The thickness of the line under text being composed by an input method.
+const UNDERLINE_WIDTH: f32 = 1.0;
+
+const SELECT_ALL: KeyChord = KeyChord::new(Key::Character('a')).ctrl();
+const COPY: KeyChord = KeyChord::new(Key::Character('c')).ctrl();
+const CUT: KeyChord = KeyChord::new(Key::Character('x')).ctrl();
+const PASTE: KeyChord = KeyChord::new(Key::Character('v')).ctrl();
+const UNDO: KeyChord = KeyChord::new(Key::Character('z')).ctrl();
+const REDO: KeyChord = KeyChord::new(Key::Character('y')).ctrl();
+const REDO_ALT: KeyChord = KeyChord::new(Key::Character('z')).ctrl().shift();
+
+/// A widget which allows multiple lines of text to be entered.
+#[cfg(feature = "platform-opengl")]
+pub type TextArea<P = crate::platforms::DefaultRenderPlatform> =
+    Widget<TextAreaContent<P>>;
+
+/// A widget which allows multiple lines of text to be entered.
+#[cfg(not(feature = "platform-opengl"))]
+pub type TextArea<P> = Widget<TextAreaContent<P>>;
+
+/// A paragraph of the text, which is laid out separately from the others.
+#[derive(Clone, Copy, Debug)]
+struct Block {
+    /// The byte offset of the paragraph in the text.
+    start: usize,
+    /// The length of the paragraph in bytes, not including the line break
+    /// which ends it.
+    len: usize,
+    /// The distance from the top of the text to the top of the paragraph.
+    top: f32,
+    /// The number of lines the paragraph wraps to.
+    lines: usize,
+    /// Changes each time the paragraph is measured, so that stale layouts
+    /// of it can be recognized.
+    version: u64,
+}
+
+impl Block {
+    fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
+/// Everything which determines how a paragraph is laid out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct BlockKey {
+    version: u64,
+    origin: [f32; 2],
+    wrap_width: f32,
+    color: Color,
+}
+
+/// The content for a widget which allows multiple lines of text to be
+/// entered.
+///
+/// In addition to the editing keys supported by
+/// [`TextFieldContent`](super::TextFieldContent), the caret can be moved
+/// between lines with the up and down arrows and PageUp and PageDown, and
+/// Home and End move to the ends of a line, or of the text while holding
+/// Ctrl.  Enter and Tab insert a line break and a tab; Shift+Tab moves focus
+/// as usual.  Ctrl+Z undoes the last change, and Ctrl+Y or Ctrl+Shift+Z
+/// redoes it.  Text being composed with an input method is shown in place
+/// of the selection, underlined, until it is committed.
+///
+/// The text wraps to the width of the widget, and scrolls vertically to
+/// keep the caret in view.  Each paragraph is laid out separately, and only
+/// the paragraphs in view are drawn, so editing long documents stays
+/// fast.  The text is not clipped to the widget; place it inside a mask if
+/// the platform provides one.
+pub struct TextAreaContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// The size of the text, in pixels.
+    pub font_size: Watched<f32>,
+    /// The color of the text.
+    pub text_color: Watched<Color>,
+    /// The color drawn behind selected text.
+    pub selection_color: Watched<Color>,
+    /// The color of the text area's background.
+    pub background_color: Watched<Color>,
+    editor: TextEditor,
+    composition: Option<Composition>,
+    composed: Option<String>,
+    input_rect: Watched<Option<SimpleRect>>,
+    history: History,
+    edit_flag: WatchedMeta<'static>,
+    caret_flag: WatchedMeta<'static>,
+    reveal_caret: bool,
+    goal_x: Option<f32>,
+    scroll: Watched<f32>,
+    on_changed: WatchedQueue<'static, ()>,
+    pointer: Option<PointerId>,
+    text_input_active: bool,
+    handle: UniqueHandle,
+    measured_text: String,
+    measured_style: Option<[f32; 2]>,
+    blocks: Vec<Block>,
+    line_height: f32,
+    next_version: u64,
+    view: Range<usize>,
+    keys: Vec<Option<BlockKey>>,
+    scratch_key: Option<(usize, BlockKey)>,
+    background: P::SlicedImage,
+    selection: Vec<P::SlicedImage>,
+    paragraphs: Vec<P::Text>,
+    scratch: P::Text,
+    caret: Conditional<P::SlicedImage>,
+}
+
+impl<P> Default for TextAreaContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        let handle = UniqueHandle::default();
+        handle.set_focusable(true);
+        Self {
+            font_size: Watched::new(16.0),
+            text_color: Watched::new(Color::BLACK),
+            selection_color: Watched::new(Color::LIGHT_SKY_BLUE),
+            background_color: Watched::new(Color::WHITE),
+            editor: TextEditor::default(),
+            composition: None,
+            composed: None,
+            input_rect: Watched::new(None),
+            history: History::default(),
+            edit_flag: WatchedMeta::default(),
+            caret_flag: WatchedMeta::default(),
+            reveal_caret: false,
+            goal_x: None,
+            scroll: Watched::new(0.0),
+            on_changed: WatchedQueue::default(),
+            pointer: None,
+            text_input_active: false,
+            handle,
+            measured_text: String::new(),
+            measured_style: None,
+            blocks: Vec::new(),
+            line_height: 0.0,
+            next_version: 0,
+            view: 0..0,
+            keys: Vec::new(),
+            scratch_key: None,
+            background: P::SlicedImage::default(),
+            selection: Vec::new(),
+            paragraphs: Vec::new(),
+            scratch: P::Text::default(),
+            caret: Conditional::default(),
+        }
+    }
+}
+
+impl<P> TextAreaContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// Get the text in the text area.
+    pub fn text(&self) -> &str {
+        self.editor.text()
+    }
+
+    /// Replace the text in the text area, placing the caret at the end.
+    ///
+    /// This clears the undo history, and does not produce a changed event.
+    pub fn set_text(&mut self, text: &str) {
+        self.editor.set_text(text);
+        self.history.clear();
+        self.edit_flag.trigger_auto();
+        self.moved();
+    }
+
+    /// Get the byte range of the selected text.
+    pub fn selection(&self) -> Range<usize> {
+        self.editor.selection()
+    }
+
+    /// Select a byte range of the text, placing the caret at its end.
+    pub fn set_selection(&mut self, range: Range<usize>) {
+        self.editor.select(range.start, range.end);
+        self.moved();
+    }
+
+    /// Undo the last change to the text.  Returns true if there was a
+    /// change to undo.
+    pub fn undo(&mut self) -> bool {
+        let undone = self.history.undo(&mut self.editor);
+        if undone {
+            self.changed();
+        }
+        undone
+    }
+
+    /// Redo the last change to the text which was undone.  Returns true if
+    /// there was a change to redo.
+    pub fn redo(&mut self) -> bool {
+        let redone = self.history.redo(&mut self.editor);
+        if redone {
+            self.changed();
+        }
+        redone
+    }
+
+    /// Set the tab index of this text area.
+    ///
+    /// See [`UniqueHandle::set_tab_index`] for details.
+    pub fn set_tab_index(&self, tab_index: i32) {
+        self.handle.set_tab_index(tab_index);
+    }
+
+    /// Move focus to this text area.
+    pub fn request_focus(&self) {
+        self.handle.request_focus();
+    }
+
+    /// Handle the text being changed by the user.
+    ///
+    /// This should be called in a watch closure, which will re-run with the
+    /// new text each time it changes.
+    pub fn on_changed<F: FnOnce(&str)>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.on_changed.handle_item(arg, |()| f(self.editor.text()));
+        });
+    }
+
+    /// Note that the caret or selection moved.
+    fn moved(&mut self) {
+        self.caret_flag.trigger_auto();
+        self.reveal_caret = true;
+        self.goal_x = None;
+        self.history.break_group();
+    }
+
+    /// Note that the text changed.
+    fn changed(&mut self) {
+        self.edit_flag.trigger_auto();
+        self.caret_flag.trigger_auto();
+        self.reveal_caret = true;
+        self.goal_x = None;
+        self.on_changed.push_external(());
+    }
+
+    /// Change the text, recording the change in the undo history.
+    fn edit<F>(&mut self, kind: EditKind, f: F)
+    where
+        F: FnOnce(&mut TextEditor) -> bool,
+    {
+        if self.history.edit(&mut self.editor, kind, f) {
+            self.changed();
+        }
+    }
+
+    /// Insert text typed or pasted by the user, normalizing line breaks and
+    /// dropping other control characters.
+    fn insert(&mut self, text: &str, kind: EditKind) {
+        let text: String = text
+            .replace("\r\n", "\n")
+            .replace('\r', "\n")
+            .chars()
+            .filter(|&ch| ch == '\n' || ch == '\t' || !ch.is_control())
+            .collect();
+        self.edit(kind, |editor| editor.insert(&text, None));
+    }
+
+    fn copy(&self) {
+        if self.editor.has_selection() {
+            if let Some(clipboard) = crate::app::clipboard() {
+                // the text area can't do anything useful if this fails
+                let _result = clipboard.set_text(self.editor.selected_text());
+            }
+        }
+    }
+
+    fn paste(&mut self) {
+        let text = crate::app::clipboard()
+            .and_then(|clipboard| clipboard.text())
+            .unwrap_or_default();
+        self.insert(&text, EditKind::Other);
+    }
+
+    /// Set the text being composed with an input method.
+    fn set_composition(&mut self, composition: Option<Composition>) {
+        if self.composition != composition {
+            self.composition = composition;
+            self.edit_flag.trigger_auto();
+            self.caret_flag.trigger_auto();
+            self.reveal_caret = true;
+        }
+    }
+
+    /// Update the displayed text, with any composition in place of the
+    /// selection.
+    fn update_composed(&mut self) {
+        self.composed = self.composition.as_ref().map(|composition| {
+            let text = self.editor.text();
+            let selection = self.editor.selection();
+            [
+                &text[..selection.start],
+                &composition.text,
+                &text[selection.end..],
+            ]
+            .concat()
+        });
+    }
+
+    /// Get the displayed text; see [`Self::update_composed`].
+    fn display_text<'a>(
+        composed: &'a Option<String>,
+        editor: &'a TextEditor,
+    ) -> &'a str {
+        composed.as_deref().unwrap_or(editor.text())
+    }
+
+    /// The byte range of the displayed text which is being composed.
+    fn composing(&self) -> Option<Range<usize>> {
+        let start = self.editor.selection().start;
+        let composition = self.composition.as_ref()?;
+        Some(start..start + composition.text.len())
+    }
+
+    /// The byte index of the caret in the displayed text.
+    fn display_caret(&self) -> usize {
+        match &self.composition {
+            Some(composition) => {
+                self.editor.selection().start + composition.cursor.end
+            }
+            None => self.editor.caret(),
+        }
+    }
+
+    /// Convert a byte index in the displayed text to one in the text.
+    /// Indices within the composition are placed at its start.
+    fn text_index(&self, index: usize) -> usize {
+        let (Some(composing), Some(composition)) =
+            (self.composing(), &self.composition)
+        else {
+            return index;
+        };
+        if index <= composing.start {
+            index
+        } else if index < composing.end {
+            composing.start
+        } else {
+            let selection = self.editor.selection();
+            index - composition.text.len() + selection.len()
+        }
+    }
+
+    /// The top of the area the text is drawn in.
+    fn view_top(rect: &WidgetRect) -> f32 {
+        rect.top() - PADDING
+    }
+
+    fn view_height(rect: &WidgetRect) -> f32 {
+        (rect.height() - 2.0 * PADDING).max(0.0)
+    }
+
+    fn wrap_width(rect: &WidgetRect) -> f32 {
+        (rect.width() - 2.0 * PADDING).max(0.0)
+    }
+
+    fn content_height(&self) -> f32 {
+        self.blocks.last().map_or(0.0, |block| {
+            block.top + self.line_height * block.lines as f32
+        })
+    }
+
+    fn max_scroll(&self, rect: &WidgetRect) -> f32 {
+        (self.content_height() - Self::view_height(rect)).max(0.0)
+    }
+
+    /// Find the paragraph containing a byte index of the text.
+    fn block_of(&self, index: usize) -> usize {
+        self.blocks
+            .partition_point(|block| block.start <= index)
+            .saturating_sub(1)
+    }
+
+    /// Find the paragraph at a distance from the top of the text.
+    fn block_at(&self, offset: f32) -> usize {
+        self.blocks
+            .partition_point(|block| block.top <= offset)
+            .saturating_sub(1)
+    }
+
+    fn block_key(&self, rect: &WidgetRect, index: usize) -> BlockKey {
+        let block = &self.blocks[index];
+        BlockKey {
+            version: block.version,
+            origin: [
+                rect.left() + PADDING,
+                Self::view_top(rect) + *self.scroll - block.top,
+            ],
+            wrap_width: Self::wrap_width(rect),
+            color: *self.text_color,
+        }
+    }
+
+    fn layout_block(
+        graphic: &mut P::Text,
+        key: BlockKey,
+        font_size: f32,
+        paragraph: &str,
+    ) {
+        graphic.set_layout(text::Layout {
+            alignment: text::Alignment::Left,
+            line: text::Line::Ascent,
+            flow: text::Flow::Down,
+            origin_x: key.origin[0],
+            origin_y: key.origin[1],
+            wrap_width: key.wrap_width,
+            vertical_limit: text::VerticalLimit::None,
+            overflow_mode: text::OverflowMode::Truncate,
+        });
+        graphic.clear();
+        let style = P::TextStyle::with_size_and_color(font_size, key.color);
+        graphic.push_span(style, paragraph);
+        graphic.finish();
+    }
+
+    /// Measure the paragraphs of the text which changed since they were
+    /// last measured, or all of them if the width or font size changed.
+    fn update_blocks(&mut self, rect: &WidgetRect) {
+        self.update_composed();
+        let font_size = *self.font_size;
+        let wrap_width = Self::wrap_width(rect);
+        if self.measured_style != Some([font_size, wrap_width]) {
+            self.measured_style = Some([font_size, wrap_width]);
+            self.measure_line_height(font_size);
+            self.blocks.clear();
+        } else if self.measured_text
+            == Self::display_text(&self.composed, &self.editor)
+        {
+            return;
+        }
+        self.scratch_key = None;
+        let old = &self.measured_text;
+        let new = Self::display_text(&self.composed, &self.editor);
+        let (first, last, start, old_end) = if self.blocks.is_empty() {
+            (0, 0, 0, old.len())
+        } else {
+            let prefix = old
+                .bytes()
+                .zip(new.bytes())
+                .take_while(|(a, b)| a == b)
+                .count();
+            let suffix = old
+                .bytes()
+                .rev()
+                .zip(new.bytes().rev())
+                .take_while(|(a, b)| a == b)
+                .count()
+                .min(old.len().min(new.len()) - prefix);
+            let first = self.block_of(prefix);
+            let last = self.block_of(old.len() - suffix) + 1;
+            (
+                first,
+                last,
+                self.blocks[first].start,
+                self.blocks[last - 1].end(),
+            )
+        };
+        // the changed paragraphs end at a line break, or the end of the
+        // text, which is unchanged
+        let new_end = new.len() - (old.len() - old_end);
+        let key = BlockKey {
+            version: 0,
+            origin: [0.0, 0.0],
+            wrap_width,
+            color: Color::BLACK,
+        };
+        let mut measured = Vec::new();
+        let mut offset = start;
+        for paragraph in new[start..new_end].split('\n') {
+            Self::layout_block(&mut self.scratch, key, font_size, paragraph);
+            let lines = self
+                .scratch
+                .caret(paragraph.len())
+                .map_or(1, |caret| caret.line + 1);
+            measured.push(Block {
+                start: offset,
+                len: paragraph.len(),
+                top: 0.0,
+                lines,
+                version: self.next_version,
+            });
+            self.next_version += 1;
+            offset += paragraph.len() + 1;
+        }
+        let count = measured.len();
+        self.blocks.splice(first..last, measured);
+        for block in &mut self.blocks[first + count..] {
+            block.start = new.len() - (old.len() - block.start);
+        }
+        let mut top = self.blocks[..first]
+            .last()
+            .map_or(0.0, |block| block.top + self.height(block));
+        for index in first..self.blocks.len() {
+            self.blocks[index].top = top;
+            top += self.height(&self.blocks[index]);
+        }
+        new.clone_into(&mut self.measured_text);
+    }
+
+    fn height(&self, block: &Block) -> f32 {
+        self.line_height * block.lines as f32
+    }
+
+    fn measure_line_height(&mut self, font_size: f32) {
+        let key = BlockKey {
+            version: 0,
+            origin: [0.0, 0.0],
+            wrap_width: f32::INFINITY,
+            color: Color::BLACK,
+        };
+        Self::layout_block(&mut self.scratch, key, font_size, "\n");
+        self.line_height = match (self.scratch.caret(0), self.scratch.caret(1))
+        {
+            (Some(first), Some(second)) => first.bottom - second.bottom,
+            _ => font_size,
+        };
+    }
+
+    /// Get a paragraph laid out where it currently appears.
+    fn block_text(&mut self, rect: &WidgetRect, index: usize) -> &P::Text {
+        let key = self.block_key(rect, index);
+        let slot = index
+            .checked_sub(self.view.start)
+            .filter(|&slot| self.keys.get(slot) == Some(&Some(key)));
+        if let Some(slot) = slot {
+            return &self.paragraphs[slot];
+        }
+        if self.scratch_key != Some((index, key)) {
+            let block = self.blocks[index];
+            let text = Self::display_text(&self.composed, &self.editor);
+            let paragraph = &text[block.start..block.end()];
+            Self::layout_block(
+                &mut self.scratch,
+                key,
+                *self.font_size,
+                paragraph,
+            );
+            self.scratch_key = Some((index, key));
+        }
+        &self.scratch
+    }
+
+    /// Get the position of the caret before a byte index of the displayed
+    /// text.
+    fn caret_pos(
+        &mut self,
+        rect: &WidgetRect,
+        index: usize,
+    ) -> Option<text::Caret> {
+        let block = self.block_of(index);
+        let start = self.blocks.get(block)?.start;
+        self.block_text(rect, block).caret(index - start)
+    }
+
+    /// Find the index in the text nearest to a point.
+    fn index_at(&mut self, rect: &WidgetRect, point: [f32; 2]) -> usize {
+        let offset = Self::view_top(rect) + *self.scroll - point[1];
+        let block = self.block_at(offset);
+        let Some(start) = self.blocks.get(block).map(|block| block.start)
+        else {
+            return 0;
+        };
+        let found = self.block_text(rect, block).caret_at(point);
+        self.text_index(start + found.unwrap_or(0))
+    }
+
+    /// Move the caret up or down by a distance, keeping its column.
+    fn move_vertically(
+        &mut self,
+        rect: &WidgetRect,
+        distance: f32,
+        extend: bool,
+    ) {
+        self.update_blocks(rect);
+        let Some(caret) = self.caret_pos(rect, self.display_caret()) else {
+            return;
+        };
+        let x = *self.goal_x.get_or_insert(caret.x);
+        let y = (caret.top + caret.bottom) / 2.0 + distance;
+        let offset = Self::view_top(rect) + *self.scroll - y;
+        let target = if offset < 0.0 {
+            0
+        } else if offset >= self.content_height() {
+            self.editor.text().len()
+        } else {
+            self.index_at(rect, [x, y])
+        };
+        let goal_x = self.goal_x;
+        self.editor.move_to(target, extend);
+        self.moved();
+        self.goal_x = goal_x;
+    }
+
+    /// Find the start or end of the line the caret is on.
+    fn line_edge(&mut self, rect: &WidgetRect, end: bool) -> usize {
+        self.update_blocks(rect);
+        let caret = self.editor.caret();
+        let Some(pos) = self.caret_pos(rect, self.display_caret()) else {
+            return caret;
+        };
+        let x = if end {
+            rect.right() + rect.width()
+        } else {
+            rect.left() - rect.width()
+        };
+        self.index_at(rect, [x, (pos.top + pos.bottom) / 2.0])
+    }
+
+    fn handle_key(&mut self, rect: &WidgetRect, event: &KeyEvent) -> bool {
+        if SELECT_ALL.matches(event) {
+            self.editor.select_all();
+            self.moved();
+            return true;
+        } else if COPY.matches(event) {
+            self.copy();
+            return true;
+        } else if CUT.matches(event) {
+            self.copy();
+            self.edit(EditKind::Other, |editor| editor.delete(Movement::Left));
+            return true;
+        } else if PASTE.matches(event) {
+            self.paste();
+            return true;
+        } else if UNDO.matches(event) {
+            self.undo();
+            return true;
+        } else if REDO.matches(event) || REDO_ALT.matches(event) {
+            self.redo();
+            return true;
+        }
+        let mods = event.modifiers;
+        let movement = match event.key {
+            Key::ArrowLeft if mods.ctrl => Movement::WordLeft,
+            Key::ArrowLeft => Movement::Left,
+            Key::ArrowRight if mods.ctrl => Movement::WordRight,
+            Key::ArrowRight => Movement::Right,
+            Key::Home if mods.ctrl => Movement::Home,
+            Key::End if mods.ctrl => Movement::End,
+            Key::Home | Key::End => {
+                let target = self.line_edge(rect, event.key == Key::End);
+                self.editor.move_to(target, mods.shift);
+                self.moved();
+                return true;
+            }
+            Key::ArrowUp | Key::ArrowDown | Key::PageUp | Key::PageDown => {
+                let distance = match event.key {
+                    Key::ArrowUp => self.line_height,
+                    Key::ArrowDown => -self.line_height,
+                    Key::PageUp => Self::view_height(rect),
+                    _ => -Self::view_height(rect),
+                };
+                self.move_vertically(rect, distance, mods.shift);
+                return true;
+            }
+            Key::Backspace | Key::Delete => {
+                let movement = match (event.key, mods.ctrl) {
+                    (Key::Backspace, true) => Movement::WordLeft,
+                    (Key::Backspace, false) => Movement::Left,
+                    (_, true) => Movement::WordRight,
+                    (_, false) => Movement::Right,
+                };
+                self.edit(EditKind::Deletion, |editor| {
+                    editor.delete(movement)
+                });
+                return true;
+            }
+            Key::Enter if !mods.ctrl && !mods.alt => {
+                // each line typed is undone separately
+                self.history.break_group();
+                self.insert("\n", EditKind::Typing);
+                return true;
+            }
+            Key::Tab if mods.is_empty() => {
+                self.insert("\t", EditKind::Typing);
+                return true;
+            }
+            _ => return false,
+        };
+        self.editor.apply(movement, mods.shift);
+        self.moved();
+        true
+    }
+
+    /// Lay out the paragraphs which are in view.
+    fn render(&mut self, rect: &WidgetRect) {
+        let scroll = *self.scroll;
+        let bottom = scroll + Self::view_height(rect);
+        let line_height = self.line_height;
+        let first = self.blocks.partition_point(|block| {
+            block.top + line_height * block.lines as f32 <= scroll
+        });
+        let last = self.blocks.partition_point(|block| block.top < bottom);
+        let last = last.max(first);
+        self.view = first..last;
+        let count = last - first;
+        self.paragraphs.truncate(count);
+        self.paragraphs.resize_with(count, P::Text::default);
+        self.keys.resize(count, None);
+        let font_size = *self.font_size;
+        for index in first..last {
+            let key = self.block_key(rect, index);
+            let slot = index - first;
+            if self.keys[slot] != Some(key) {
+                let block = self.blocks[index];
+                let text = Self::display_text(&self.composed, &self.editor);
+                let paragraph = &text[block.start..block.end()];
+                let graphic = &mut self.paragraphs[slot];
+                Self::layout_block(graphic, key, font_size, paragraph);
+                self.keys[slot] = Some(key);
+            }
+        }
+    }
+
+    /// Position the highlights for the selection and the caret, or the
+    /// underline of the composition in place of the selection.
+    fn position_highlights(&mut self, rect: &WidgetRect) {
+        let composing = self.composing();
+        let underline = composing.is_some();
+        let selection = composing.unwrap_or_else(|| self.editor.selection());
+        let text_left = rect.left() + PADDING;
+        let text_right = rect.right() - PADDING;
+        let line_height = self.line_height;
+        let mut highlights = Vec::new();
+        for index in self.view.clone() {
+            let block = self.blocks[index];
+            if selection.is_empty()
+                || selection.start > block.end()
+                || selection.end < block.start
+            {
+                continue;
+            }
+            let selects_break = selection.end > block.end();
+            let lo = selection.start.max(block.start) - block.start;
+            let hi = selection.end.min(block.end()) - block.start;
+            let graphic = &self.paragraphs[index - self.view.start];
+            let (Some(lo), Some(hi)) = (graphic.caret(lo), graphic.caret(hi))
+            else {
+                continue;
+            };
+            for line in lo.line..=hi.line {
+                let shift = line_height * (line - lo.line) as f32;
+                let left = if line == lo.line { lo.x } else { text_left };
+                let right = if line == hi.line && !selects_break {
+                    hi.x
+                } else {
+                    text_right.max(hi.x)
+                };
+                highlights.push([
+                    left,
+                    right,
+                    lo.bottom - shift,
+                    lo.top - shift,
+                ]);
+            }
+        }
+        let mut input_rect = None;
+        if let (true, Some(&[left, right, bottom, top])) =
+            (underline, highlights.first())
+        {
+            let mut composition_rect = SimpleRect::default();
+            composition_rect.set_horizontal_stretch(left, right);
+            composition_rect.set_vertical_stretch(bottom, top);
+            input_rect = Some(composition_rect);
+        }
+        self.selection.truncate(highlights.len());
+        self.selection
+            .resize_with(highlights.len(), P::SlicedImage::default);
+        for (graphic, [left, right, bottom, top]) in
+            self.selection.iter_mut().zip(highlights)
+        {
+            graphic.set_horizontal_stretch(left, right);
+            if underline {
+                graphic.set_color(*self.text_color);
+                graphic.set_vertical_stretch(bottom, bottom + UNDERLINE_WIDTH);
+            } else {
+                graphic.set_color(*self.selection_color);
+                graphic.set_vertical_stretch(bottom, top);
+            }
+        }
+        let display_caret = self.display_caret();
+        let caret_block = self.block_of(display_caret);
+        let in_view = self.view.contains(&caret_block);
+        self.caret.enable = false;
+        let show_caret = underline || selection.is_empty();
+        if in_view && show_caret && self.handle.is_focused() {
+            if let Some(pos) = self.caret_pos(rect, display_caret) {
+                self.caret.enable =
+                    pos.top > rect.bottom() && pos.bottom < rect.top();
+                self.caret.graphic.set_horizontal_stretch(
+                    pos.x - CARET_WIDTH / 2.0,
+                    pos.x + CARET_WIDTH / 2.0,
+                );
+                self.caret.graphic.set_vertical_stretch(pos.bottom, pos.top);
+                if input_rect.is_none() {
+                    let mut caret_rect = SimpleRect::default();
+                    caret_rect.set_horizontal_stretch(pos.x, pos.x);
+                    caret_rect.set_vertical_stretch(pos.bottom, pos.top);
+                    input_rect = Some(caret_rect);
+                }
+            }
+        }
+        Watched::set_if_neq(&mut self.input_rect, input_rect);
+    }
+
+    /// Scroll so the caret is in view.
+    fn reveal(&mut self, rect: &WidgetRect) {
+        let Some(pos) = self.caret_pos(rect, self.display_caret()) else {
+            return;
+        };
+        let top = Self::view_top(rect);
+        let bottom = top - Self::view_height(rect);
+        let mut scroll = *self.scroll;
+        if pos.top > top {
+            scroll -= pos.top - top;
+        } else if pos.bottom < bottom {
+            scroll += bottom - pos.bottom;
+        }
+        self.scroll_to(rect, scroll);
+    }
+
+    fn scroll_to(&mut self, rect: &WidgetRect, scroll: f32) {
+        let scroll = scroll.min(self.max_scroll(rect)).max(0.0);
+        if scroll != *self.scroll {
+            *self.scroll = scroll;
+        }
+    }
+}
+
+impl<P> widget::Content<P> for TextAreaContent<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, rect| {
+            this.background.set_fill(rect, &Padding2d::zero());
+            this.background.set_slice_padding(Padding2d::uniform(4.0));
+            this.background.set_corners(CornerStyle::Rounded);
+        });
+        desc.watch(|this, _rect| {
+            this.background.set_color(*this.background_color);
+        });
+        desc.watch(|this, _rect| {
+            this.caret.graphic.set_color(*this.text_color);
+        });
+        desc.watch(|this, rect| {
+            this.edit_flag.watched_auto();
+            this.caret_flag.watched_auto();
+            this.update_blocks(rect);
+            if std::mem::take(&mut this.reveal_caret) {
+                this.reveal(rect);
+            }
+            let scroll = *this.scroll;
+            this.scroll_to(rect, scroll);
+            this.render(rect);
+            this.position_highlights(rect);
+        });
+        desc.watch(|this, rect| {
+            if this.handle.is_focused() {
+                crate::app::start_text_input(&this.handle);
+                // place input method windows by the composition or caret
+                let input_rect =
+                    this.input_rect.unwrap_or_else(|| SimpleRect::from(rect));
+                crate::app::set_text_input_rect(&this.handle, &input_rect);
+                this.text_input_active = true;
+            } else if this.text_input_active {
+                crate::app::stop_text_input(&this.handle);
+                this.set_composition(None);
+                this.text_input_active = false;
+            }
+        });
+        desc.watch(|this, _rect| {
+            let pointer = &mut this.pointer;
+            this.handle.handle_pointer_grab_stolen(|_pointer_id| {
+                *pointer = None;
+            });
+        });
+        desc.graphic(|this| &mut this.background);
+        desc.graphic(|this| &mut this.selection);
+        desc.graphic(|this| &mut this.paragraphs);
+        desc.graphic(|this| &mut this.caret);
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match *event.action() {
+            PointerAction::Down => {
+                let grabbed = self.hittest(rect, event.pos())
+                    && event.try_grab(self.handle.id());
+                if grabbed {
+                    self.handle.request_focus();
+                    self.pointer = Some(event.id());
+                    self.update_blocks(rect);
+                    let index = self.index_at(rect, event.pos());
+                    if event.click_count() >= 2 {
+                        self.editor.select_word(index);
+                    } else {
+                        let extend = event.modifiers().shift;
+                        self.editor.move_to(index, extend);
+                    }
+                    self.moved();
+                }
+                grabbed
+            }
+            PointerAction::Move(_, _) if self.pointer == Some(event.id()) => {
+                self.update_blocks(rect);
+                let index = self.index_at(rect, event.pos());
+                self.editor.move_to(index, true);
+                self.moved();
+                true
+            }
+            PointerAction::Up if self.pointer == Some(event.id()) => {
+                self.pointer = None;
+                event.try_ungrab(self.handle.id())
+            }
+            PointerAction::Wheel(_, dy) if self.hittest(rect, event.pos()) => {
+                self.update_blocks(rect);
+                let scroll = *self.scroll + dy;
+                self.scroll_to(rect, scroll);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn key_event(&mut self, rect: &WidgetRect, event: &KeyEvent) -> bool {
+        if event.action != KeyAction::Down || !self.handle.is_focused() {
+            return false;
+        }
+        self.handle_key(rect, event)
+    }
+
+    fn text_event(&mut self, _rect: &WidgetRect, event: &TextEvent) -> bool {
+        if !self.handle.is_focused() {
+            return false;
+        }
+        match event {
+            TextEvent::Input(text) => {
+                self.set_composition(None);
+                self.insert(text, EditKind::Typing);
+            }
+            TextEvent::Editing { text, cursor } => {
+                self.set_composition(Composition::from_editing(text, cursor));
+            }
+        }
+        true
+    }
+}
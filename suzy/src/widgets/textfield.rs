@@ -0,0 +1,547 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::ops::Range;
+
+use drying_paint::{Watched, WatchedQueue};
+
+use crate::{
+    dims::{Padding2d, Rect, SimpleRect},
+    graphics::{Color, Conditional, CornerStyle},
+    keyboard::{Key, KeyAction, KeyChord, KeyEvent, TextEvent},
+    platform::{
+        graphics::{SlicedImage, Text, TextStyle},
+        RenderPlatform,
+    },
+    pointer::{PointerAction, PointerEvent, PointerId},
+    text,
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+use super::editor::{Composition, Movement, TextEditor};
+
+/// The space between the edge of a text field and its text.
+const PADDING: f32 = 6.0;
+
+const CARET_WIDTH: f32 = 1.5;
+
+/// The thickness of the line under text being composed by an input method.
+const UNDERLINE_WIDTH: f32 = 1.0;
+
+const SELECT_ALL: KeyChord = KeyChord::new(Key::Character('a')).ctrl();
+const COPY: KeyChord = KeyChord::new(Key::Character('c')).ctrl();
+const CUT: KeyChord = KeyChord::new(Key::Character('x')).ctrl();
+const PASTE: KeyChord = KeyChord::new(Key::Character('v')).ctrl();
+
+/// A widget which allows a single line of text to be entered.
+#[cfg(feature = "platform-opengl")]
+pub type TextField<P = crate::platforms::DefaultRenderPlatform> =
+    Widget<TextFieldContent<P>>;
+
+/// A widget which allows a single line of text to be entered.
+#[cfg(not(feature = "platform-opengl"))]
+pub type TextField<P> = Widget<TextFieldContent<P>>;
+
+/// The content for a widget which allows a single line of text to be
+/// entered.
+///
+/// The field accepts text while it is focused.  The caret can be moved by
+/// character with the arrow keys, or by word while holding Ctrl, and the
+/// selection extended by holding Shift.  Ctrl+A, Ctrl+C, Ctrl+X and Ctrl+V
+/// select all, copy, cut and paste using the app's
+/// [`Clipboard`](crate::clipboard::Clipboard).
+///
+/// Text being composed with an input method is shown in place of the
+/// selection, underlined, until it is committed.
+///
+/// When the text is wider than the field, it scrolls to keep the caret in
+/// view.  The text is not clipped to the field; place it inside a mask if
+/// the platform provides one.
+pub struct TextFieldContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// Text displayed while the field is empty.
+    pub placeholder: Watched<String>,
+    /// The size of the text, in pixels.
+    pub font_size: Watched<f32>,
+    /// The color of the text.
+    pub text_color: Watched<Color>,
+    /// The color of the placeholder text.
+    pub placeholder_color: Watched<Color>,
+    /// The color drawn behind selected text.
+    pub selection_color: Watched<Color>,
+    /// The color of the field's background.
+    pub background_color: Watched<Color>,
+    /// If set, every character is displayed as this character, e.g. for
+    /// entering passwords.  Masked text can not be copied or cut.
+    pub mask: Watched<Option<char>>,
+    /// The most characters which can be entered into the field.
+    pub max_length: Watched<Option<usize>>,
+    editor: Watched<TextEditor>,
+    composition: Watched<Option<Composition>>,
+    input_rect: Watched<Option<SimpleRect>>,
+    scroll: Watched<f32>,
+    on_changed: WatchedQueue<'static, String>,
+    on_submit: WatchedQueue<'static, String>,
+    pointer: Option<PointerId>,
+    text_input_active: bool,
+    handle: UniqueHandle,
+    background: P::SlicedImage,
+    selection: Conditional<P::SlicedImage>,
+    text_graphic: P::Text,
+    caret: Conditional<P::SlicedImage>,
+    underline: Conditional<P::SlicedImage>,
+}
+
+impl<P> Default for TextFieldContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        let handle = UniqueHandle::default();
+        handle.set_focusable(true);
+        Self {
+            placeholder: Watched::default(),
+            font_size: Watched::new(16.0),
+            text_color: Watched::new(Color::BLACK),
+            placeholder_color: Watched::new(Color::GRAY),
+            selection_color: Watched::new(Color::LIGHT_SKY_BLUE),
+            background_color: Watched::new(Color::WHITE),
+            mask: Watched::new(None),
+            max_length: Watched::new(None),
+            editor: Watched::default(),
+            composition: Watched::new(None),
+            input_rect: Watched::new(None),
+            scroll: Watched::new(0.0),
+            on_changed: WatchedQueue::default(),
+            on_submit: WatchedQueue::default(),
+            pointer: None,
+            text_input_active: false,
+            handle,
+            background: P::SlicedImage::default(),
+            selection: Conditional::default(),
+            text_graphic: P::Text::default(),
+            caret: Conditional::default(),
+            underline: Conditional::default(),
+        }
+    }
+}
+
+impl<P> TextFieldContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// Get the text in the field.
+    pub fn text(&self) -> &str {
+        self.editor.text()
+    }
+
+    /// Replace the text in the field, placing the caret at the end.
+    ///
+    /// This does not produce a changed event.
+    pub fn set_text(&mut self, text: &str) {
+        self.editor.set_text(text);
+    }
+
+    /// Get the byte range of the selected text.
+    pub fn selection(&self) -> Range<usize> {
+        self.editor.selection()
+    }
+
+    /// Select a byte range of the text, placing the caret at its end.
+    pub fn set_selection(&mut self, range: Range<usize>) {
+        self.editor.select(range.start, range.end);
+    }
+
+    /// Set the tab index of this field.
+    ///
+    /// See [`UniqueHandle::set_tab_index`] for details.
+    pub fn set_tab_index(&self, tab_index: i32) {
+        self.handle.set_tab_index(tab_index);
+    }
+
+    /// Move focus to this field.
+    pub fn request_focus(&self) {
+        self.handle.request_focus();
+    }
+
+    /// Handle the text being changed by the user.
+    ///
+    /// This should be called in a watch closure, which will re-run with the
+    /// new text each time it changes.
+    pub fn on_changed<F: FnOnce(&str)>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.on_changed.handle_item(arg, |text| f(text));
+        });
+    }
+
+    /// Handle the user pressing Enter in the field.
+    ///
+    /// This should be called in a watch closure, which will re-run with the
+    /// text each time it is submitted.
+    pub fn on_submit<F: FnOnce(&str)>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.on_submit.handle_item(arg, |text| f(text));
+        });
+    }
+
+    fn changed(&mut self) {
+        self.on_changed.push_external(self.editor.text().to_owned());
+    }
+
+    /// Convert a byte index in the text, with any composition in place of
+    /// the selection, to one in the displayed text.
+    fn display_index(&self, composed: &str, index: usize) -> usize {
+        match *self.mask {
+            Some(mask) => composed[..index].chars().count() * mask.len_utf8(),
+            None => index,
+        }
+    }
+
+    /// Convert a byte index in the displayed text to one in the text.
+    fn text_index(&self, index: usize) -> usize {
+        let text = self.editor.text();
+        match *self.mask {
+            Some(mask) => text
+                .char_indices()
+                .nth(index / mask.len_utf8())
+                .map_or(text.len(), |(i, _)| i),
+            None => index,
+        }
+    }
+
+    /// Find the index in the text nearest to a point.
+    fn index_at(&self, point: [f32; 2]) -> usize {
+        if self.editor.text().is_empty() {
+            return 0;
+        }
+        self.text_graphic
+            .caret_at(point)
+            .map_or(self.editor.caret(), |index| self.text_index(index))
+    }
+
+    fn copy(&self) {
+        if self.mask.is_none() && self.editor.has_selection() {
+            if let Some(clipboard) = crate::app::clipboard() {
+                // the field can't do anything useful if this fails
+                let _result = clipboard.set_text(self.editor.selected_text());
+            }
+        }
+    }
+
+    fn paste(&mut self) {
+        let text = crate::app::clipboard()
+            .and_then(|clipboard| clipboard.text())
+            .unwrap_or_default();
+        self.insert(&text);
+    }
+
+    /// Insert text typed or pasted by the user, dropping any line breaks or
+    /// other control characters.
+    fn insert(&mut self, text: &str) {
+        let text: String =
+            text.chars().filter(|ch| !ch.is_control()).collect();
+        let max_length = *self.max_length;
+        if self.editor.insert(&text, max_length) {
+            self.changed();
+        }
+    }
+
+    fn handle_key(&mut self, event: &KeyEvent) -> bool {
+        if SELECT_ALL.matches(event) {
+            self.editor.select_all();
+            return true;
+        } else if COPY.matches(event) {
+            self.copy();
+            return true;
+        } else if CUT.matches(event) {
+            if self.mask.is_none() {
+                self.copy();
+                if self.editor.delete(Movement::Left) {
+                    self.changed();
+                }
+            }
+            return true;
+        } else if PASTE.matches(event) {
+            self.paste();
+            return true;
+        }
+        let mods = event.modifiers;
+        // word boundaries would reveal the structure of masked text, so
+        // in that case words extend to the ends of the text
+        let masked = self.mask.is_some();
+        let by_word = mods.ctrl && !masked;
+        let movement = match event.key {
+            Key::ArrowLeft if mods.ctrl && masked => Movement::Home,
+            Key::ArrowLeft if by_word => Movement::WordLeft,
+            Key::ArrowLeft => Movement::Left,
+            Key::ArrowRight if mods.ctrl && masked => Movement::End,
+            Key::ArrowRight if by_word => Movement::WordRight,
+            Key::ArrowRight => Movement::Right,
+            Key::Home => Movement::Home,
+            Key::End => Movement::End,
+            Key::Backspace | Key::Delete => {
+                let movement = match (event.key, by_word) {
+                    (Key::Backspace, true) => Movement::WordLeft,
+                    (Key::Backspace, false) => Movement::Left,
+                    (_, true) => Movement::WordRight,
+                    (_, false) => Movement::Right,
+                };
+                if self.editor.delete(movement) {
+                    self.changed();
+                }
+                return true;
+            }
+            Key::Enter => {
+                self.on_submit.push_external(self.editor.text().to_owned());
+                return true;
+            }
+            _ => return false,
+        };
+        self.editor.apply(movement, mods.shift);
+        true
+    }
+}
+
+impl<P> widget::Content<P> for TextFieldContent<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, rect| {
+            this.background.set_fill(rect, &Padding2d::zero());
+            this.background.set_slice_padding(Padding2d::uniform(4.0));
+            this.background.set_corners(CornerStyle::Rounded);
+        });
+        desc.watch(|this, _rect| {
+            this.background.set_color(*this.background_color);
+        });
+        desc.watch(|this, _rect| {
+            this.selection.graphic.set_color(*this.selection_color);
+            this.caret.graphic.set_color(*this.text_color);
+            this.underline.graphic.set_color(*this.text_color);
+        });
+        desc.watch(|this, rect| {
+            this.text_graphic.set_layout(text::Layout {
+                alignment: text::Alignment::Left,
+                line: text::Line::BetweenBaseAndCap,
+                flow: text::Flow::Out,
+                origin_x: rect.left() + PADDING - *this.scroll,
+                origin_y: rect.center_y(),
+                wrap_width: f32::INFINITY,
+                vertical_limit: text::VerticalLimit::Lines(1),
+                overflow_mode: text::OverflowMode::Truncate,
+            });
+        });
+        desc.watch(|this, rect| {
+            let editor = &*this.editor;
+            let selection = editor.selection();
+            // text being composed replaces the selection when committed
+            let (composed, composing, caret_index) = match &*this.composition {
+                Some(composition) => {
+                    let text = editor.text();
+                    let composed = [
+                        &text[..selection.start],
+                        &composition.text,
+                        &text[selection.end..],
+                    ]
+                    .concat();
+                    let start = selection.start;
+                    let end = start + composition.text.len();
+                    let caret = start + composition.cursor.end;
+                    (composed, Some(start..end), caret)
+                }
+                None => (editor.text().to_owned(), None, editor.caret()),
+            };
+            let (display, color) = if composed.is_empty() {
+                (this.placeholder.clone(), *this.placeholder_color)
+            } else if let Some(mask) = *this.mask {
+                let count = composed.chars().count();
+                (std::iter::repeat_n(mask, count).collect(), *this.text_color)
+            } else {
+                (composed.clone(), *this.text_color)
+            };
+            let style =
+                P::TextStyle::with_size_and_color(*this.font_size, color);
+            this.text_graphic.clear();
+            this.text_graphic.push_span(style, &display);
+            this.text_graphic.finish();
+            // keep the caret in view
+            let old_scroll = *this.scroll;
+            let mut scroll = old_scroll;
+            let left = rect.left() + PADDING;
+            let right = rect.right() - PADDING;
+            let caret_index = this.display_index(&composed, caret_index);
+            let caret = this.text_graphic.caret(caret_index);
+            if let Some(end) = this.text_graphic.caret(display.len()) {
+                let slack = right - end.x;
+                if slack > 0.0 {
+                    scroll = (scroll - slack).max(0.0);
+                }
+            }
+            if let Some(caret) = caret {
+                let x = caret.x + old_scroll - scroll;
+                if x < left {
+                    scroll -= left - x;
+                } else if x > right {
+                    scroll += x - right;
+                }
+            }
+            if scroll != old_scroll {
+                *this.scroll = scroll;
+            }
+            let shift = old_scroll - scroll;
+            // position the caret and selection, or the underline of the
+            // composition in place of the selection
+            let focused = this.handle.is_focused();
+            let highlight = composing.clone().unwrap_or(selection);
+            let hl_start = this
+                .text_graphic
+                .caret(this.display_index(&composed, highlight.start));
+            let hl_end = this
+                .text_graphic
+                .caret(this.display_index(&composed, highlight.end));
+            this.caret.enable = false;
+            this.selection.enable = false;
+            this.underline.enable = false;
+            let mut input_rect = None;
+            if let (Some(_), Some(start), Some(end)) =
+                (&composing, hl_start, hl_end)
+            {
+                let [left, right] = [start.x + shift, end.x + shift];
+                this.underline.enable = focused;
+                this.underline.graphic.set_horizontal_stretch(left, right);
+                this.underline.graphic.set_vertical_stretch(
+                    start.bottom,
+                    start.bottom + UNDERLINE_WIDTH,
+                );
+                let mut composition_rect = SimpleRect::default();
+                composition_rect.set_horizontal_stretch(left, right);
+                composition_rect.set_vertical_stretch(start.bottom, start.top);
+                input_rect = Some(composition_rect);
+            }
+            match (caret, hl_start, hl_end) {
+                (Some(caret), _, _)
+                    if composing.is_some() || highlight.is_empty() =>
+                {
+                    this.caret.enable = focused;
+                    let x = caret.x + shift;
+                    this.caret.graphic.set_horizontal_stretch(
+                        x - CARET_WIDTH / 2.0,
+                        x + CARET_WIDTH / 2.0,
+                    );
+                    this.caret
+                        .graphic
+                        .set_vertical_stretch(caret.bottom, caret.top);
+                    if input_rect.is_none() {
+                        let mut caret_rect = SimpleRect::default();
+                        caret_rect.set_horizontal_stretch(x, x);
+                        caret_rect
+                            .set_vertical_stretch(caret.bottom, caret.top);
+                        input_rect = Some(caret_rect);
+                    }
+                }
+                (_, Some(start), Some(end)) if composing.is_none() => {
+                    this.selection.enable = focused;
+                    this.selection.graphic.set_horizontal_stretch(
+                        start.x + shift,
+                        end.x + shift,
+                    );
+                    this.selection
+                        .graphic
+                        .set_vertical_stretch(start.bottom, start.top);
+                }
+                _ => {}
+            }
+            Watched::set_if_neq(&mut this.input_rect, input_rect);
+        });
+        desc.watch(|this, rect| {
+            if this.handle.is_focused() {
+                crate::app::start_text_input(&this.handle);
+                // place input method windows by the composition or caret
+                let input_rect =
+                    this.input_rect.unwrap_or_else(|| SimpleRect::from(rect));
+                crate::app::set_text_input_rect(&this.handle, &input_rect);
+                this.text_input_active = true;
+            } else if this.text_input_active {
+                crate::app::stop_text_input(&this.handle);
+                *this.composition = None;
+                this.text_input_active = false;
+            }
+        });
+        desc.watch(|this, _rect| {
+            let pointer = &mut this.pointer;
+            this.handle.handle_pointer_grab_stolen(|_pointer_id| {
+                *pointer = None;
+            });
+        });
+        desc.graphic(|this| &mut this.background);
+        desc.graphic(|this| &mut this.selection);
+        desc.graphic(|this| &mut this.text_graphic);
+        desc.graphic(|this| &mut this.caret);
+        desc.graphic(|this| &mut this.underline);
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down => {
+                let grabbed = self.hittest(rect, event.pos())
+                    && event.try_grab(self.handle.id());
+                if grabbed {
+                    self.handle.request_focus();
+                    self.pointer = Some(event.id());
+                    let index = self.index_at(event.pos());
+                    if event.click_count() >= 2 && self.mask.is_none() {
+                        self.editor.select_word(index);
+                    } else {
+                        let extend = event.modifiers().shift;
+                        self.editor.move_to(index, extend);
+                    }
+                }
+                grabbed
+            }
+            PointerAction::Move(_, _) if self.pointer == Some(event.id()) => {
+                let index = self.index_at(event.pos());
+                self.editor.move_to(index, true);
+                true
+            }
+            PointerAction::Up if self.pointer == Some(event.id()) => {
+                self.pointer = None;
+                event.try_ungrab(self.handle.id())
+            }
+            _ => false,
+        }
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        if event.action != KeyAction::Down || !self.handle.is_focused() {
+            return false;
+        }
+        self.handle_key(event)
+    }
+
+    fn text_event(&mut self, _rect: &WidgetRect, event: &TextEvent) -> bool {
+        if !self.handle.is_focused() {
+            return false;
+        }
+        match event {
+            TextEvent::Input(text) => {
+                *self.composition = None;
+                self.insert(text);
+            }
+            TextEvent::Editing { text, cursor } => {
+                *self.composition = Composition::from_editing(text, cursor);
+            }
+        }
+        true
+    }
+}
@@ -6,8 +6,9 @@ use std::rc::Rc;
 use drying_paint::{Watched, WatchedCell};
 
 use crate::{
+    keyboard::{Key, KeyAction, KeyEvent},
     pointer::{PointerAction, PointerEvent},
-    selectable::{Selectable, SelectionState, SelectionStateV1},
+    selectable::{Selectable, SelectionState},
     widget::{self, UniqueHandle, Widget, WidgetRect},
 };
 
@@ -85,6 +86,8 @@ pub struct ToggleButtonContent<T, V = ()> {
     allow_unselect: bool,
     interactable: Watched<bool>,
     pointers_down: usize,
+    key_down: Option<Key>,
+    is_default: bool,
     just_clicked: bool,
     currently_selected: bool,
     handle: UniqueHandle,
@@ -101,7 +104,36 @@ impl<T, V> ToggleButtonContent<T, V> {
     }
 
     pub fn state(&self) -> SelectionState {
-        *self.state
+        let state = *self.state;
+        if state != SelectionState::normal() {
+            state
+        } else if self.handle.is_focused() {
+            SelectionState::focus()
+        } else if *self.interactable && self.handle.is_hovered() {
+            SelectionState::hover()
+        } else {
+            state
+        }
+    }
+
+    /// Set the tab index of this button.
+    ///
+    /// See [`UniqueHandle::set_tab_index`] for details.
+    pub fn set_tab_index(&self, tab_index: i32) {
+        self.handle.set_tab_index(tab_index);
+    }
+
+    /// Move focus to this button.
+    pub fn request_focus(&self) {
+        self.handle.request_focus();
+    }
+
+    /// Set if this is a default button.
+    ///
+    /// Buttons normally respond to Enter or Space only when focused.  A
+    /// default button will also respond to Enter when no widget is focused.
+    pub fn set_default(&mut self, is_default: bool) {
+        self.is_default = is_default;
     }
 
     pub fn add_to_group(&mut self, group: &ToggleButtonGroup<V>) {
@@ -114,6 +146,25 @@ impl<T, V> ToggleButtonContent<T, V> {
         group.unset();
     }
 
+    /// Set if clicking this button while it is selected unselects it.
+    ///
+    /// This is allowed by default.
+    pub fn set_allow_unselect(&mut self, allow_unselect: bool) {
+        self.allow_unselect = allow_unselect;
+    }
+
+    /// Select or unselect this button, without changing the value of its
+    /// group.
+    pub fn set_selected(&mut self, selected: bool) {
+        if self.currently_selected == selected {
+            return;
+        }
+        self.currently_selected = selected;
+        if self.pointers_down == 0 && self.key_down.is_none() {
+            *self.state = self.base_state();
+        }
+    }
+
     fn base_state(&self) -> SelectionState {
         if self.currently_selected {
             SelectionState::active()
@@ -121,6 +172,25 @@ impl<T, V> ToggleButtonContent<T, V> {
             SelectionState::normal()
         }
     }
+
+    fn toggle(&mut self, rect: &WidgetRect)
+    where
+        T: ToggleButtonValue<V>,
+    {
+        if !self.currently_selected {
+            self.just_clicked = true;
+            if let Some(group) = &*self.group {
+                group.set(self.content.get_value(rect));
+            }
+            self.currently_selected = true;
+        } else if self.allow_unselect {
+            if let Some(group) = &*self.group {
+                group.unset();
+            }
+            self.currently_selected = false;
+        }
+        *self.state = self.base_state();
+    }
 }
 
 impl<T, V, P> widget::Content<P> for ToggleButtonContent<T, V>
@@ -131,9 +201,11 @@ where
     fn desc(mut desc: impl widget::Desc<Self, P>) {
         desc.bare_child(|button| &mut button.content);
         desc.watch(|button, _rect| {
-            button.content.selection_changed(*button.state);
+            let state = button.state();
+            button.content.selection_changed(state);
         });
         desc.watch(|button, _rect| {
+            button.handle.set_focusable(*button.interactable);
             if !*button.interactable {
                 *button.state = button.base_state();
             }
@@ -175,6 +247,10 @@ where
         self.content.hittest(rect, point)
     }
 
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
     fn pointer_event(
         &mut self,
         rect: &WidgetRect,
@@ -185,10 +261,10 @@ where
                 let grabbed = self.hittest(rect, event.pos())
                     && event.try_grab(self.handle.id());
                 if grabbed {
-                    eprintln!("down: {:?}", self.content.get_value(rect));
                     self.pointers_down += 1;
                     if *self.interactable {
                         *self.state = SelectionState::pressed();
+                        self.handle.request_focus();
                     }
                 }
                 grabbed
@@ -209,41 +285,36 @@ where
                 if ungrabbed {
                     self.pointers_down -= 1;
                     if self.pointers_down == 0 {
-                        if !self.currently_selected {
-                            self.just_clicked = true;
-                            if let Some(group) = &*self.group {
-                                group.set(self.content.get_value(rect));
-                            }
-                            self.currently_selected = true;
-                        } else if self.allow_unselect {
-                            if let Some(group) = &*self.group {
-                                group.unset();
-                            }
-                            self.currently_selected = false;
-                        }
-                        *self.state = self.base_state();
+                        self.toggle(rect);
                     }
                 }
                 ungrabbed
             }
-            PointerAction::Hover(_, _) => {
-                match (self.state.v1(), self.hittest(rect, event.pos())) {
-                    (SelectionStateV1::Normal, true) => {
-                        let grabbed = event.try_grab(self.handle.id());
-                        if grabbed && *self.interactable {
-                            *self.state = SelectionState::hover();
-                        }
-                        grabbed
-                    }
-                    (SelectionStateV1::Hover, false) => {
-                        let ungrabbed = event.try_ungrab(self.handle.id());
-                        if ungrabbed {
-                            *self.state = self.base_state();
-                        }
-                        ungrabbed
-                    }
-                    _ => false,
+            _ => false,
+        }
+    }
+
+    fn key_event(&mut self, rect: &WidgetRect, event: &KeyEvent) -> bool {
+        match (event.action, event.key) {
+            (KeyAction::Down, key @ (Key::Enter | Key::Space)) => {
+                let is_default = self.is_default && key == Key::Enter;
+                if !*self.interactable
+                    || !self.handle.is_activation_target(is_default)
+                {
+                    return false;
+                }
+                if self.key_down.is_none() && self.pointers_down == 0 {
+                    self.key_down = Some(key);
+                    *self.state = SelectionState::pressed();
+                }
+                true
+            }
+            (KeyAction::Up, key) if self.key_down == Some(key) => {
+                self.key_down = None;
+                if self.pointers_down == 0 {
+                    self.toggle(rect);
                 }
+                true
             }
             _ => false,
         }
@@ -252,15 +323,19 @@ where
 
 impl<T: Default, V> Default for ToggleButtonContent<T, V> {
     fn default() -> Self {
+        let handle = UniqueHandle::default();
+        handle.set_focusable(true);
         Self {
             state: Watched::default(),
             group: Watched::new(None),
             allow_unselect: true,
             interactable: Watched::new(true),
             pointers_down: 0,
+            key_down: None,
+            is_default: false,
             just_clicked: false,
             currently_selected: false,
-            handle: UniqueHandle::default(),
+            handle,
             content: T::default(),
         }
     }
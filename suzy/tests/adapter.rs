@@ -0,0 +1,150 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use suzy::{
+    adapter::{Adaptable, DownwardVecAdapter, ScrollSettings},
+    app::{App, TestingExt},
+    dims::{Padding2d, Rect},
+    platforms::no_graphics::NoGraphics,
+    pointer::PointerAction,
+    watch::Watched,
+    widget::{self, Widget},
+};
+
+mod utils;
+use utils::*;
+
+/// An element which reports where its top edge is.
+struct Item {
+    top: Watched<Rc<Cell<f32>>>,
+}
+
+impl Adaptable<Rc<Cell<f32>>> for Item {
+    fn adapt(&mut self, data: &Rc<Cell<f32>>) {
+        *self.top = Rc::clone(data);
+    }
+
+    fn from(data: &Rc<Cell<f32>>) -> Self {
+        Item {
+            top: Watched::new(Rc::clone(data)),
+        }
+    }
+}
+
+impl widget::Content<NoGraphics> for Item {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, rect| {
+            this.top.set(rect.top());
+        });
+    }
+}
+
+#[derive(Default)]
+struct Root {
+    list: DownwardVecAdapter<Rc<Cell<f32>>, Item>,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, rect| {
+            this.list.set_fill(rect, &Padding2d::zero());
+        });
+        desc.child(|this| &mut this.list);
+    }
+}
+
+/// Create an app with a list of 20 elements, each 100 pixels tall, and
+/// return the cell tracking the top of the first one.
+fn setup(settings: ScrollSettings) -> (App<NoGraphics>, Rc<Cell<f32>>) {
+    let first = Rc::new(Cell::new(f32::NAN));
+    let mut root = Widget::<Root>::default();
+    *root.list.settings_mut() = settings;
+    let data = root.list.data_mut();
+    data.push(Rc::clone(&first));
+    data.extend((1..20).map(|_| Rc::new(Cell::new(f32::NAN))));
+    (app_with_root(root), first)
+}
+
+/// Drag the content by `dy` in each of `steps` frames, then release it
+/// after `hold`.
+fn drag(app: &mut App<NoGraphics>, dy: f32, steps: usize, hold: Duration) {
+    let mut y = 180.0;
+    mouse(app, PointerAction::Down, [240.0, y]);
+    for _ in 0..steps {
+        app.next_frame(Duration::from_millis(20));
+        y += dy;
+        mouse(app, PointerAction::Move(0.0, dy), [240.0, y]);
+    }
+    app.next_frame(hold);
+    mouse(app, PointerAction::Up, [240.0, y]);
+}
+
+/// Run frames until the content comes to rest, and return the lowest top
+/// edge of the first element seen along the way.
+fn settle(app: &mut App<NoGraphics>, first: &Cell<f32>) -> f32 {
+    let mut lowest = first.get();
+    for _ in 0..300 {
+        app.next_frame_60fps();
+        lowest = lowest.min(first.get());
+    }
+    app.update_watches();
+    lowest
+}
+
+#[test]
+fn fling_continues_and_bounces() {
+    let (mut app, first) = setup(ScrollSettings::default());
+    assert_eq!(first.get(), 360.0);
+    // stop before releasing, so the content does not keep moving
+    drag(&mut app, 10.0, 8, Duration::from_millis(200));
+    settle(&mut app, &first);
+    assert_eq!(first.get(), 440.0);
+    // fling the content back towards its start
+    drag(&mut app, -20.0, 3, Duration::ZERO);
+    assert_eq!(first.get(), 380.0);
+    app.next_frame_60fps();
+    app.update_watches();
+    assert!(first.get() < 380.0);
+    // it overshoots the start, then springs back
+    let lowest = settle(&mut app, &first);
+    assert!(lowest < 360.0, "lowest: {}", lowest);
+    assert_eq!(first.get(), 360.0);
+}
+
+#[test]
+fn fling_can_be_disabled() {
+    let (mut app, first) = setup(ScrollSettings {
+        kinetic: false,
+        ..ScrollSettings::default()
+    });
+    drag(&mut app, 20.0, 3, Duration::ZERO);
+    assert_eq!(first.get(), 420.0);
+    settle(&mut app, &first);
+    assert_eq!(first.get(), 420.0);
+}
+
+#[test]
+fn overscroll_resists_and_returns() {
+    let (mut app, first) = setup(ScrollSettings::default());
+    drag(&mut app, -25.0, 4, Duration::ZERO);
+    let pulled = first.get();
+    assert!(pulled > 260.0 && pulled < 300.0, "pulled: {}", pulled);
+    let lowest = settle(&mut app, &first);
+    assert!(lowest <= pulled);
+    assert_eq!(first.get(), 360.0);
+}
+
+#[test]
+fn scroll_to_reveals_element() {
+    let tops: Vec<_> = (0..20).map(|_| Rc::new(Cell::new(f32::NAN))).collect();
+    let mut root = Widget::<Root>::default();
+    root.list.data_mut().extend(tops.iter().cloned());
+    root.list.scroll_to(15);
+    let _app: App<NoGraphics> = app_with_root(root);
+    // the content moves just far enough for the element to be entirely
+    // visible
+    assert_eq!(tops[15].get(), 100.0);
+    assert_eq!(tops[14].get(), 200.0);
+}
@@ -3,10 +3,14 @@
 
 #![cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
 
+use std::{cell::Cell, rc::Rc};
+
 use suzy::{
     app::App,
     dims::{Padding2d, Rect},
     graphics::{Color, Conditional},
+    keyboard::{Key, KeyAction, KeyEvent},
+    navigation::NavAction,
     platforms::{
         opengl::{OpenGlRenderPlatform, SlicedImage},
         TEST_ENV,
@@ -24,7 +28,8 @@ struct ButtonContent {
 
 impl Selectable for ButtonContent {
     fn selection_changed(&mut self, state: SelectionState) {
-        self.image.enable = state == SelectionState::active();
+        self.image.enable = state == SelectionState::active()
+            || state == SelectionState::pressed();
     }
 }
 
@@ -40,6 +45,7 @@ impl widget::Content<OpenGlRenderPlatform> for ButtonContent {
 #[derive(Default)]
 struct Root {
     button: Button<ButtonContent>,
+    clicks: Rc<Cell<u32>>,
 }
 
 impl widget::Content<OpenGlRenderPlatform> for Root {
@@ -47,6 +53,10 @@ impl widget::Content<OpenGlRenderPlatform> for Root {
         desc.watch(|root, rect| {
             root.button.set_fill(rect, &Padding2d::zero());
         });
+        desc.watch(|root, _rect| {
+            let clicks = &root.clicks;
+            root.button.on_click(|| clicks.set(clicks.get() + 1));
+        });
         desc.child(|this| &mut this.button);
     }
 }
@@ -61,26 +71,72 @@ fn button() {
         let color = Color::from_rgba8(chunk[0], chunk[1], chunk[2], chunk[3]);
         assert_eq!(color, Color::BLACK);
     }
-    app.pointer_event(PointerEventData {
-        id: PointerId::Other(1),
-        action: PointerAction::Down,
-        x: 240.0,
-        y: 180.0,
-    });
+    app.pointer_event(PointerEventData::new(
+        PointerId::Other(1),
+        PointerAction::Down,
+        240.0,
+        180.0,
+    ));
     let capture = window.draw_and_take_screenshot(&mut app);
     for chunk in capture.chunks_exact(4) {
         let color = Color::from_rgba8(chunk[0], chunk[1], chunk[2], chunk[3]);
         assert_eq!(color, Color::WHITE);
     }
-    app.pointer_event(PointerEventData {
-        id: PointerId::Other(1),
-        action: PointerAction::Up,
-        x: 240.0,
-        y: 180.0,
-    });
+    app.pointer_event(PointerEventData::new(
+        PointerId::Other(1),
+        PointerAction::Up,
+        240.0,
+        180.0,
+    ));
     let capture = window.draw_and_take_screenshot(&mut app);
     for chunk in capture.chunks_exact(4) {
         let color = Color::from_rgba8(chunk[0], chunk[1], chunk[2], chunk[3]);
         assert_eq!(color, Color::BLACK);
     }
 }
+
+fn assert_fill(
+    window: &mut suzy::platforms::opengl::Window,
+    app: &mut App<OpenGlRenderPlatform>,
+    expected: Color,
+) {
+    let capture = window.draw_and_take_screenshot(app);
+    for chunk in capture.chunks_exact(4) {
+        let color = Color::from_rgba8(chunk[0], chunk[1], chunk[2], chunk[3]);
+        assert_eq!(color, expected);
+    }
+}
+
+#[test]
+fn button_keyboard() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let clicks = Rc::default();
+    let mut root = Widget::<Root>::default();
+    root.clicks = Rc::clone(&clicks);
+    root.button.set_default(true);
+    app.add_root(root);
+    assert_fill(&mut window, &mut app, Color::BLACK);
+    // with nothing focused, Enter goes to the default button
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Enter)));
+    assert_fill(&mut window, &mut app, Color::WHITE);
+    assert_eq!(clicks.get(), 0);
+    assert!(app.key_event(KeyEvent::new(KeyAction::Up, Key::Enter)));
+    assert_fill(&mut window, &mut app, Color::BLACK);
+    assert_eq!(clicks.get(), 1);
+    // Space only activates a focused button
+    assert!(!app.key_event(KeyEvent::new(KeyAction::Down, Key::Space)));
+    assert_fill(&mut window, &mut app, Color::BLACK);
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Tab)));
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Space)));
+    assert_fill(&mut window, &mut app, Color::WHITE);
+    assert!(app.key_event(KeyEvent::new(KeyAction::Up, Key::Space)));
+    assert_fill(&mut window, &mut app, Color::BLACK);
+    assert_eq!(clicks.get(), 2);
+    // a gamepad accept action works the same way
+    assert!(app.nav_event(NavAction::Activate, KeyAction::Down));
+    assert_fill(&mut window, &mut app, Color::WHITE);
+    assert!(app.nav_event(NavAction::Activate, KeyAction::Up));
+    assert_fill(&mut window, &mut app, Color::BLACK);
+    assert_eq!(clicks.get(), 3);
+}
@@ -0,0 +1,87 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::RefCell, rc::Rc};
+
+use suzy::{
+    app::{self, App, TestingExt},
+    clipboard::{Clipboard, MemoryClipboard},
+    keyboard::{Key, KeyAction, KeyChord, KeyEvent},
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerEvent},
+    widget::{self, Widget, WidgetRect},
+};
+
+const PASTE: KeyChord = KeyChord::new(Key::Character('v')).ctrl();
+
+/// Copies text when clicked, and pastes text when Ctrl+V is pressed.
+#[derive(Default)]
+struct CopyPaste {
+    pasted: Rc<RefCell<Option<String>>>,
+}
+
+impl widget::Content<NoGraphics> for CopyPaste {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+
+    fn pointer_event(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Up => {
+                let clipboard = app::clipboard().expect("no current app");
+                clipboard.set_text("copied").is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        if PASTE.matches(event) {
+            let clipboard = app::clipboard().expect("no current app");
+            *self.pasted.borrow_mut() = clipboard.text();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[test]
+fn copy_and_paste() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let clipboard = app.test_clipboard();
+    let pasted = Rc::default();
+    let mut widget = Widget::<CopyPaste>::default();
+    widget.pasted = Rc::clone(&pasted);
+    app.add_root(widget);
+    app.update_watches();
+    assert_eq!(clipboard.text(), None);
+    app.mouse_click([100.0, 100.0]);
+    assert_eq!(clipboard.text().as_deref(), Some("copied"));
+    clipboard
+        .set_text("from elsewhere")
+        .expect("memory clipboard failed");
+    let paste = KeyEvent::new(KeyAction::Down, PASTE.key)
+        .with_modifiers(PASTE.modifiers);
+    assert!(app.key_event(paste));
+    assert_eq!(pasted.borrow().as_deref(), Some("from elsewhere"));
+    // outside of an app, there is no clipboard
+    assert!(app::clipboard().is_none());
+}
+
+#[test]
+fn memory_clipboard_data() {
+    let clipboard = MemoryClipboard::new();
+    clipboard
+        .set_data("image/png", &[1, 2, 3])
+        .expect("memory clipboard failed");
+    assert_eq!(clipboard.data("image/png"), Some(vec![1, 2, 3]));
+    assert_eq!(clipboard.data("text/html"), None);
+    assert_eq!(clipboard.text(), None);
+    clipboard.set_text("text").expect("memory clipboard failed");
+    assert_eq!(clipboard.data("image/png"), None);
+    clipboard.clear();
+    assert_eq!(clipboard.text(), None);
+}
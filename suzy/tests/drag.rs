@@ -0,0 +1,290 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use suzy::{
+    app::App,
+    dims::Rect,
+    drag::{self, DragEvent},
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerEvent},
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+mod utils;
+use utils::*;
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+/// Take the logged events, grouped by the widget which received them.
+fn take_log(log: &Log) -> Vec<String> {
+    let mut entries = log.take();
+    // sources and targets are notified in separate watch closures, so
+    // their relative order is unspecified
+    entries.sort_by_key(|entry| entry.starts_with("source"));
+    entries
+}
+
+#[derive(Default)]
+struct Visual {
+    center: Rc<Cell<[f32; 2]>>,
+}
+
+impl widget::Content<NoGraphics> for Visual {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, rect| this.center.set(rect.center()));
+    }
+}
+
+#[derive(Default)]
+struct Source {
+    handle: UniqueHandle,
+    with_visual: Option<Rc<Cell<[f32; 2]>>>,
+    log: Log,
+}
+
+impl widget::Content<NoGraphics> for Source {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, _rect| {
+            let log = &this.log;
+            this.handle.handle_drag_event(|event| {
+                log.borrow_mut().push(format!("source {:?}", event));
+            });
+        });
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down => {
+                rect.contains(event.pos()) && event.try_grab(&self.handle)
+            }
+            PointerAction::Move(_, _) if event.is_grabbed_by(&self.handle) => {
+                let payload = String::from("hello");
+                if let Some(center) = self.with_visual.take() {
+                    let mut visual = Widget::<Visual>::default();
+                    visual.center = center;
+                    drag::start_drag_with_visual::<_, _, NoGraphics>(
+                        event,
+                        &self.handle,
+                        payload,
+                        visual,
+                    )
+                } else {
+                    drag::start_drag(event, &self.handle, payload)
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Target<T> {
+    name: &'static str,
+    handle: UniqueHandle,
+    log: Log,
+    _accepts: std::marker::PhantomData<T>,
+}
+
+impl<T: 'static> widget::Content<NoGraphics> for Target<T> {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, _rect| {
+            this.handle.accept_drops::<T>();
+        });
+        desc.watch(|this, _rect| {
+            let Self {
+                name, handle, log, ..
+            } = this;
+            handle.handle_drag_event(|event| {
+                let desc = match event {
+                    DragEvent::Enter { payload, .. } => {
+                        format!("enter {:?}", payload.downcast_ref::<String>())
+                    }
+                    DragEvent::Over { .. } => "over".to_string(),
+                    DragEvent::Leave => "leave".to_string(),
+                    DragEvent::Drop { payload, .. } => {
+                        format!("drop {:?}", payload.downcast_ref::<String>())
+                    }
+                    other => format!("{:?}", other),
+                };
+                log.borrow_mut().push(format!("{} {}", name, desc));
+            });
+        });
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+}
+
+#[derive(Default)]
+struct Root {
+    source: Widget<Source>,
+    strings: Widget<Target<String>>,
+    numbers: Widget<Target<i32>>,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, rect| {
+            // three columns: source, strings, numbers
+            let width = rect.width() / 3.0;
+            let place = |child: &mut dyn Rect, column: f32| {
+                child.set_width(width);
+                child.set_height(rect.height());
+                child.set_left(rect.left() + width * column);
+                child.set_bottom(rect.bottom());
+            };
+            place(&mut root.source, 0.0);
+            place(&mut root.strings, 1.0);
+            place(&mut root.numbers, 2.0);
+        });
+        desc.child(|root| &mut root.source);
+        desc.child(|root| &mut root.strings);
+        desc.child(|root| &mut root.numbers);
+    }
+}
+
+/// Two string targets covering the same area, beside the source.
+#[derive(Default)]
+struct OverlapRoot {
+    source: Widget<Source>,
+    first: Widget<Target<String>>,
+    second: Widget<Target<String>>,
+}
+
+impl widget::Content<NoGraphics> for OverlapRoot {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, rect| {
+            let width = rect.width() / 3.0;
+            root.source.set_width(width);
+            root.source.set_height(rect.height());
+            root.source.set_left(rect.left());
+            root.source.set_bottom(rect.bottom());
+            for target in [&mut root.first, &mut root.second] {
+                target.set_width(width * 2.0);
+                target.set_height(rect.height());
+                target.set_right(rect.right());
+                target.set_bottom(rect.bottom());
+            }
+        });
+        desc.child(|root| &mut root.source);
+        desc.child(|root| &mut root.first);
+        desc.child(|root| &mut root.second);
+    }
+}
+
+fn setup() -> (App<NoGraphics>, Log) {
+    let log = Log::default();
+    let mut root = Widget::<Root>::default();
+    root.source.log = Rc::clone(&log);
+    root.strings.log = Rc::clone(&log);
+    root.strings.name = "strings";
+    root.numbers.log = Rc::clone(&log);
+    root.numbers.name = "numbers";
+    (app_with_root(root), log)
+}
+
+#[test]
+fn drag_and_drop() {
+    let (mut app, log) = setup();
+    touch(&mut app, 1, PointerAction::Down, [80.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(10.0, 0.0), [90.0, 100.0]);
+    assert!(app.is_dragging());
+    assert!(log.borrow().is_empty());
+    // the numbers target does not accept strings
+    touch(&mut app, 1, PointerAction::Move(300.0, 0.0), [390.0, 100.0]);
+    assert!(log.borrow().is_empty());
+    touch(
+        &mut app,
+        1,
+        PointerAction::Move(-150.0, 0.0),
+        [240.0, 100.0],
+    );
+    touch(&mut app, 1, PointerAction::Move(10.0, 0.0), [250.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Up, [250.0, 100.0]);
+    assert!(!app.is_dragging());
+    assert_eq!(
+        take_log(&log),
+        [
+            "strings enter Some(\"hello\")",
+            "strings over",
+            "strings over",
+            "strings drop Some(\"hello\")",
+            "source Completed",
+        ],
+    );
+}
+
+#[test]
+fn unaccepted_drag_is_cancelled() {
+    let (mut app, log) = setup();
+    touch(&mut app, 1, PointerAction::Down, [80.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(170.0, 0.0), [250.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(140.0, 0.0), [390.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Up, [390.0, 100.0]);
+    assert!(!app.is_dragging());
+    assert_eq!(
+        take_log(&log),
+        [
+            "strings enter Some(\"hello\")",
+            "strings leave",
+            "source Cancelled",
+        ],
+    );
+    // the pointer is released, so it can start another drag
+    touch(&mut app, 1, PointerAction::Down, [80.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(10.0, 0.0), [90.0, 100.0]);
+    assert!(app.is_dragging());
+    app.cancel_drag();
+    app.update_watches();
+    assert_eq!(take_log(&log), ["source Cancelled"]);
+}
+
+#[test]
+fn drag_visual_follows_pointer() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let center = Rc::new(Cell::new([0.0; 2]));
+    let mut root = Widget::<Root>::default();
+    root.source.with_visual = Some(Rc::clone(&center));
+    app.add_root(root);
+    app.update_watches();
+    touch(&mut app, 1, PointerAction::Down, [80.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(10.0, 0.0), [90.0, 100.0]);
+    assert_eq!(center.get(), [90.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(200.0, 0.0), [290.0, 100.0]);
+    assert_eq!(center.get(), [290.0, 100.0]);
+}
+
+#[test]
+fn overlapping_targets() {
+    let log = Log::default();
+    let mut root = Widget::<OverlapRoot>::default();
+    root.source.log = Rc::clone(&log);
+    root.first.log = Rc::clone(&log);
+    root.first.name = "first";
+    root.second.log = Rc::clone(&log);
+    root.second.name = "second";
+    let mut app = app_with_root(root);
+    touch(&mut app, 1, PointerAction::Down, [80.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(170.0, 0.0), [250.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Up, [250.0, 100.0]);
+    // the drop goes to the target a pointer event would reach first
+    assert_eq!(
+        take_log(&log),
+        [
+            "first enter Some(\"hello\")",
+            "first over",
+            "first drop Some(\"hello\")",
+            "source Completed",
+        ],
+    );
+}
@@ -0,0 +1,170 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+#![cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::Rect,
+    keyboard::Key,
+    platforms::{opengl::OpenGlRenderPlatform, TEST_ENV},
+    widget::{self, Widget},
+    widgets::Dropdown,
+};
+
+mod utils;
+use utils::*;
+
+const FRUIT: [&str; 4] = ["apple", "banana", "blueberry", "cherry"];
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+type Selected = Rc<RefCell<Option<&'static str>>>;
+
+#[derive(Default)]
+struct Root {
+    dropdown: Dropdown<&'static str>,
+    selected: Selected,
+    open: Rc<Cell<bool>>,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.dropdown.set_width(200.0);
+            root.dropdown.set_height(30.0);
+            root.dropdown.set_center(rect.center());
+        });
+        desc.watch(|root, _rect| {
+            *root.selected.borrow_mut() = *root.dropdown.selected;
+        });
+        desc.watch(|root, _rect| {
+            root.open.set(root.dropdown.is_open());
+        });
+        desc.child(|root| &mut root.dropdown);
+    }
+}
+
+fn setup(
+    options: &[&'static str],
+) -> (App<OpenGlRenderPlatform>, Selected, Rc<Cell<bool>>) {
+    let selected = Selected::default();
+    let open = Rc::default();
+    let mut root = Widget::<Root>::default();
+    root.selected = Rc::clone(&selected);
+    root.open = Rc::clone(&open);
+    root.dropdown.set_options(options.to_vec());
+    (app_with_root(root), selected, open)
+}
+
+/// The center of an option in the popup list, which opens below the
+/// dropdown.
+fn option_pos(index: usize) -> [f32; 2] {
+    [240.0, 165.0 - 14.0 - 28.0 * index as f32]
+}
+
+#[test]
+fn dropdown_pointer() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, selected, open) = setup(&FRUIT);
+    assert_eq!(*selected.borrow(), None);
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+    assert!(open.get());
+    let _capture = window.draw_and_take_screenshot(&mut app);
+    app.mouse_click(option_pos(1));
+    app.update_watches();
+    assert!(!open.get());
+    assert_eq!(*selected.borrow(), Some("banana"));
+    // pressing outside the list closes it without choosing anything
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+    assert!(open.get());
+    app.mouse_click([20.0, 20.0]);
+    app.update_watches();
+    assert!(!open.get());
+    assert_eq!(*selected.borrow(), Some("banana"));
+}
+
+#[test]
+fn dropdown_keys() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, selected, open) = setup(&FRUIT);
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+    key(&mut app, Key::Escape);
+    assert!(!open.get());
+    // while closed, keys change the selection directly
+    key(&mut app, Key::ArrowDown);
+    assert_eq!(*selected.borrow(), Some("apple"));
+    key(&mut app, Key::End);
+    assert_eq!(*selected.borrow(), Some("cherry"));
+    // typing the same letter cycles through the options starting with it
+    key(&mut app, Key::Character('b'));
+    assert_eq!(*selected.borrow(), Some("banana"));
+    key(&mut app, Key::Character('b'));
+    assert_eq!(*selected.borrow(), Some("blueberry"));
+    app.next_frame(Duration::from_secs(2));
+    key(&mut app, Key::Character('a'));
+    assert_eq!(*selected.borrow(), Some("apple"));
+    // while open, keys move the highlight, and Enter chooses it
+    key(&mut app, Key::Space);
+    assert!(open.get());
+    key(&mut app, Key::ArrowDown);
+    key(&mut app, Key::ArrowDown);
+    assert_eq!(*selected.borrow(), Some("apple"));
+    key(&mut app, Key::Enter);
+    assert!(!open.get());
+    assert_eq!(*selected.borrow(), Some("blueberry"));
+    // options are found by their lowercase form, even when that is a
+    // different length than the character typed
+    app.next_frame(Duration::from_secs(2));
+    key(&mut app, Key::Character('\u{212a}'));
+    assert_eq!(*selected.borrow(), Some("blueberry"));
+    app.next_frame(Duration::from_secs(2));
+    key(&mut app, Key::Character('C'));
+    assert_eq!(*selected.borrow(), Some("cherry"));
+    let _capture = window.draw_and_take_screenshot(&mut app);
+}
+
+#[test]
+fn dropdown_scrolls_to_highlight() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, selected, open) = setup(&MONTHS);
+    // with more options than fit, the list opens upwards, and this is the
+    // lowest visible row
+    let bottom_row = [240.0, 195.0 + 14.0];
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+    assert!(open.get());
+    key(&mut app, Key::End);
+    let _capture = window.draw_and_take_screenshot(&mut app);
+    app.mouse_click(bottom_row);
+    app.update_watches();
+    assert_eq!(*selected.borrow(), Some("december"));
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+    key(&mut app, Key::Home);
+    app.mouse_click(bottom_row);
+    app.update_watches();
+    assert_eq!(*selected.borrow(), Some("august"));
+}
@@ -0,0 +1,203 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::Cell, rc::Rc};
+
+use suzy::{
+    app::App,
+    dims::Rect,
+    keyboard::{Key, KeyAction, Modifiers},
+    navigation::{Direction, NavAction},
+    platforms::no_graphics::NoGraphics,
+    selectable::{Selectable, SelectionState},
+    widget::{self, Widget},
+    widgets::{ButtonBehavior, ToggleButton},
+};
+
+mod utils;
+use utils::*;
+
+#[derive(Default)]
+struct StateFeedback {
+    state: Rc<Cell<SelectionState>>,
+}
+
+impl Selectable for StateFeedback {
+    fn selection_changed(&mut self, state: SelectionState) {
+        self.state.set(state);
+    }
+}
+
+impl widget::Content<NoGraphics> for StateFeedback {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+}
+
+type TestButton = Widget<ButtonBehavior<StateFeedback>>;
+
+#[derive(Default)]
+struct Root {
+    first: TestButton,
+    second: ToggleButton<StateFeedback>,
+    third: TestButton,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.child(|root| &mut root.first);
+        desc.child(|root| &mut root.second);
+        desc.child(|root| &mut root.third);
+    }
+}
+
+fn tab(app: &mut App<NoGraphics>, shift: bool) {
+    let modifiers = Modifiers {
+        shift,
+        ..Modifiers::NONE
+    };
+    assert!(key_with(app, Key::Tab, modifiers));
+}
+
+#[test]
+fn tab_traversal() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let states: [Rc<Cell<SelectionState>>; 3] = Default::default();
+    let mut root = Widget::<Root>::default();
+    root.first.content_mut().state = Rc::clone(&states[0]);
+    root.second.content_mut().state = Rc::clone(&states[1]);
+    root.third.content_mut().state = Rc::clone(&states[2]);
+    app.add_root(root);
+    let focused = || {
+        states
+            .iter()
+            .map(|state| state.get() == SelectionState::focus())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(focused(), [false, false, false]);
+    tab(&mut app, false);
+    assert_eq!(focused(), [true, false, false]);
+    tab(&mut app, false);
+    assert_eq!(focused(), [false, true, false]);
+    tab(&mut app, false);
+    assert_eq!(focused(), [false, false, true]);
+    tab(&mut app, false);
+    assert_eq!(focused(), [true, false, false]);
+    tab(&mut app, true);
+    assert_eq!(focused(), [false, false, true]);
+    app.clear_focus();
+    app.update_watches();
+    assert_eq!(focused(), [false, false, false]);
+}
+
+#[derive(Default)]
+struct TabIndexRoot {
+    first: TestButton,
+    second: TestButton,
+    skipped: TestButton,
+}
+
+impl widget::Content<NoGraphics> for TabIndexRoot {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.child(|root| &mut root.first);
+        desc.child(|root| &mut root.skipped);
+        desc.child(|root| &mut root.second);
+    }
+}
+
+#[test]
+fn tab_index_order() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let states: [Rc<Cell<SelectionState>>; 3] = Default::default();
+    let mut root = Widget::<TabIndexRoot>::default();
+    root.first.content_mut().state = Rc::clone(&states[0]);
+    root.second.content_mut().state = Rc::clone(&states[1]);
+    root.second.set_tab_index(1);
+    root.skipped.content_mut().state = Rc::clone(&states[2]);
+    root.skipped.set_tab_index(-1);
+    app.add_root(root);
+    let focused = || {
+        states
+            .iter()
+            .map(|state| state.get() == SelectionState::focus())
+            .collect::<Vec<_>>()
+    };
+    tab(&mut app, false);
+    assert_eq!(focused(), [false, true, false]);
+    tab(&mut app, false);
+    assert_eq!(focused(), [true, false, false]);
+    tab(&mut app, false);
+    assert_eq!(focused(), [false, true, false]);
+}
+
+#[derive(Default)]
+struct GridRoot {
+    top_left: TestButton,
+    top_right: TestButton,
+    bottom_left: TestButton,
+    bottom_right: TestButton,
+}
+
+impl widget::Content<NoGraphics> for GridRoot {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, rect| {
+            let [width, height] = [rect.width() / 2.0, rect.height() / 2.0];
+            root.top_left.set_width(width);
+            root.top_left.set_height(height);
+            root.top_left.set_left(rect.left());
+            root.top_left.set_top(rect.top());
+            root.top_right.set_width(width);
+            root.top_right.set_height(height);
+            root.top_right.set_right(rect.right());
+            root.top_right.set_top(rect.top());
+            root.bottom_left.set_width(width);
+            root.bottom_left.set_height(height);
+            root.bottom_left.set_left(rect.left());
+            root.bottom_left.set_bottom(rect.bottom());
+            root.bottom_right.set_width(width);
+            root.bottom_right.set_height(height);
+            root.bottom_right.set_right(rect.right());
+            root.bottom_right.set_bottom(rect.bottom());
+        });
+        desc.child(|root| &mut root.top_left);
+        desc.child(|root| &mut root.top_right);
+        desc.child(|root| &mut root.bottom_left);
+        desc.child(|root| &mut root.bottom_right);
+    }
+}
+
+#[test]
+fn directional_navigation() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let states: [Rc<Cell<SelectionState>>; 4] = Default::default();
+    let mut root = Widget::<GridRoot>::default();
+    root.top_left.content_mut().state = Rc::clone(&states[0]);
+    root.top_right.content_mut().state = Rc::clone(&states[1]);
+    root.bottom_left.content_mut().state = Rc::clone(&states[2]);
+    root.bottom_right.content_mut().state = Rc::clone(&states[3]);
+    app.add_root(root);
+    app.update_watches();
+    let focused = || {
+        states
+            .iter()
+            .position(|state| state.get() == SelectionState::focus())
+    };
+    let nav = |app: &mut App<NoGraphics>, direction| {
+        let handled =
+            app.nav_event(NavAction::Navigate(direction), KeyAction::Down);
+        app.update_watches();
+        handled
+    };
+    // with nothing focused, navigating focuses the first widget
+    assert!(nav(&mut app, Direction::Down));
+    assert_eq!(focused(), Some(0));
+    assert!(nav(&mut app, Direction::Right));
+    assert_eq!(focused(), Some(1));
+    assert!(nav(&mut app, Direction::Down));
+    assert_eq!(focused(), Some(3));
+    assert!(nav(&mut app, Direction::Left));
+    assert_eq!(focused(), Some(2));
+    // nothing further left, focus stays put
+    assert!(!nav(&mut app, Direction::Left));
+    assert_eq!(focused(), Some(2));
+    assert!(nav(&mut app, Direction::Up));
+    assert_eq!(focused(), Some(0));
+}
@@ -0,0 +1,321 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::{Padding2d, Rect},
+    gesture::{GestureArea, GestureEvent, GesturePhase},
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerEvent},
+    selectable::SelectableIgnored,
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+    widgets::ButtonBehavior,
+};
+
+mod utils;
+use utils::*;
+
+#[derive(Default)]
+struct Surface {
+    button: Widget<ButtonBehavior<SelectableIgnored<()>>>,
+}
+
+impl widget::Content<NoGraphics> for Surface {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, rect| {
+            // the button covers the right half of the surface
+            this.button.set_width(rect.width() / 2.0);
+            this.button.set_height(rect.height());
+            this.button.set_right(rect.right());
+            this.button.set_bottom(rect.bottom());
+        });
+        desc.child(|this| &mut this.button);
+    }
+}
+
+#[derive(Default)]
+struct Root {
+    area: GestureArea<Surface>,
+    gestures: Rc<RefCell<Vec<GestureEvent>>>,
+    clicks: Rc<RefCell<usize>>,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, rect| {
+            this.area.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|this, _rect| {
+            let gestures = &this.gestures;
+            this.area
+                .on_gesture(|event| gestures.borrow_mut().push(event));
+        });
+        desc.watch(|this, _rect| {
+            let clicks = &this.clicks;
+            this.area.content().button.on_click(|| {
+                *clicks.borrow_mut() += 1;
+            });
+        });
+        desc.child(|this| &mut this.area);
+    }
+}
+
+type Feedback<T> = Rc<RefCell<T>>;
+
+fn setup() -> (
+    App<NoGraphics>,
+    Feedback<Vec<GestureEvent>>,
+    Feedback<usize>,
+) {
+    let root = Widget::<Root>::default();
+    let gestures = Rc::clone(&root.gestures);
+    let clicks = Rc::clone(&root.clicks);
+    (app_with_root(root), gestures, clicks)
+}
+
+#[test]
+fn tap_and_double_tap() {
+    let (mut app, gestures, clicks) = setup();
+    touch(&mut app, 1, PointerAction::Down, [100.0, 100.0]);
+    app.next_frame_60fps();
+    touch(&mut app, 1, PointerAction::Up, [102.0, 100.0]);
+    app.next_frame(Duration::from_millis(100));
+    assert_eq!(
+        gestures.borrow_mut().drain(..).collect::<Vec<_>>(),
+        [GestureEvent::Tap {
+            pos: [102.0, 100.0]
+        }],
+    );
+    touch(&mut app, 2, PointerAction::Down, [104.0, 102.0]);
+    app.next_frame_60fps();
+    touch(&mut app, 2, PointerAction::Up, [104.0, 102.0]);
+    app.update_watches();
+    assert_eq!(
+        gestures.borrow_mut().drain(..).collect::<Vec<_>>(),
+        [
+            GestureEvent::Tap {
+                pos: [104.0, 102.0]
+            },
+            GestureEvent::DoubleTap {
+                pos: [104.0, 102.0]
+            },
+        ],
+    );
+    // a tap on the button clicks it, without producing a gesture
+    touch(&mut app, 3, PointerAction::Down, [400.0, 100.0]);
+    app.next_frame_60fps();
+    touch(&mut app, 3, PointerAction::Up, [400.0, 100.0]);
+    app.update_watches();
+    assert!(gestures.borrow().is_empty());
+    assert_eq!(*clicks.borrow(), 1);
+}
+
+#[test]
+fn long_press() {
+    let (mut app, gestures, clicks) = setup();
+    touch(&mut app, 1, PointerAction::Down, [400.0, 100.0]);
+    app.next_frame(Duration::from_millis(300));
+    app.update_watches();
+    assert!(gestures.borrow().is_empty());
+    app.next_frame(Duration::from_millis(300));
+    app.update_watches();
+    assert_eq!(
+        gestures.borrow_mut().drain(..).collect::<Vec<_>>(),
+        [GestureEvent::LongPress {
+            pos: [400.0, 100.0]
+        }],
+    );
+    // the long press takes the pointer from the button
+    touch(&mut app, 1, PointerAction::Up, [400.0, 100.0]);
+    app.update_watches();
+    assert!(gestures.borrow().is_empty());
+    assert_eq!(*clicks.borrow(), 0);
+}
+
+#[test]
+fn pan_steals_from_button() {
+    let (mut app, gestures, clicks) = setup();
+    touch(&mut app, 1, PointerAction::Down, [400.0, 100.0]);
+    app.next_frame_60fps();
+    touch(&mut app, 1, PointerAction::Move(5.0, 0.0), [405.0, 100.0]);
+    app.update_watches();
+    assert!(gestures.borrow().is_empty());
+    app.next_frame(Duration::from_millis(10));
+    touch(&mut app, 1, PointerAction::Move(-20.0, 0.0), [385.0, 100.0]);
+    app.next_frame(Duration::from_millis(10));
+    touch(&mut app, 1, PointerAction::Move(-10.0, 0.0), [375.0, 100.0]);
+    app.next_frame(Duration::from_millis(10));
+    touch(&mut app, 1, PointerAction::Up, [375.0, 100.0]);
+    app.update_watches();
+    let events = gestures.borrow_mut().drain(..).collect::<Vec<_>>();
+    let phases = events
+        .iter()
+        .map(|event| match event {
+            GestureEvent::Pan { phase, .. } => *phase,
+            other => panic!("unexpected gesture {:?}", other),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        phases,
+        [
+            GesturePhase::Began,
+            GesturePhase::Changed,
+            GesturePhase::Ended
+        ],
+    );
+    let GestureEvent::Pan {
+        translation,
+        velocity,
+        ..
+    } = events[2]
+    else {
+        unreachable!()
+    };
+    assert_eq!(translation, [-25.0, 0.0]);
+    assert!(velocity[0] < -500.0);
+    assert_eq!(*clicks.borrow(), 0);
+}
+
+#[test]
+fn pinch_and_rotate() {
+    let (mut app, gestures, _clicks) = setup();
+    touch(&mut app, 1, PointerAction::Down, [100.0, 100.0]);
+    touch(&mut app, 2, PointerAction::Down, [200.0, 100.0]);
+    app.next_frame_60fps();
+    // spread the pointers apart
+    touch(&mut app, 2, PointerAction::Move(50.0, 0.0), [250.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(-50.0, 0.0), [50.0, 100.0]);
+    app.update_watches();
+    let events = gestures.borrow_mut().drain(..).collect::<Vec<_>>();
+    assert!(matches!(
+        events[0],
+        GestureEvent::Pinch {
+            phase: GesturePhase::Began,
+            ..
+        }
+    ));
+    assert_eq!(
+        events.last(),
+        Some(&GestureEvent::Pinch {
+            phase: GesturePhase::Changed,
+            center: [150.0, 100.0],
+            scale: 2.0,
+        }),
+    );
+    // rotate a quarter turn counter-clockwise around the center
+    touch(
+        &mut app,
+        2,
+        PointerAction::Move(-100.0, 100.0),
+        [150.0, 200.0],
+    );
+    touch(
+        &mut app,
+        1,
+        PointerAction::Move(100.0, -100.0),
+        [150.0, 0.0],
+    );
+    app.update_watches();
+    let events = gestures.borrow_mut().drain(..).collect::<Vec<_>>();
+    let rotation = events.iter().rev().find_map(|event| match event {
+        GestureEvent::Rotate { angle, .. } => Some(*angle),
+        _ => None,
+    });
+    let angle = rotation.expect("no rotate gesture");
+    assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-4);
+    touch(&mut app, 1, PointerAction::Up, [150.0, 0.0]);
+    touch(&mut app, 2, PointerAction::Up, [150.0, 200.0]);
+    app.update_watches();
+    let events = gestures.borrow_mut().drain(..).collect::<Vec<_>>();
+    assert!(matches!(
+        events[..],
+        [
+            GestureEvent::Pinch {
+                phase: GesturePhase::Ended,
+                ..
+            },
+            GestureEvent::Rotate {
+                phase: GesturePhase::Ended,
+                ..
+            },
+        ]
+    ));
+}
+
+/// A root drawn above the gesture area, which steals every pointer that
+/// moves while it is armed.
+#[derive(Default)]
+struct Stealer {
+    armed: Rc<Cell<bool>>,
+    handle: UniqueHandle,
+}
+
+impl widget::Content<NoGraphics> for Stealer {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn pointer_event(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Move(_, _) if self.armed.get() => {
+                event.force_grab(self.handle.id());
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn stolen_pointers_cancel() {
+    let (mut app, gestures, _clicks) = setup();
+    let stealer = Widget::<Stealer>::default();
+    let armed = Rc::clone(&stealer.armed);
+    app.add_root(stealer);
+    app.update_watches();
+    touch(&mut app, 1, PointerAction::Down, [100.0, 100.0]);
+    touch(&mut app, 2, PointerAction::Down, [200.0, 100.0]);
+    app.next_frame_60fps();
+    touch(&mut app, 2, PointerAction::Move(50.0, 0.0), [250.0, 100.0]);
+    touch(&mut app, 1, PointerAction::Move(-50.0, 0.0), [50.0, 100.0]);
+    app.update_watches();
+    gestures.borrow_mut().clear();
+    // both pointers are stolen before the gesture area updates
+    armed.set(true);
+    touch(&mut app, 1, PointerAction::Move(-5.0, 0.0), [45.0, 100.0]);
+    touch(&mut app, 2, PointerAction::Move(5.0, 0.0), [255.0, 100.0]);
+    app.update_watches();
+    let events = gestures.borrow_mut().drain(..).collect::<Vec<_>>();
+    assert!(matches!(
+        events[..],
+        [GestureEvent::Pinch {
+            phase: GesturePhase::Cancelled,
+            ..
+        }]
+    ));
+    // neither pointer is still tracked, so a new touch can tap
+    armed.set(false);
+    touch(&mut app, 3, PointerAction::Down, [100.0, 100.0]);
+    app.next_frame_60fps();
+    touch(&mut app, 3, PointerAction::Up, [100.0, 100.0]);
+    app.update_watches();
+    let events = gestures.borrow_mut().drain(..).collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        [GestureEvent::Tap {
+            pos: [100.0, 100.0]
+        }]
+    );
+}
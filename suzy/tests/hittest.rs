@@ -0,0 +1,94 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use suzy::{
+    app::App,
+    dims::Rect,
+    platforms::no_graphics::NoGraphics,
+    widget::{self, UniqueHandle, Widget},
+};
+
+#[derive(Default)]
+struct Leaf {
+    handle: Option<UniqueHandle>,
+}
+
+impl widget::Content<NoGraphics> for Leaf {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        self.handle.as_ref()
+    }
+}
+
+#[derive(Default)]
+struct Columns {
+    left: Widget<Leaf>,
+    right: Widget<Leaf>,
+}
+
+impl widget::Content<NoGraphics> for Columns {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, rect| {
+            let width = rect.width() / 2.0;
+            root.left.set_width(width);
+            root.left.set_height(rect.height());
+            root.left.set_left(rect.left());
+            root.left.set_bottom(rect.bottom());
+            root.right.set_width(width);
+            root.right.set_height(rect.height());
+            root.right.set_right(rect.right());
+            root.right.set_bottom(rect.bottom());
+        });
+        desc.child(|root| &mut root.left);
+        desc.child(|root| &mut root.right);
+    }
+}
+
+#[derive(Default)]
+struct Popup {
+    inner: Widget<Leaf>,
+}
+
+impl widget::Content<NoGraphics> for Popup {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|popup, rect| {
+            popup.inner.set_width(100.0);
+            popup.inner.set_height(100.0);
+            popup.inner.set_center(rect.center());
+        });
+        desc.child(|popup| &mut popup.inner);
+    }
+
+    fn hittest(&self, _rect: &widget::WidgetRect, _point: [f32; 2]) -> bool {
+        // the popup itself is transparent to the pointer
+        false
+    }
+}
+
+#[test]
+fn widgets_at_point() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let mut columns = Widget::<Columns>::default();
+    let handle = UniqueHandle::default();
+    let right_id = handle.id();
+    columns.right.handle = Some(handle);
+    app.add_root(columns);
+    app.add_root(Widget::<Popup>::default());
+    app.update_watches();
+    let names = |point| {
+        app.widgets_at(point)
+            .into_iter()
+            .map(|hit| hit.type_name.rsplit("::").next().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names([100.0, 100.0]), ["Leaf", "Columns"]);
+    assert_eq!(names([250.0, 180.0]), ["Leaf", "Leaf", "Columns"]);
+    let hits = app.widgets_at([300.0, 100.0]);
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].handle == Some(right_id));
+    assert_eq!(hits[0].rect.left(), 240.0);
+    assert_eq!(hits[0].rect.width(), 240.0);
+    assert!(hits[1].handle.is_none());
+    assert!(app.widgets_at([-10.0, 100.0]).is_empty());
+}
@@ -0,0 +1,157 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::Cell, rc::Rc};
+
+use suzy::{
+    app::App,
+    dims::{Padding2d, Rect},
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerId},
+    selectable::{Selectable, SelectionState},
+    widget::{self, Widget},
+    widgets::ButtonBehavior,
+};
+
+mod utils;
+use utils::*;
+
+#[derive(Default)]
+struct StateFeedback {
+    state: Rc<Cell<SelectionState>>,
+}
+
+impl Selectable for StateFeedback {
+    fn selection_changed(&mut self, state: SelectionState) {
+        self.state.set(state);
+    }
+}
+
+impl widget::Content<NoGraphics> for StateFeedback {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+}
+
+#[derive(Default)]
+struct Root {
+    left: Widget<ButtonBehavior<StateFeedback>>,
+    right: Widget<ButtonBehavior<StateFeedback>>,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, rect| {
+            let width = rect.width() / 2.0;
+            root.left.set_width(width);
+            root.left.set_height(rect.height());
+            root.left.set_left(rect.left());
+            root.left.set_bottom(rect.bottom());
+            root.right.set_width(width);
+            root.right.set_height(rect.height());
+            root.right.set_right(rect.right());
+            root.right.set_bottom(rect.bottom());
+        });
+        desc.child(|root| &mut root.left);
+        desc.child(|root| &mut root.right);
+    }
+}
+
+#[test]
+fn hover_enter_leave() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let states: [Rc<Cell<SelectionState>>; 2] = Default::default();
+    let mut root = Widget::<Root>::default();
+    root.left.content_mut().state = Rc::clone(&states[0]);
+    root.right.content_mut().state = Rc::clone(&states[1]);
+    app.add_root(root);
+    app.update_watches();
+    let hovered = || {
+        states
+            .iter()
+            .map(|state| state.get() == SelectionState::hover())
+            .collect::<Vec<_>>()
+    };
+    let hover = PointerAction::Hover(0.0, 0.0);
+    pointer(&mut app, PointerId::Mouse, hover, [100.0, 100.0]);
+    assert_eq!(hovered(), [true, false]);
+    pointer(&mut app, PointerId::Mouse, hover, [300.0, 100.0]);
+    assert_eq!(hovered(), [false, true]);
+    app.pointer_leave(PointerId::Mouse);
+    app.update_watches();
+    assert_eq!(hovered(), [false, false]);
+    // a touch hovers only while it is held down
+    let touch = PointerId::Touch(1);
+    pointer(&mut app, touch, PointerAction::Down, [100.0, 100.0]);
+    assert_eq!(states[0].get(), SelectionState::active());
+    pointer(
+        &mut app,
+        touch,
+        PointerAction::Move(200.0, 0.0),
+        [300.0, 100.0],
+    );
+    assert_eq!(hovered(), [false, true]);
+    pointer(&mut app, touch, PointerAction::Up, [300.0, 100.0]);
+    assert_eq!(hovered(), [false, false]);
+    // the mouse remains over a button after clicking it; clicking also
+    // focuses the button, which takes precedence over hover
+    pointer(
+        &mut app,
+        PointerId::Mouse,
+        PointerAction::Down,
+        [100.0, 100.0],
+    );
+    pointer(
+        &mut app,
+        PointerId::Mouse,
+        PointerAction::Up,
+        [100.0, 100.0],
+    );
+    assert_eq!(states[0].get(), SelectionState::focus());
+    app.clear_focus();
+    app.update_watches();
+    assert_eq!(hovered(), [true, false]);
+}
+
+#[derive(Default)]
+struct Stacked {
+    first: Widget<ButtonBehavior<StateFeedback>>,
+    second: Widget<ButtonBehavior<StateFeedback>>,
+}
+
+impl widget::Content<NoGraphics> for Stacked {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, rect| {
+            root.first.set_fill(rect, &Padding2d::zero());
+            root.second.set_fill(rect, &Padding2d::zero());
+        });
+        desc.child(|root| &mut root.first);
+        desc.child(|root| &mut root.second);
+    }
+}
+
+#[test]
+fn hover_only_topmost() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let states: [Rc<Cell<SelectionState>>; 4] = Default::default();
+    let mut lower = Widget::<Root>::default();
+    lower.left.content_mut().state = Rc::clone(&states[0]);
+    lower.right.content_mut().state = Rc::clone(&states[1]);
+    let mut upper = Widget::<Stacked>::default();
+    upper.first.content_mut().state = Rc::clone(&states[2]);
+    upper.second.content_mut().state = Rc::clone(&states[3]);
+    app.add_root(lower);
+    app.add_root(upper);
+    app.update_watches();
+    let hovered = || {
+        states
+            .iter()
+            .map(|state| state.get() == SelectionState::hover())
+            .collect::<Vec<_>>()
+    };
+    // only the widget a click would reach is hovered, not the overlapping
+    // sibling or the root below
+    let hover = PointerAction::Hover(0.0, 0.0);
+    pointer(&mut app, PointerId::Mouse, hover, [100.0, 100.0]);
+    assert_eq!(hovered(), [false, false, true, false]);
+    pointer(&mut app, PointerId::Mouse, hover, [300.0, 100.0]);
+    assert_eq!(hovered(), [false, false, true, false]);
+}
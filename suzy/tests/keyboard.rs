@@ -0,0 +1,156 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::RefCell, rc::Rc};
+
+use suzy::{
+    app::{self, App},
+    keyboard::{Key, KeyAction, KeyEvent, TextEvent},
+    platforms::no_graphics::NoGraphics,
+    watch::WatchedValueCore,
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+#[derive(Default)]
+struct Leaf {
+    accept: Option<Key>,
+    received: Rc<RefCell<Vec<(&'static str, Key)>>>,
+}
+
+impl widget::Content<NoGraphics> for Leaf {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        self.received.borrow_mut().push(("leaf", event.key));
+        self.accept == Some(event.key)
+    }
+}
+
+#[derive(Default)]
+struct Root {
+    intercept: Option<Key>,
+    leaf: Widget<Leaf>,
+    received: Rc<RefCell<Vec<(&'static str, Key)>>>,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.child(|root| &mut root.leaf);
+    }
+
+    fn key_event_before(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &KeyEvent,
+    ) -> bool {
+        self.intercept == Some(event.key)
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        self.received.borrow_mut().push(("root", event.key));
+        true
+    }
+}
+
+#[test]
+fn key_event_routing() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let received = Rc::default();
+    let mut root = Widget::<Root>::default();
+    root.intercept = Some(Key::Escape);
+    root.received = Rc::clone(&received);
+    root.leaf.accept = Some(Key::Enter);
+    root.leaf.received = Rc::clone(&received);
+    app.add_root(root);
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Enter)));
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Tab)));
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Escape)));
+    assert_eq!(
+        *received.borrow(),
+        [("leaf", Key::Enter), ("leaf", Key::Tab), ("root", Key::Tab)],
+    );
+}
+
+/// Starts text input when its start key is pressed, and stops it when its
+/// stop key is pressed.
+struct TextReceiver {
+    start: Key,
+    stop: Key,
+    handle: UniqueHandle,
+}
+
+impl Default for TextReceiver {
+    fn default() -> Self {
+        Self {
+            start: Key::Enter,
+            stop: Key::Escape,
+            handle: UniqueHandle::default(),
+        }
+    }
+}
+
+impl widget::Content<NoGraphics> for TextReceiver {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+
+    fn key_event(&mut self, rect: &WidgetRect, event: &KeyEvent) -> bool {
+        if event.is_press_of(self.start) {
+            app::start_text_input(&self.handle);
+            app::set_text_input_rect(&self.handle, rect);
+            true
+        } else if event.is_press_of(self.stop) {
+            app::stop_text_input(&self.handle);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn text_event(&mut self, _rect: &WidgetRect, event: &TextEvent) -> bool {
+        matches!(event, TextEvent::Input(_))
+    }
+}
+
+#[test]
+fn text_input_requests() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    app.add_root(Widget::<TextReceiver>::default());
+    assert!(!app.state().text_input().get_unwatched().active);
+    app.key_event(KeyEvent::new(KeyAction::Down, Key::Enter));
+    let text_input = app.state().text_input().get_unwatched();
+    assert!(text_input.active);
+    assert!(text_input.rect.is_some());
+    assert!(app.text_event(TextEvent::Input("é".to_owned())));
+    app.key_event(KeyEvent::new(KeyAction::Down, Key::Escape));
+    let text_input = app.state().text_input().get_unwatched();
+    assert!(!text_input.active);
+    assert!(text_input.rect.is_none());
+}
+
+#[derive(Default)]
+struct TextReceiverPair {
+    first: Widget<TextReceiver>,
+    second: Widget<TextReceiver>,
+}
+
+impl widget::Content<NoGraphics> for TextReceiverPair {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.child(|pair| &mut pair.first);
+        desc.child(|pair| &mut pair.second);
+    }
+}
+
+#[test]
+fn text_input_stopped_only_by_owner() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let mut pair = Widget::<TextReceiverPair>::default();
+    pair.second.start = Key::Character('s');
+    pair.second.stop = Key::Character('x');
+    app.add_root(pair);
+    app.key_event(KeyEvent::new(KeyAction::Down, Key::Enter));
+    app.key_event(KeyEvent::new(KeyAction::Down, Key::Character('s')));
+    // the first receiver no longer owns text input, so can not stop it
+    app.key_event(KeyEvent::new(KeyAction::Down, Key::Escape));
+    assert!(app.state().text_input().get_unwatched().active);
+    app.key_event(KeyEvent::new(KeyAction::Down, Key::Character('x')));
+    assert!(!app.state().text_input().get_unwatched().active);
+}
@@ -0,0 +1,310 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::Cell, rc::Rc};
+
+use suzy::{
+    app::{App, Layer, Overlay, TestingExt},
+    dims::{Padding2d, Rect},
+    keyboard::{Key, KeyAction, KeyChord, KeyEvent, TextEvent},
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerEvent},
+    shortcut::Shortcut,
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+mod utils;
+use utils::*;
+
+/// A widget which counts the presses it receives.
+#[derive(Default)]
+struct Panel {
+    presses: Rc<Cell<u32>>,
+}
+
+fn count_press(
+    presses: &Cell<u32>,
+    rect: &WidgetRect,
+    event: &PointerEvent<'_>,
+) -> bool {
+    let pressed = matches!(event.action(), PointerAction::Down)
+        && rect.contains(event.pos());
+    if pressed {
+        presses.set(presses.get() + 1);
+    }
+    pressed
+}
+
+impl widget::Content<NoGraphics> for Panel {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        count_press(&self.presses, rect, event)
+    }
+}
+
+/// A focusable widget which counts the key and text events it receives,
+/// without handling them.
+#[derive(Default)]
+struct Field {
+    handle: UniqueHandle,
+    events: Rc<Cell<u32>>,
+}
+
+impl widget::Content<NoGraphics> for Field {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|field, _rect| {
+            field.handle.set_focusable(true);
+        });
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, _event: &KeyEvent) -> bool {
+        self.events.set(self.events.get() + 1);
+        false
+    }
+
+    fn text_event(&mut self, _rect: &WidgetRect, _event: &TextEvent) -> bool {
+        self.events.set(self.events.get() + 1);
+        false
+    }
+}
+
+/// A modal dialog which counts the presses it receives, and shows a popup
+/// of its own when a key is pressed.
+#[derive(Default)]
+struct Dialog {
+    presses: Rc<Cell<u32>>,
+    popup: Overlay<Panel>,
+}
+
+impl widget::Content<NoGraphics> for Dialog {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|dialog, _rect| {
+            let mut popup = dialog.popup.widget_mut();
+            popup.set_width(100.0);
+            popup.set_height(100.0);
+            popup.set_center([380.0, 60.0]);
+        });
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        count_press(&self.presses, rect, event)
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        let show = (event.action, event.key)
+            == (KeyAction::Down, Key::Character('p'));
+        if show {
+            self.popup.show::<NoGraphics>();
+        }
+        show
+    }
+}
+
+/// A root which fills the window and counts the presses it receives,
+/// showing an overlay in each layer when a key is pressed.  It also counts
+/// the triggers of a global shortcut.
+struct Root {
+    panel: Widget<Panel>,
+    field: Widget<Field>,
+    popup: Overlay<Panel>,
+    modal: Overlay<Dialog>,
+    tooltip: Overlay<Panel>,
+    refresh: Shortcut,
+    refreshes: Rc<Cell<u32>>,
+}
+
+impl Default for Root {
+    fn default() -> Self {
+        Self {
+            panel: Widget::default(),
+            field: Widget::default(),
+            popup: Overlay::new(Layer::Popup),
+            modal: Overlay::new(Layer::Modal),
+            tooltip: Overlay::new(Layer::Tooltip),
+            refresh: Shortcut::new(),
+            refreshes: Rc::default(),
+        }
+    }
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, rect| {
+            root.panel.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|root, _rect| {
+            let mut popup = root.popup.widget_mut();
+            popup.set_width(100.0);
+            popup.set_height(100.0);
+            popup.set_center([100.0, 100.0]);
+            let mut modal = root.modal.widget_mut();
+            modal.set_width(200.0);
+            modal.set_height(200.0);
+            modal.set_center([240.0, 180.0]);
+            let mut tooltip = root.tooltip.widget_mut();
+            tooltip.set_width(100.0);
+            tooltip.set_height(100.0);
+            tooltip.set_center([400.0, 300.0]);
+        });
+        desc.watch(|root, _rect| {
+            root.refresh.register(KeyChord::new(Key::F(5)));
+        });
+        desc.watch(|root, _rect| {
+            let refreshes = &root.refreshes;
+            root.refresh
+                .on_trigger(|| refreshes.set(refreshes.get() + 1));
+        });
+        desc.child(|root| &mut root.panel);
+        desc.child(|root| &mut root.field);
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        match (event.action, event.key) {
+            (KeyAction::Down, Key::Character('p')) => {
+                self.popup.show::<NoGraphics>();
+            }
+            (KeyAction::Down, Key::Character('m')) => {
+                self.modal.show::<NoGraphics>();
+            }
+            (KeyAction::Down, Key::Character('t')) => {
+                self.tooltip.show::<NoGraphics>();
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+struct Presses {
+    root: Rc<Cell<u32>>,
+    popup: Rc<Cell<u32>>,
+    modal: Rc<Cell<u32>>,
+    tooltip: Rc<Cell<u32>>,
+    modal_popup: Rc<Cell<u32>>,
+    field: Rc<Cell<u32>>,
+    refreshes: Rc<Cell<u32>>,
+}
+
+impl Presses {
+    fn get(&self) -> [u32; 4] {
+        [&self.root, &self.popup, &self.modal, &self.tooltip].map(|c| c.get())
+    }
+}
+
+fn setup() -> (App<NoGraphics>, Presses) {
+    let root = Widget::<Root>::default();
+    let presses = Presses {
+        root: Rc::clone(&root.panel.presses),
+        popup: Rc::clone(&root.popup.widget().presses),
+        modal: Rc::clone(&root.modal.widget().presses),
+        tooltip: Rc::clone(&root.tooltip.widget().presses),
+        modal_popup: Rc::clone(&root.modal.widget().popup.widget().presses),
+        field: Rc::clone(&root.field.events),
+        refreshes: Rc::clone(&root.refreshes),
+    };
+    (app_with_root(root), presses)
+}
+
+fn show(app: &mut App<NoGraphics>, ch: char) {
+    key(app, Key::Character(ch));
+}
+
+#[test]
+fn popup_dismissed_by_outside_press() {
+    let (mut app, presses) = setup();
+    show(&mut app, 'p');
+    app.mouse_click([100.0, 100.0]);
+    assert_eq!(presses.get(), [0, 1, 0, 0]);
+    // the press which dismisses the popup does not reach the root
+    app.mouse_click([300.0, 300.0]);
+    assert_eq!(presses.get(), [0, 1, 0, 0]);
+    app.mouse_click([100.0, 100.0]);
+    assert_eq!(presses.get(), [1, 1, 0, 0]);
+}
+
+#[test]
+fn modal_blocks_layers_below() {
+    let (mut app, presses) = setup();
+    show(&mut app, 'm');
+    // presses outside of the modal are handled, but reach no widget
+    assert!(mouse(&mut app, PointerAction::Down, [20.0, 20.0]));
+    mouse(&mut app, PointerAction::Up, [20.0, 20.0]);
+    app.mouse_click([240.0, 180.0]);
+    assert_eq!(presses.get(), [0, 0, 1, 0]);
+    // a popup shown while the modal is shown is drawn above it
+    show(&mut app, 'p');
+    app.mouse_click([335.0, 95.0]);
+    assert_eq!(presses.modal_popup.get(), 1);
+    app.mouse_click([240.0, 180.0]);
+    app.mouse_click([240.0, 180.0]);
+    assert_eq!(presses.get(), [0, 0, 2, 0]);
+}
+
+#[test]
+fn tooltip_ignores_pointer() {
+    let (mut app, presses) = setup();
+    show(&mut app, 't');
+    app.mouse_click([400.0, 300.0]);
+    assert_eq!(presses.get(), [1, 0, 0, 0]);
+}
+
+#[test]
+fn modal_takes_keys_and_focus() {
+    let (mut app, presses) = setup();
+    assert!(app.focus_next());
+    let type_x = |app: &mut App<NoGraphics>| {
+        app.key_event(KeyEvent::new(KeyAction::Down, Key::Character('x')));
+        app.text_event(TextEvent::Input("x".to_string()));
+        app.update_watches();
+    };
+    type_x(&mut app);
+    assert_eq!(presses.field.get(), 2);
+    // the field is below the modal, so it loses focus, can't get it back,
+    // and no longer receives keys or text
+    show(&mut app, 'm');
+    assert!(app.focused().is_none());
+    assert!(!app.focus_next());
+    let received = presses.field.get();
+    type_x(&mut app);
+    assert_eq!(presses.field.get(), received);
+}
+
+#[test]
+fn modal_blocks_global_shortcuts() {
+    let (mut app, presses) = setup();
+    assert!(key(&mut app, Key::F(5)));
+    assert_eq!(presses.refreshes.get(), 1);
+    show(&mut app, 'm');
+    assert!(!key(&mut app, Key::F(5)));
+    assert_eq!(presses.refreshes.get(), 1);
+}
+
+#[test]
+fn remove_root() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let root = Widget::<Panel>::default();
+    let presses = Rc::clone(&root.presses);
+    let id = app.add_root(root);
+    app.update_watches();
+    app.mouse_click([240.0, 180.0]);
+    assert_eq!(presses.get(), 1);
+    assert!(app.remove_root(&id));
+    assert!(!app.remove_root(&id));
+    app.mouse_click([240.0, 180.0]);
+    assert_eq!(presses.get(), 1);
+    // the widget is dropped along with the app's reference to it
+    assert_eq!(Rc::strong_count(&presses), 1);
+}
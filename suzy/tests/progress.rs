@@ -0,0 +1,135 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+#![cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
+
+use std::time::Duration;
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::{Padding, Rect},
+    graphics::Color,
+    platforms::{opengl::OpenGlRenderPlatform, TEST_ENV},
+    widget::{self, Widget},
+    widgets::{ProgressBar, ProgressCircle},
+};
+
+mod utils;
+use utils::*;
+
+#[derive(Default)]
+struct Root {
+    bar: ProgressBar,
+    circle: ProgressCircle,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.bar.set_fill_width(rect, Padding::zero());
+            root.bar.set_height(20.0);
+            root.bar.set_bottom(rect.bottom());
+            *root.bar.color = Color::WHITE;
+            root.circle.set_width(100.0);
+            root.circle.set_height(100.0);
+            root.circle.set_center(rect.center());
+        });
+        desc.child(|root| &mut root.bar);
+        desc.child(|root| &mut root.circle);
+    }
+}
+
+/// Check that the area around a pixel is close to a color.
+fn is_near_at(capture: &[u8], [x, y]: [usize; 2], expected: Color) -> bool {
+    region(capture, 480, (x - 1)..(x + 2), (y - 1)..(y + 2))
+        .into_iter()
+        .all(|color| is_near(color, expected))
+}
+
+/// Points in the middle of the circle's ring, 47 pixels from its center,
+/// in each diagonal direction.
+const UPPER_RIGHT: [usize; 2] = [273, 213];
+const LOWER_RIGHT: [usize; 2] = [273, 146];
+const LOWER_LEFT: [usize; 2] = [206, 146];
+const UPPER_LEFT: [usize; 2] = [206, 213];
+
+fn settled(progress: f32) -> Box<[u8]> {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let mut root = Widget::<Root>::default();
+    *root.bar.progress = progress;
+    *root.circle.progress = progress;
+    app.add_root(root);
+    app.update_watches();
+    // let the indicators move smoothly to their new progress
+    for _ in 0..60 {
+        app.next_frame(Duration::from_millis(16));
+    }
+    window.draw_and_take_screenshot(&mut app)
+}
+
+#[test]
+fn progress_bar_fills() {
+    let capture = settled(0.5);
+    // the middle row of the bar
+    let row = &capture[(480 * 4 * 10)..(480 * 4 * 11)];
+    let (left, right) = row.split_at(240 * 4);
+    assert!(is_color(round_both(left), Color::WHITE));
+    assert!(!is_color(round_front(right), Color::WHITE));
+}
+
+#[test]
+fn progress_circle_arc() {
+    let capture = settled(0.75);
+    // the arc goes clockwise from the top, leaving the upper left quarter
+    // of the ring unfilled
+    let fill = Color::MEDIUM_SLATE_BLUE;
+    assert!(is_near_at(&capture, UPPER_RIGHT, fill));
+    assert!(is_near_at(&capture, LOWER_RIGHT, fill));
+    assert!(is_near_at(&capture, LOWER_LEFT, fill));
+    assert!(is_near_at(&capture, UPPER_LEFT, Color::LIGHT_GRAY));
+    // the middle of the ring is empty
+    assert!(is_near_at(&capture, [240, 180], Color::BLACK));
+}
+
+#[test]
+fn progress_full_shapes() {
+    let capture = settled(1.0);
+    // a complete arc is drawn as a whole circle
+    for point in [UPPER_RIGHT, LOWER_RIGHT, LOWER_LEFT, UPPER_LEFT] {
+        assert!(is_near_at(&capture, point, Color::MEDIUM_SLATE_BLUE));
+    }
+    assert!(is_near_at(&capture, [240, 180], Color::BLACK));
+    assert!(is_near_at(&capture, [240, 232], Color::BLACK));
+    // the rounded ends of the bar leave its corners empty
+    assert!(is_near_at(&capture, [10, 10], Color::WHITE));
+    assert!(is_near_at(&capture, [469, 10], Color::WHITE));
+    let corner = region(&capture, 480, 0..2, 0..2);
+    assert!(corner.into_iter().all(is_dark));
+    let corner = region(&capture, 480, 478..480, 18..20);
+    assert!(corner.into_iter().all(is_dark));
+}
+
+#[test]
+fn progress_indeterminate() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let mut root = Widget::<Root>::default();
+    *root.bar.indeterminate = true;
+    *root.circle.indeterminate = true;
+    app.add_root(root);
+    app.update_watches();
+    for _ in 0..30 {
+        app.next_frame(Duration::from_millis(16));
+    }
+    let capture = window.draw_and_take_screenshot(&mut app);
+    // about a third of the way through the cycle, the filled part of the
+    // bar is a little way in from the start
+    assert!(is_near_at(&capture, [128, 10], Color::WHITE));
+    assert!(is_near_at(&capture, [30, 10], Color::LIGHT_GRAY));
+    assert!(is_near_at(&capture, [300, 10], Color::LIGHT_GRAY));
+    // and the arc has turned past the bottom of the circle
+    let fill = Color::MEDIUM_SLATE_BLUE;
+    assert!(is_near_at(&capture, [197, 160], fill));
+    assert!(is_near_at(&capture, [282, 199], Color::LIGHT_GRAY));
+}
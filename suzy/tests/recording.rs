@@ -0,0 +1,224 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use suzy::{
+    app::{self, App, RecordedEvent, Recording, TestingExt},
+    dims::Rect,
+    keyboard::{Key, KeyAction, KeyEvent, Modifiers, ScanCode, TextEvent},
+    navigation::{Direction, NavAction},
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerEvent, PointerEventData, PointerId},
+    widget::{self, Widget, WidgetRect},
+};
+
+mod utils;
+use utils::*;
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+/// Logs the pointer, key and text events it receives and its size, along
+/// with the time since the first pointer event.
+#[derive(Default)]
+struct Logger {
+    start: Option<std::time::Instant>,
+    log: Log,
+}
+
+impl Logger {
+    fn elapsed(&mut self) -> u128 {
+        let now = app::time_unwatched();
+        (now - *self.start.get_or_insert(now)).as_millis()
+    }
+}
+
+impl widget::Content<NoGraphics> for Logger {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, rect| {
+            let entry = format!("size {}x{}", rect.width(), rect.height());
+            this.log.borrow_mut().push(entry);
+        });
+    }
+
+    fn pointer_event(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        let entry = format!(
+            "{}ms {:?} {:?} {:?}",
+            self.elapsed(),
+            event.id(),
+            event.action(),
+            event.pos(),
+        );
+        self.log.borrow_mut().push(entry);
+        true
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        self.log.borrow_mut().push(format!("{event:?}"));
+        true
+    }
+
+    fn text_event(&mut self, _rect: &WidgetRect, event: &TextEvent) -> bool {
+        self.log.borrow_mut().push(format!("{event:?}"));
+        true
+    }
+}
+
+fn setup() -> (App<NoGraphics>, Log) {
+    let log = Log::default();
+    let mut logger = Widget::<Logger>::default();
+    logger.log = Rc::clone(&log);
+    (app_with_root(logger), log)
+}
+
+#[test]
+fn record_and_replay() {
+    let (mut app, log) = setup();
+    app.start_recording();
+    app.next_frame(Duration::from_millis(10));
+    app.pointer_event(PointerEventData::new(
+        PointerId::Touch(3),
+        PointerAction::Down,
+        10.5,
+        20.25,
+    ));
+    app.next_frame(Duration::from_millis(25));
+    let modifiers = Modifiers {
+        shift: true,
+        logo: true,
+        ..Modifiers::NONE
+    };
+    app.pointer_event(
+        PointerEventData::new(
+            PointerId::Touch(3),
+            PointerAction::Move(0.1, -3.0),
+            10.6,
+            17.25,
+        )
+        .with_modifiers(modifiers)
+        .with_click_count(2)
+        .with_pressure(0.75)
+        .with_tilt([-10.0, 12.5])
+        .with_timestamp(Duration::from_millis(1234)),
+    );
+    app.resize(640.0, 480.0);
+    app.next_frame(Duration::from_millis(40));
+    app.pointer_leave(PointerId::Mouse);
+    app.update_dpi([144.0, 144.0]);
+    app.mouse_click([100.0, 100.0]);
+    app.update_watches();
+    let recording = app.stop_recording().expect("app was not recording");
+    assert!(!app.is_recording());
+    assert_eq!(recording.events().len(), 11);
+    assert!(matches!(
+        recording.events()[0],
+        RecordedEvent::Frame(duration) if duration.as_millis() == 10
+    ));
+
+    let mut file = Vec::new();
+    recording
+        .write_to(&mut file)
+        .expect("failed to write recording");
+    let loaded =
+        Recording::read_from(&file[..]).expect("failed to read recording");
+    let mut again = Vec::new();
+    loaded
+        .write_to(&mut again)
+        .expect("failed to write recording");
+    assert_eq!(file, again);
+    match &loaded.events()[3] {
+        RecordedEvent::Pointer(data) => {
+            assert_eq!(data.modifiers, modifiers);
+            assert_eq!(data.click_count, 2);
+            assert_eq!(data.pressure, Some(0.75));
+            assert_eq!(data.tilt, Some([-10.0, 12.5]));
+            assert_eq!(data.timestamp, Some(Duration::from_millis(1234)));
+        }
+        other => panic!("expected a pointer event, found {:?}", other),
+    }
+
+    let (mut replay_app, replay_log) = setup();
+    replay_app.replay(&loaded);
+    assert_eq!(*replay_log.borrow(), *log.borrow());
+    assert!(log.borrow().contains(&"size 640x480".to_string()));
+}
+
+#[test]
+fn record_and_replay_typing() {
+    let (mut app, log) = setup();
+    app.start_recording();
+    let ctrl = Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    };
+    app.key_event(
+        KeyEvent::new(KeyAction::Down, Key::Character('a'))
+            .with_modifiers(ctrl)
+            .with_scancode(ScanCode(4)),
+    );
+    app.key_event(KeyEvent::new(KeyAction::Up, Key::Character('a')));
+    app.text_event(TextEvent::Editing {
+        text: "に".to_string(),
+        cursor: 3..3,
+    });
+    app.text_event(TextEvent::Input("にほ 100%\n".to_string()));
+    app.text_event(TextEvent::Editing {
+        text: String::new(),
+        cursor: 0..0,
+    });
+    app.key_event(
+        KeyEvent::new(KeyAction::Down, Key::Character(' ')).with_repeat(true),
+    );
+    app.key_event(KeyEvent::new(KeyAction::Down, Key::F(12)));
+    app.nav_event(NavAction::Navigate(Direction::Left), KeyAction::Down);
+    app.nav_event(NavAction::Back, KeyAction::Up);
+    let recording = app.stop_recording().expect("app was not recording");
+    assert_eq!(recording.events().len(), 9);
+
+    let mut file = Vec::new();
+    recording
+        .write_to(&mut file)
+        .expect("failed to write recording");
+    let loaded =
+        Recording::read_from(&file[..]).expect("failed to read recording");
+    let mut again = Vec::new();
+    loaded
+        .write_to(&mut again)
+        .expect("failed to write recording");
+    assert_eq!(file, again);
+    match &loaded.events()[3] {
+        RecordedEvent::Text(TextEvent::Input(text)) => {
+            assert_eq!(text, "にほ 100%\n");
+        }
+        other => panic!("expected a text event, found {:?}", other),
+    }
+
+    let (mut replay_app, replay_log) = setup();
+    replay_app.replay(&loaded);
+    assert_eq!(*replay_log.borrow(), *log.borrow());
+    // navigation actions are delivered as their equivalent keys
+    assert_eq!(
+        log.borrow().last(),
+        Some(&format!("{:?}", KeyEvent::new(KeyAction::Up, Key::Escape))),
+    );
+}
+
+#[test]
+fn invalid_recordings() {
+    let read = |text: &str| Recording::read_from(text.as_bytes());
+    assert!(read("").is_err());
+    assert!(read("not a recording\n").is_err());
+    assert!(read("suzy-recording 1\nz\n").is_err());
+    assert!(read("suzy-recording 1\np mouse 1 2\n").is_err());
+    assert!(read("suzy-recording 1\nf 10 20\n").is_err());
+    assert!(read("suzy-recording 1\np mouse 1 2 up mods=meta\n").is_err());
+    assert!(read("suzy-recording 1\nk down char =ab\n").is_err());
+    assert!(read("suzy-recording 1\nt input =%E3%81\n").is_err());
+    assert!(read("suzy-recording 1\nt input no-prefix\n").is_err());
+    let empty = read("suzy-recording 1\n\n").expect("empty recording");
+    assert!(empty.events().is_empty());
+}
@@ -0,0 +1,194 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+#![cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
+
+use std::{cell::Cell, rc::Rc};
+
+use suzy::{
+    app::{App, WidgetHit},
+    dims::{Padding, Padding2d, Rect, SimpleRect},
+    platforms::{
+        opengl::{OpenGlRenderPlatform, SlicedImage},
+        TEST_ENV,
+    },
+    pointer::{PointerAction, PointerEvent},
+    widget::{self, Widget, WidgetRect},
+    widgets::ScrollView,
+};
+
+mod utils;
+use utils::*;
+
+/// A widget which counts the presses it receives.
+#[derive(Default)]
+struct Row {
+    presses: Rc<Cell<u32>>,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Row {
+    fn desc(_desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {}
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down if rect.contains(event.pos()) => {
+                self.presses.set(self.presses.get() + 1);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Form {
+    background: SlicedImage,
+    last_row: Widget<Row>,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Form {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|form, rect| {
+            form.background.set_fill(rect, &Padding2d::zero());
+            form.last_row.set_fill_width(rect, Padding::zero());
+            form.last_row.set_height(50.0);
+            form.last_row.set_bottom(rect.bottom());
+        });
+        desc.graphic(|form| &mut form.background);
+        desc.child(|form| &mut form.last_row);
+    }
+}
+
+#[derive(Default)]
+struct Root {
+    scroll: ScrollView<Form>,
+    show_last_row: bool,
+    offset: Rc<Cell<[f32; 2]>>,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.scroll.set_width(200.0);
+            root.scroll.set_height(200.0);
+            root.scroll.set_center(rect.center());
+            root.scroll.content_mut().set_height(600.0);
+        });
+        desc.watch(|root, _rect| {
+            root.offset.set(root.scroll.scroll_offset());
+        });
+        desc.watch(|root, _rect| {
+            if root.show_last_row {
+                let row = SimpleRect::from(&root.scroll.content().last_row);
+                root.scroll.scroll_to(&row);
+            }
+        });
+        desc.child(|root| &mut root.scroll);
+    }
+}
+
+#[test]
+fn scrollview_wheel() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let offset = Rc::<Cell<[f32; 2]>>::default();
+    let mut root = Widget::<Root>::default();
+    root.offset = Rc::clone(&offset);
+    app.add_root(root);
+    app.update_watches();
+    assert!(mouse(
+        &mut app,
+        PointerAction::Wheel(0.0, 50.0),
+        [240.0, 180.0]
+    ));
+    assert_eq!(offset.get(), [0.0, 50.0]);
+    // vertical views ignore horizontal scrolling
+    assert!(!mouse(
+        &mut app,
+        PointerAction::Wheel(50.0, 0.0),
+        [240.0, 180.0]
+    ));
+    // the view stops at the end of the content, and lets an enclosing
+    // widget handle further scrolling
+    assert!(mouse(
+        &mut app,
+        PointerAction::Wheel(0.0, 1000.0),
+        [240.0, 180.0]
+    ));
+    assert_eq!(offset.get(), [0.0, 400.0]);
+    assert!(!mouse(
+        &mut app,
+        PointerAction::Wheel(0.0, 50.0),
+        [240.0, 180.0]
+    ));
+    // wheel events outside the view are ignored
+    assert!(!mouse(
+        &mut app,
+        PointerAction::Wheel(0.0, -50.0),
+        [20.0, 20.0]
+    ));
+    let _capture = window.draw_and_take_screenshot(&mut app);
+}
+
+#[test]
+fn scrollview_drag_scrollbar() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let offset = Rc::<Cell<[f32; 2]>>::default();
+    let mut root = Widget::<Root>::default();
+    root.offset = Rc::clone(&offset);
+    root.scroll.set_auto_hide(false);
+    app.add_root(root);
+    app.update_watches();
+    // the thumb starts at the top of the track, along the right edge
+    assert!(mouse(&mut app, PointerAction::Down, [335.0, 270.0]));
+    mouse(&mut app, PointerAction::Move(0.0, -300.0), [335.0, -30.0]);
+    assert_eq!(offset.get(), [0.0, 400.0]);
+    mouse(&mut app, PointerAction::Move(0.0, 100.0), [335.0, 70.0]);
+    let [_, y] = offset.get();
+    assert!(y > 0.0 && y < 400.0);
+    mouse(&mut app, PointerAction::Up, [335.0, 70.0]);
+    let _capture = window.draw_and_take_screenshot(&mut app);
+}
+
+#[test]
+fn scrollview_scroll_to() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let offset = Rc::<Cell<[f32; 2]>>::default();
+    let mut root = Widget::<Root>::default();
+    root.offset = Rc::clone(&offset);
+    root.show_last_row = true;
+    app.add_root(root);
+    app.update_watches();
+    assert_eq!(offset.get(), [0.0, 400.0]);
+    let _capture = window.draw_and_take_screenshot(&mut app);
+}
+
+#[test]
+fn scrollview_clips_pointer() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let root = Widget::<Root>::default();
+    let presses = Rc::clone(&root.scroll.content().last_row.presses);
+    app.add_root(root);
+    app.update_watches();
+    let is_row = |hit: &WidgetHit| hit.type_name.ends_with("::Row");
+    // the last row is now just below the view, where the mask hides it
+    mouse(&mut app, PointerAction::Wheel(0.0, 340.0), [240.0, 180.0]);
+    assert!(!mouse(&mut app, PointerAction::Down, [240.0, 45.0]));
+    mouse(&mut app, PointerAction::Up, [240.0, 45.0]);
+    assert_eq!(presses.get(), 0);
+    assert!(!app.widgets_at([240.0, 45.0]).iter().any(is_row));
+    // once it is scrolled into view, it can be pressed
+    mouse(&mut app, PointerAction::Wheel(0.0, 60.0), [240.0, 180.0]);
+    assert!(mouse(&mut app, PointerAction::Down, [240.0, 105.0]));
+    mouse(&mut app, PointerAction::Up, [240.0, 105.0]);
+    assert_eq!(presses.get(), 1);
+    assert!(app.widgets_at([240.0, 105.0]).iter().any(is_row));
+    let _capture = window.draw_and_take_screenshot(&mut app);
+}
@@ -0,0 +1,169 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::RefCell, rc::Rc};
+
+use suzy::{
+    app::App,
+    dims::Rect,
+    keyboard::{Key, KeyAction, KeyChord, KeyEvent},
+    platforms::no_graphics::NoGraphics,
+    shortcut::Shortcut,
+    widget::{self, UniqueHandle, Widget, WidgetRect},
+};
+
+mod utils;
+use utils::*;
+
+type Log = Rc<RefCell<Vec<&'static str>>>;
+
+const SAVE: KeyChord = KeyChord::new(Key::Character('s')).ctrl();
+const REFRESH: KeyChord = KeyChord::new(Key::F(5));
+
+#[derive(Default)]
+struct Leaf {
+    handle: UniqueHandle,
+    claims: Option<KeyChord>,
+    log: Log,
+}
+
+impl widget::Content<NoGraphics> for Leaf {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, _rect| this.handle.set_focusable(true));
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        let claimed = self.handle.is_focused()
+            && self.claims.is_some_and(|chord| chord.matches(event));
+        if claimed {
+            self.log.borrow_mut().push("leaf");
+        }
+        claimed
+    }
+}
+
+#[derive(Default)]
+struct Pane {
+    name: &'static str,
+    handle: UniqueHandle,
+    save: Shortcut,
+    leaf: Widget<Leaf>,
+    log: Log,
+}
+
+impl widget::Content<NoGraphics> for Pane {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, rect| {
+            this.leaf.set_fill(rect, &Default::default());
+        });
+        desc.watch(|this, _rect| {
+            this.save.register_scoped(SAVE, &this.handle);
+        });
+        desc.watch(|this, _rect| {
+            let Self {
+                name, save, log, ..
+            } = this;
+            save.on_trigger(|| log.borrow_mut().push(name));
+        });
+        desc.child(|this| &mut this.leaf);
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+}
+
+#[derive(Default)]
+struct Root {
+    save: Shortcut,
+    refresh: Shortcut,
+    first: Widget<Pane>,
+    second: Widget<Pane>,
+    log: Log,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, _rect| {
+            root.save.register(SAVE);
+            root.refresh.register(REFRESH);
+        });
+        desc.watch(|root, _rect| {
+            let log = &root.log;
+            root.save
+                .on_trigger(|| log.borrow_mut().push("global save"));
+        });
+        desc.watch(|root, _rect| {
+            let log = &root.log;
+            root.refresh.on_trigger(|| log.borrow_mut().push("refresh"));
+        });
+        desc.child(|root| &mut root.first);
+        desc.child(|root| &mut root.second);
+    }
+}
+
+fn press(app: &mut App<NoGraphics>, chord: KeyChord) -> bool {
+    key_with(app, chord.key, chord.modifiers)
+}
+
+#[test]
+fn shortcuts_resolve_from_focus_outward() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let log = Log::default();
+    let mut root = Widget::<Root>::default();
+    root.log = Rc::clone(&log);
+    root.first.name = "first save";
+    root.first.log = Rc::clone(&log);
+    root.second.name = "second save";
+    root.second.log = Rc::clone(&log);
+    let first_leaf = root.first.leaf.handle.id();
+    app.add_root(root);
+    app.update_watches();
+    // with nothing focused, only global shortcuts apply
+    assert!(press(&mut app, SAVE));
+    assert!(press(&mut app, REFRESH));
+    assert_eq!(log.take(), ["global save", "refresh"]);
+    // the chord must match exactly
+    assert!(!press(&mut app, REFRESH.shift()));
+    assert!(!press(&mut app, KeyChord::new(Key::Character('s'))));
+    assert!(log.take().is_empty());
+    // focus a widget within the first pane
+    assert!(app.focus_next());
+    assert!(app.focused() == Some(first_leaf));
+    assert!(press(&mut app, SAVE));
+    assert!(press(&mut app, REFRESH));
+    assert_eq!(log.take(), ["first save", "refresh"]);
+    assert!(app.focus_next());
+    assert!(press(&mut app, SAVE));
+    assert_eq!(log.take(), ["second save"]);
+    // repeats do not trigger shortcuts
+    let repeat = KeyEvent::new(KeyAction::Down, Key::F(5)).with_repeat(true);
+    assert!(!app.key_event(repeat));
+}
+
+#[test]
+fn focused_widget_takes_precedence() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let log = Log::default();
+    let mut root = Widget::<Root>::default();
+    root.log = Rc::clone(&log);
+    root.first.name = "first save";
+    root.first.log = Rc::clone(&log);
+    root.first.leaf.claims = Some(SAVE);
+    root.first.leaf.log = Rc::clone(&log);
+    app.add_root(root);
+    app.update_watches();
+    // the focused widget handles the chord before any shortcut
+    assert!(app.focus_next());
+    assert!(press(&mut app, SAVE));
+    assert!(press(&mut app, REFRESH));
+    assert_eq!(log.take(), ["leaf", "refresh"]);
+    // without focus, it does not claim the chord
+    app.clear_focus();
+    assert!(press(&mut app, SAVE));
+    assert_eq!(log.take(), ["global save"]);
+}
@@ -0,0 +1,138 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{cell::RefCell, rc::Rc};
+
+use suzy::{
+    app::App,
+    dims::{Padding2d, Rect},
+    keyboard::Key,
+    platforms::no_graphics::NoGraphics,
+    pointer::PointerAction,
+    selectable::{Selectable, SelectionState},
+    widget::{self, Widget},
+    widgets::{Orientation, RangeSlider, Slider, SliderContent},
+};
+
+mod utils;
+use utils::*;
+
+type Log = Rc<RefCell<Vec<f32>>>;
+
+/// Records where the slider places its handles.
+#[derive(Default)]
+struct Handles {
+    handles: Log,
+}
+
+impl Selectable for Handles {
+    fn selection_changed(&mut self, _state: SelectionState) {}
+}
+
+impl SliderContent for Handles {
+    fn handles_changed(&mut self, _orientation: Orientation, handles: &[f32]) {
+        *self.handles.borrow_mut() = handles.to_vec();
+    }
+}
+
+impl widget::Content<NoGraphics> for Handles {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+}
+
+#[derive(Default)]
+struct Root {
+    slider: Slider<Handles>,
+    range: RangeSlider<Handles>,
+    values: Log,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, rect| {
+            // the slider runs horizontally along the bottom half of the
+            // window's left 400 pixels, the range slider vertically along
+            // the top half
+            root.slider
+                .set_fill(rect, &Padding2d::new(180.0, 80.0, 0.0, 0.0));
+            root.range
+                .set_fill(rect, &Padding2d::new(0.0, 80.0, 180.0, 0.0));
+        });
+        desc.watch(|root, _rect| {
+            let [low, high] = root.range.range();
+            *root.values.borrow_mut() = vec![root.slider.value(), low, high];
+        });
+        desc.child(|root| &mut root.slider);
+        desc.child(|root| &mut root.range);
+    }
+}
+
+fn setup() -> (App<NoGraphics>, Log, Log) {
+    let values = Log::default();
+    let handles = Log::default();
+    let mut root = Widget::<Root>::default();
+    root.values = Rc::clone(&values);
+    root.slider.content_mut().handles = Rc::clone(&handles);
+    root.slider.set_bounds(0.0, 100.0);
+    root.slider.set_step(10.0);
+    root.range.set_orientation(Orientation::Vertical);
+    root.range.set_bounds(0.0, 180.0);
+    root.range.set_range(30.0, 150.0);
+    (app_with_root(root), values, handles)
+}
+
+#[test]
+fn slider_drag() {
+    let (mut app, values, handles) = setup();
+    assert_eq!(*values.borrow(), [0.0, 30.0, 150.0]);
+    mouse(&mut app, PointerAction::Down, [200.0, 90.0]);
+    assert_eq!(values.borrow()[0], 50.0);
+    assert_eq!(*handles.borrow(), [0.5]);
+    // the pointer keeps dragging the slider after leaving it
+    mouse(&mut app, PointerAction::Move(50.0, 200.0), [250.0, 290.0]);
+    assert_eq!(values.borrow()[0], 60.0);
+    mouse(&mut app, PointerAction::Move(500.0, 0.0), [750.0, 290.0]);
+    assert_eq!(values.borrow()[0], 100.0);
+    mouse(&mut app, PointerAction::Up, [750.0, 290.0]);
+    mouse(&mut app, PointerAction::Move(-700.0, 0.0), [50.0, 290.0]);
+    assert_eq!(values.borrow()[0], 100.0);
+}
+
+#[test]
+fn slider_keys() {
+    let (mut app, values, _handles) = setup();
+    // unfocused sliders ignore keys
+    key(&mut app, Key::ArrowRight);
+    assert_eq!(values.borrow()[0], 0.0);
+    mouse(&mut app, PointerAction::Down, [0.0, 90.0]);
+    mouse(&mut app, PointerAction::Up, [0.0, 90.0]);
+    key(&mut app, Key::ArrowRight);
+    key(&mut app, Key::ArrowUp);
+    assert_eq!(values.borrow()[0], 20.0);
+    key(&mut app, Key::ArrowLeft);
+    assert_eq!(values.borrow()[0], 10.0);
+    key(&mut app, Key::PageUp);
+    assert_eq!(values.borrow()[0], 100.0);
+    key(&mut app, Key::Home);
+    assert_eq!(values.borrow()[0], 0.0);
+    key(&mut app, Key::End);
+    assert_eq!(values.borrow()[0], 100.0);
+}
+
+#[test]
+fn range_slider() {
+    let (mut app, values, _handles) = setup();
+    // pressing nearer the high handle drags it
+    mouse(&mut app, PointerAction::Down, [200.0, 320.0]);
+    assert_eq!(values.borrow()[1..], [30.0, 140.0]);
+    // it can't be dragged past the low handle
+    mouse(&mut app, PointerAction::Move(0.0, -200.0), [200.0, 120.0]);
+    assert_eq!(values.borrow()[1..], [30.0, 30.0]);
+    mouse(&mut app, PointerAction::Up, [200.0, 120.0]);
+    // with the handles together, the pointer's direction decides
+    mouse(&mut app, PointerAction::Down, [200.0, 190.0]);
+    assert_eq!(values.borrow()[1..], [10.0, 30.0]);
+    mouse(&mut app, PointerAction::Up, [200.0, 190.0]);
+    // keys move the handle which was dragged last
+    key(&mut app, Key::End);
+    assert_eq!(values.borrow()[1..], [30.0, 30.0]);
+}
@@ -0,0 +1,223 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+#![cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    rc::Rc,
+    time::Duration,
+};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::{Padding2d, Rect},
+    keyboard::{Key, KeyAction, KeyEvent},
+    platforms::{opengl::OpenGlRenderPlatform, TEST_ENV},
+    pointer::{PointerAction, PointerEvent},
+    widget::{self, Widget, WidgetRect},
+    widgets::TabView,
+};
+
+mod utils;
+use utils::*;
+
+/// What the pages of a tab view have seen.
+#[derive(Default)]
+struct Log {
+    presses: RefCell<Vec<usize>>,
+    lefts: RefCell<BTreeMap<usize, f32>>,
+}
+
+#[derive(Default)]
+struct Page {
+    id: usize,
+    log: Rc<Log>,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Page {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|page, rect| {
+            page.log.lefts.borrow_mut().insert(page.id, rect.left());
+        });
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        let pressed = matches!(event.action(), PointerAction::Down)
+            && rect.contains(event.pos());
+        if pressed {
+            self.log.presses.borrow_mut().push(self.id);
+        }
+        pressed
+    }
+}
+
+/// A root which fills the window with a tab view, and changes it when
+/// keys are pressed.
+#[derive(Default)]
+struct Root {
+    tabs: TabView<Page>,
+    log: Rc<Log>,
+    active: Rc<Cell<Option<usize>>>,
+    next_id: usize,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.tabs.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|root, _rect| {
+            root.active.set(root.tabs.active_index());
+        });
+        desc.child(|root| &mut root.tabs);
+    }
+
+    fn key_event(&mut self, _rect: &WidgetRect, event: &KeyEvent) -> bool {
+        let (KeyAction::Down, Key::Character(ch)) = (event.action, event.key)
+        else {
+            return false;
+        };
+        match ch {
+            'a' => {
+                let id = self.next_id;
+                self.next_id += 1;
+                let page = Page {
+                    id,
+                    log: Rc::clone(&self.log),
+                };
+                self.tabs.add_tab(&format!("Tab {id}"), page);
+            }
+            'r' => self.tabs.remove_tab(0),
+            's' => self.tabs.set_animated(true),
+            _ => match ch.to_digit(10) {
+                Some(index) => self.tabs.set_active_index(index as usize),
+                None => return false,
+            },
+        }
+        true
+    }
+}
+
+fn press(app: &mut App<OpenGlRenderPlatform>, keys: &str) {
+    for ch in keys.chars() {
+        key(app, Key::Character(ch));
+    }
+}
+
+fn setup() -> (App<OpenGlRenderPlatform>, Rc<Log>, Rc<Cell<Option<usize>>>) {
+    let root = Widget::<Root>::default();
+    let log = Rc::clone(&root.log);
+    let active = Rc::clone(&root.active);
+    (app_with_root(root), log, active)
+}
+
+/// Click a page, and return which one received the press.
+fn click_page(
+    app: &mut App<OpenGlRenderPlatform>,
+    log: &Log,
+) -> Option<usize> {
+    app.mouse_click([240.0, 160.0]);
+    app.update_watches();
+    log.presses.borrow_mut().pop()
+}
+
+#[test]
+fn tabview_headers() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, log, active) = setup();
+    assert_eq!(active.get(), None);
+    press(&mut app, "aaa");
+    assert_eq!(active.get(), Some(0));
+    assert_eq!(click_page(&mut app, &log), Some(0));
+    app.mouse_click([180.0, 344.0]);
+    app.update_watches();
+    assert_eq!(active.get(), Some(1));
+    assert_eq!(click_page(&mut app, &log), Some(1));
+    // clicking the active header keeps it active
+    app.mouse_click([180.0, 344.0]);
+    app.update_watches();
+    assert_eq!(active.get(), Some(1));
+    // the header of a tab activated another way can be clicked again
+    press(&mut app, "2");
+    assert_eq!(active.get(), Some(2));
+    app.mouse_click([180.0, 344.0]);
+    app.update_watches();
+    assert_eq!(active.get(), Some(1));
+}
+
+#[test]
+fn tabview_add_remove() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, log, active) = setup();
+    press(&mut app, "aaa1");
+    assert_eq!(click_page(&mut app, &log), Some(1));
+    // removing a tab before the active one keeps the same page active
+    press(&mut app, "r");
+    assert_eq!(active.get(), Some(0));
+    assert_eq!(click_page(&mut app, &log), Some(1));
+    // removing the active tab activates the one which takes its place
+    press(&mut app, "r");
+    assert_eq!(active.get(), Some(0));
+    assert_eq!(click_page(&mut app, &log), Some(2));
+    // the headers follow the tabs
+    press(&mut app, "a");
+    app.mouse_click([180.0, 344.0]);
+    app.update_watches();
+    assert_eq!(active.get(), Some(1));
+    assert_eq!(click_page(&mut app, &log), Some(3));
+    press(&mut app, "rr");
+    assert_eq!(active.get(), None);
+    assert_eq!(click_page(&mut app, &log), None);
+}
+
+#[test]
+fn tabview_header_overflow() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, log, active) = setup();
+    press(&mut app, "aaaaaa");
+    // activating a hidden tab scrolls its header into view
+    press(&mut app, "5");
+    app.mouse_click([420.0, 344.0]);
+    app.update_watches();
+    assert_eq!(active.get(), Some(5));
+    app.mouse_click([60.0, 344.0]);
+    app.update_watches();
+    assert_eq!(active.get(), Some(2));
+    assert_eq!(click_page(&mut app, &log), Some(2));
+}
+
+#[test]
+fn tabview_slide() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, log, active) = setup();
+    press(&mut app, "aas1");
+    assert_eq!(active.get(), Some(1));
+    app.next_frame(Duration::from_millis(100));
+    app.update_watches();
+    // the new page comes in from the right, pushing the old one out
+    let lefts = log.lefts.borrow().clone();
+    assert!(lefts[&0] < 0.0);
+    assert!(lefts[&1] > 0.0 && lefts[&1] < 480.0);
+    assert!((lefts[&1] - lefts[&0] - 480.0).abs() < 0.01);
+    app.next_frame(Duration::from_millis(200));
+    app.update_watches();
+    assert_eq!(log.lefts.borrow()[&1], 0.0);
+    assert_eq!(click_page(&mut app, &log), Some(1));
+    // sliding back goes the other way
+    press(&mut app, "0");
+    app.next_frame(Duration::from_millis(100));
+    app.update_watches();
+    let lefts = log.lefts.borrow().clone();
+    assert!(lefts[&0] < 0.0);
+    assert!(lefts[&1] > 0.0);
+    app.next_frame(Duration::from_millis(200));
+    app.update_watches();
+    assert_eq!(log.lefts.borrow()[&0], 0.0);
+    assert_eq!(click_page(&mut app, &log), Some(0));
+}
@@ -0,0 +1,150 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+#![cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
+
+use std::{cell::RefCell, rc::Rc};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::Rect,
+    graphics::Color,
+    keyboard::{Key, Modifiers, TextEvent},
+    platforms::{opengl::OpenGlRenderPlatform, TEST_ENV},
+    watch::WatchedValueCore,
+    widget::{self, Widget},
+    widgets::TextArea,
+};
+
+mod utils;
+use utils::*;
+
+/// The area of the window covered by the text area, inside its padding.
+const TEXT_X: std::ops::Range<usize> = 96..384;
+
+#[derive(Default)]
+struct Root {
+    area: TextArea<OpenGlRenderPlatform>,
+    text: Rc<RefCell<String>>,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.area.set_width(300.0);
+            root.area.set_height(200.0);
+            root.area.set_center(rect.center());
+        });
+        desc.watch(|root, _rect| {
+            let text = &root.text;
+            root.area
+                .on_changed(|new| new.clone_into(&mut text.borrow_mut()));
+        });
+        desc.child(|root| &mut root.area);
+    }
+}
+
+const CTRL: Modifiers = Modifiers {
+    ctrl: true,
+    ..Modifiers::NONE
+};
+
+#[test]
+fn textarea_editing() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let text = Rc::<RefCell<String>>::default();
+    let mut root = Widget::<Root>::default();
+    root.text = Rc::clone(&text);
+    app.add_root(root);
+    app.update_watches();
+    app.mouse_click([240.0, 180.0]);
+    type_text(&mut app, "abcdef");
+    key(&mut app, Key::Enter);
+    type_text(&mut app, "ab");
+    key(&mut app, Key::Tab);
+    assert_eq!(*text.borrow(), "abcdef\nab\t");
+    // moving up and back down keeps the column
+    key(&mut app, Key::ArrowLeft);
+    key(&mut app, Key::ArrowUp);
+    type_text(&mut app, "X");
+    assert_eq!(*text.borrow(), "abXcdef\nab\t");
+    key_with(&mut app, Key::Character('z'), CTRL);
+    assert_eq!(*text.borrow(), "abcdef\nab\t");
+    key_with(&mut app, Key::Character('z'), CTRL);
+    assert_eq!(*text.borrow(), "abcdef");
+    key_with(&mut app, Key::Character('y'), CTRL);
+    assert_eq!(*text.borrow(), "abcdef\nab\t");
+    // the two lines of text are drawn at the top of the text area
+    let capture = window.draw_and_take_screenshot(&mut app);
+    assert!(is_color(&capture[..4 * 480 * 70], Color::BLACK));
+    let top = region(&capture, 480, TEXT_X, 240..274);
+    assert!(top.iter().any(|&color| is_dark(color)));
+    let bottom = region(&capture, 480, TEXT_X, 90..200);
+    assert!(bottom.iter().all(|&color| color == Color::WHITE));
+}
+
+#[test]
+fn textarea_long_document() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let text = Rc::<RefCell<String>>::default();
+    let mut root = Widget::<Root>::default();
+    root.text = Rc::clone(&text);
+    let line = "The quick brown fox jumps over the lazy dog.";
+    let document = vec![line; 2000].join("\n");
+    root.area.set_text(&document);
+    app.add_root(root);
+    app.update_watches();
+    app.mouse_click([240.0, 180.0]);
+    key_with(&mut app, Key::End, CTRL);
+    type_text(&mut app, "!");
+    // the paragraphs in view at the end of the document fill the text area
+    let capture = window.draw_and_take_screenshot(&mut app);
+    assert_eq!(text.borrow().len(), document.len() + 1);
+    let top = region(&capture, 480, TEXT_X, 240..274);
+    assert!(top.iter().any(|&color| is_dark(color)));
+    let bottom = region(&capture, 480, TEXT_X, 86..120);
+    assert!(bottom.iter().any(|&color| is_dark(color)));
+}
+
+#[test]
+fn textarea_composition() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let text = Rc::<RefCell<String>>::default();
+    let mut root = Widget::<Root>::default();
+    root.text = Rc::clone(&text);
+    app.add_root(root);
+    app.update_watches();
+    app.mouse_click([240.0, 180.0]);
+    type_text(&mut app, "ab");
+    key(&mut app, Key::ArrowLeft);
+    let caret_rect = app.state().text_input().get_unwatched().rect;
+    // text being composed is shown at the caret, but is not part of the
+    // text until it is committed
+    app.text_event(TextEvent::Editing {
+        text: "cde".to_string(),
+        cursor: 0..0,
+    });
+    let capture = window.draw_and_take_screenshot(&mut app);
+    assert_eq!(*text.borrow(), "ab");
+    let input = app.state().text_input().get_unwatched();
+    let composition = input.rect.expect("no text input rect");
+    let caret = caret_rect.expect("no text input rect");
+    assert!(composition.left() >= caret.left() - 0.5);
+    assert!(composition.width() > 0.0);
+    // the composition is underlined, below where its letters are drawn
+    // leave out the caret at the start of the composition
+    let left = composition.left().ceil() as usize + 2;
+    let right = composition.right().floor() as usize;
+    let bottom = composition.bottom().floor() as usize;
+    let underline = region(&capture, 480, left..right, bottom..bottom + 2);
+    assert!(underline.iter().any(|&color| color != Color::WHITE));
+    app.text_event(TextEvent::Input("cde".to_string()));
+    app.update_watches();
+    assert_eq!(*text.borrow(), "acdeb");
+    // committed text is undone like typing
+    key_with(&mut app, Key::Character('z'), CTRL);
+    assert_eq!(*text.borrow(), "ab");
+}
@@ -0,0 +1,219 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+#![cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
+
+use std::{cell::RefCell, rc::Rc};
+
+use suzy::{
+    app::{App, TestingExt},
+    clipboard::Clipboard,
+    dims::Rect,
+    graphics::Color,
+    keyboard::{Key, Modifiers, TextEvent},
+    platforms::{opengl::OpenGlRenderPlatform, TEST_ENV},
+    watch::WatchedValueCore,
+    widget::{self, Widget},
+    widgets::TextField,
+};
+
+mod utils;
+use utils::*;
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+/// The area of the window covered by the field, inside its padding.
+const TEXT_X: std::ops::Range<usize> = 146..334;
+const TEXT_Y: std::ops::Range<usize> = 168..192;
+
+#[derive(Default)]
+struct Root {
+    field: TextField<OpenGlRenderPlatform>,
+    changed: Log,
+    submitted: Log,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.field.set_width(200.0);
+            root.field.set_height(30.0);
+            root.field.set_center(rect.center());
+        });
+        desc.watch(|root, _rect| {
+            let changed = &root.changed;
+            root.field
+                .on_changed(|text| changed.borrow_mut().push(text.to_owned()));
+        });
+        desc.watch(|root, _rect| {
+            let submitted = &root.submitted;
+            root.field.on_submit(|text| {
+                submitted.borrow_mut().push(text.to_owned());
+            });
+        });
+        desc.child(|root| &mut root.field);
+    }
+}
+
+const CTRL: Modifiers = Modifiers {
+    ctrl: true,
+    ..Modifiers::NONE
+};
+
+const SHIFT: Modifiers = Modifiers {
+    shift: true,
+    ..Modifiers::NONE
+};
+
+const CTRL_SHIFT: Modifiers = Modifiers {
+    ctrl: true,
+    shift: true,
+    ..Modifiers::NONE
+};
+
+#[test]
+fn textfield() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let clipboard = app.test_clipboard();
+    let changed = Log::default();
+    let submitted = Log::default();
+    let mut root = Widget::<Root>::default();
+    root.changed = Rc::clone(&changed);
+    root.submitted = Rc::clone(&submitted);
+    app.add_root(root);
+    app.update_watches();
+    // text is ignored until the field is focused
+    type_text(&mut app, "ignored");
+    assert!(changed.borrow().is_empty());
+    app.mouse_click([240.0, 180.0]);
+    type_text(&mut app, "hello world");
+    assert_eq!(
+        changed.borrow().last().map(String::as_str),
+        Some("hello world")
+    );
+    key_with(&mut app, Key::ArrowLeft, CTRL_SHIFT);
+    key_with(&mut app, Key::Character('x'), CTRL);
+    assert_eq!(clipboard.text().as_deref(), Some("world"));
+    key(&mut app, Key::Home);
+    key_with(&mut app, Key::Character('v'), CTRL);
+    key(&mut app, Key::Enter);
+    assert_eq!(*submitted.borrow(), ["worldhello "]);
+    let capture = window.draw_and_take_screenshot(&mut app);
+    // the field is drawn over the black background, with its text in black
+    assert!(is_color(&capture[..4 * 480 * 150], Color::BLACK));
+    let inside = region(&capture, 480, TEXT_X, TEXT_Y);
+    assert!(inside.contains(&Color::WHITE));
+    assert!(inside.iter().any(|&color| is_dark(color)));
+}
+
+/// Add a field to an app.
+fn setup(
+    configure: impl FnOnce(&mut Root),
+) -> (App<OpenGlRenderPlatform>, Log) {
+    let changed = Log::default();
+    let mut root = Widget::<Root>::default();
+    root.changed = Rc::clone(&changed);
+    configure(&mut root);
+    (app_with_root(root), changed)
+}
+
+fn focus(app: &mut App<OpenGlRenderPlatform>) {
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+}
+
+#[test]
+fn textfield_mask() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, changed) = setup(|root| *root.field.mask = Some('*'));
+    focus(&mut app);
+    let clipboard = app.test_clipboard();
+    clipboard
+        .set_text("clipboard")
+        .expect("failed to set clipboard");
+    type_text(&mut app, "secret");
+    assert_eq!(changed.borrow().last().map(String::as_str), Some("secret"));
+    // masked text can not be copied or cut
+    key_with(&mut app, Key::Character('a'), CTRL);
+    key_with(&mut app, Key::Character('c'), CTRL);
+    key_with(&mut app, Key::Character('x'), CTRL);
+    assert_eq!(clipboard.text().as_deref(), Some("clipboard"));
+    assert_eq!(changed.borrow().last().map(String::as_str), Some("secret"));
+    // words would reveal the structure of the text, so Ctrl moves to the
+    // ends of it
+    key(&mut app, Key::End);
+    key_with(&mut app, Key::ArrowLeft, CTRL_SHIFT);
+    type_text(&mut app, "x");
+    assert_eq!(changed.borrow().last().map(String::as_str), Some("x"));
+}
+
+#[test]
+fn textfield_placeholder() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, changed) = setup(|root| {
+        *root.field.placeholder = "Search".to_string();
+    });
+    // the placeholder is drawn in gray, and is not the field's text
+    let capture = window.draw_and_take_screenshot(&mut app);
+    let inside = region(&capture, 480, TEXT_X, TEXT_Y);
+    assert!(inside.iter().any(|&color| color != Color::WHITE));
+    assert!(!inside.iter().any(|&color| is_dark(color)));
+    focus(&mut app);
+    key(&mut app, Key::Enter);
+    assert!(changed.borrow().is_empty());
+    type_text(&mut app, "a");
+    let capture = window.draw_and_take_screenshot(&mut app);
+    let inside = region(&capture, 480, TEXT_X, TEXT_Y);
+    assert!(inside.iter().any(|&color| is_dark(color)));
+}
+
+#[test]
+fn textfield_max_length() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, changed) = setup(|root| *root.field.max_length = Some(5));
+    focus(&mut app);
+    let clipboard = app.test_clipboard();
+    type_text(&mut app, "hello world");
+    assert_eq!(changed.borrow().last().map(String::as_str), Some("hello"));
+    // pasted text is truncated to fit, replacing the selection
+    clipboard
+        .set_text("abcdefg")
+        .expect("failed to set clipboard");
+    key_with(&mut app, Key::ArrowLeft, SHIFT);
+    key_with(&mut app, Key::Character('v'), CTRL);
+    assert_eq!(changed.borrow().last().map(String::as_str), Some("hella"));
+}
+
+#[test]
+fn textfield_composition() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let (mut app, changed) = setup(|_root| {});
+    focus(&mut app);
+    type_text(&mut app, "ab");
+    let caret_rect = app.state().text_input().get_unwatched().rect;
+    // text being composed is shown at the caret, but is not part of the
+    // text until it is committed
+    app.text_event(TextEvent::Editing {
+        text: "cde".to_string(),
+        cursor: 3..3,
+    });
+    let capture = window.draw_and_take_screenshot(&mut app);
+    assert_eq!(changed.borrow().last().map(String::as_str), Some("ab"));
+    let input = app.state().text_input().get_unwatched();
+    let composition = input.rect.expect("no text input rect");
+    let caret = caret_rect.expect("no text input rect");
+    assert!(composition.left() >= caret.left() - 0.5);
+    assert!(composition.width() > 0.0);
+    assert!(composition.right() < 340.0);
+    // the composition is underlined, below where its letters are drawn
+    let left = composition.left().ceil() as usize;
+    // leave out the caret at the end of the composition
+    let right = composition.right().floor() as usize - 2;
+    let bottom = composition.bottom().floor() as usize;
+    let underline = region(&capture, 480, left..right, bottom..bottom + 2);
+    assert!(underline.iter().any(|&color| color != Color::WHITE));
+    app.text_event(TextEvent::Input("cde".to_string()));
+    app.update_watches();
+    assert_eq!(changed.borrow().last().map(String::as_str), Some("abcde"));
+}
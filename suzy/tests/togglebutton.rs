@@ -7,6 +7,8 @@ use suzy::{
     app::{App, TestingExt},
     dims::{Padding2d, Rect},
     graphics::{Color, Conditional},
+    keyboard::{Key, KeyAction, KeyEvent},
+    navigation::NavAction,
     platforms::{
         opengl::{OpenGlRenderPlatform, SlicedImage},
         TEST_ENV,
@@ -27,7 +29,8 @@ struct ButtonContent {
 
 impl Selectable for ButtonContent {
     fn selection_changed(&mut self, state: SelectionState) {
-        self.image.enable = state == SelectionState::active();
+        self.image.enable = state == SelectionState::active()
+            || state == SelectionState::pressed();
     }
 }
 
@@ -148,3 +151,51 @@ fn togglebutton_group() {
     assert_eq!(group_value_output.get(), None);
     assert!(is_color(&capture, Color::BLACK));
 }
+
+#[test]
+fn togglebutton_keyboard() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let group_value_output = std::rc::Rc::default();
+    let group_value_feedback = std::rc::Rc::clone(&group_value_output);
+    let mut root = Widget::<GroupRoot>::default();
+    root.value_feedback = group_value_feedback;
+    root.bottom.set_default(true);
+    app.add_root(root);
+    let capture = window.draw_and_take_screenshot(&mut app);
+    assert_eq!(group_value_output.get(), None);
+    assert!(is_color(&capture, Color::BLACK));
+    // with nothing focused, Enter goes to the default button, which is
+    // pressed while the key is held
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Enter)));
+    let capture = window.draw_and_take_screenshot(&mut app);
+    let index = (capture.len() / 3) & ALIGN_MASK;
+    let (bottom_3rd, top) = capture.split_at(index);
+    assert_eq!(group_value_output.get(), None);
+    assert!(is_color(round_back(bottom_3rd), Color::WHITE));
+    assert!(is_color(round_front(top), Color::BLACK));
+    // and toggles when it is released
+    assert!(app.key_event(KeyEvent::new(KeyAction::Up, Key::Enter)));
+    let capture = window.draw_and_take_screenshot(&mut app);
+    let index = (capture.len() / 3) & ALIGN_MASK;
+    let (bottom_3rd, top) = capture.split_at(index);
+    assert_eq!(group_value_output.get(), Some(3));
+    assert!(is_color(round_back(bottom_3rd), Color::WHITE));
+    assert!(is_color(round_front(top), Color::BLACK));
+    // focus the top button and toggle it with Space
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Tab)));
+    assert!(app.key_event(KeyEvent::new(KeyAction::Down, Key::Space)));
+    assert!(app.key_event(KeyEvent::new(KeyAction::Up, Key::Space)));
+    let capture = window.draw_and_take_screenshot(&mut app);
+    let index = (capture.len() / 3) & ALIGN_MASK;
+    let (bottom, top_3rd) = capture.split_at(2 * index);
+    assert_eq!(group_value_output.get(), Some(1));
+    assert!(is_color(round_back(bottom), Color::BLACK));
+    assert!(is_color(round_front(top_3rd), Color::WHITE));
+    // a gamepad accept action unselects it again
+    assert!(app.nav_event(NavAction::Activate, KeyAction::Down));
+    assert!(app.nav_event(NavAction::Activate, KeyAction::Up));
+    let capture = window.draw_and_take_screenshot(&mut app);
+    assert_eq!(group_value_output.get(), None);
+    assert!(is_color(&capture, Color::BLACK));
+}
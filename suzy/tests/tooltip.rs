@@ -0,0 +1,159 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::{Padding2d, Rect},
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerId},
+    selectable::SelectableIgnored,
+    tooltip::TooltipArea,
+    widget::{self, Widget},
+    widgets::ButtonBehavior,
+};
+
+mod utils;
+use utils::*;
+
+#[derive(Default)]
+struct Tip;
+
+impl widget::Content<NoGraphics> for Tip {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+}
+
+type Placement = Option<[f32; 2]>;
+
+#[derive(Default)]
+struct Root {
+    area: TooltipArea<ButtonBehavior<SelectableIgnored<()>>, Tip>,
+    placement: Rc<Cell<Placement>>,
+    clicks: Rc<RefCell<usize>>,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|this, rect| {
+            this.area.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|this, _rect| {
+            let placement = this.area.is_shown().then(|| {
+                let tooltip = this.area.tooltip();
+                [tooltip.left(), tooltip.bottom()]
+            });
+            this.placement.set(placement);
+        });
+        desc.watch(|this, _rect| {
+            let clicks = &this.clicks;
+            this.area.content().on_click(|| {
+                *clicks.borrow_mut() += 1;
+            });
+        });
+        desc.child(|this| &mut this.area);
+    }
+}
+
+fn hover(app: &mut App<NoGraphics>, pos: [f32; 2]) {
+    let action = PointerAction::Hover(0.0, 0.0);
+    pointer(app, PointerId::Mouse, action, pos);
+}
+
+fn wait(app: &mut App<NoGraphics>, millis: u64) {
+    app.next_frame(Duration::from_millis(millis));
+    app.update_watches();
+}
+
+fn setup() -> (App<NoGraphics>, Rc<Cell<Placement>>, Rc<RefCell<usize>>) {
+    let root = Widget::<Root>::default();
+    let placement = Rc::clone(&root.placement);
+    let clicks = Rc::clone(&root.clicks);
+    (app_with_root(root), placement, clicks)
+}
+
+#[test]
+fn shown_after_hover_delay() {
+    let (mut app, placement, _clicks) = setup();
+    hover(&mut app, [240.0, 180.0]);
+    wait(&mut app, 300);
+    assert_eq!(placement.get(), None);
+    // moving restarts the delay
+    hover(&mut app, [200.0, 180.0]);
+    wait(&mut app, 300);
+    assert_eq!(placement.get(), None);
+    wait(&mut app, 300);
+    assert_eq!(placement.get(), Some([212.0, 140.0]));
+    app.pointer_leave(PointerId::Mouse);
+    app.update_watches();
+    assert_eq!(placement.get(), None);
+}
+
+#[test]
+fn kept_inside_window() {
+    let (mut app, placement, _clicks) = setup();
+    hover(&mut app, [470.0, 10.0]);
+    wait(&mut app, 600);
+    assert_eq!(placement.get(), Some([360.0, 26.0]));
+}
+
+#[test]
+fn hidden_by_press() {
+    let (mut app, placement, clicks) = setup();
+    hover(&mut app, [240.0, 180.0]);
+    wait(&mut app, 600);
+    assert!(placement.get().is_some());
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+    assert_eq!(placement.get(), None);
+    assert_eq!(*clicks.borrow(), 1);
+    // not shown again until the pointer leaves and comes back
+    hover(&mut app, [250.0, 180.0]);
+    wait(&mut app, 600);
+    assert_eq!(placement.get(), None);
+    app.pointer_leave(PointerId::Mouse);
+    app.update_watches();
+    hover(&mut app, [250.0, 180.0]);
+    wait(&mut app, 600);
+    assert!(placement.get().is_some());
+}
+
+#[test]
+fn touch_long_press() {
+    let (mut app, placement, clicks) = setup();
+    let touch = PointerId::Touch(1);
+    // a quick tap clicks the button without showing the tooltip
+    pointer(&mut app, touch, PointerAction::Down, [240.0, 180.0]);
+    wait(&mut app, 100);
+    pointer(&mut app, touch, PointerAction::Up, [240.0, 180.0]);
+    assert_eq!(placement.get(), None);
+    assert_eq!(*clicks.borrow(), 1);
+    // a long press shows the tooltip until released, without clicking
+    pointer(&mut app, touch, PointerAction::Down, [240.0, 180.0]);
+    wait(&mut app, 600);
+    assert_eq!(placement.get(), Some([252.0, 140.0]));
+    pointer(&mut app, touch, PointerAction::Up, [240.0, 180.0]);
+    assert_eq!(placement.get(), None);
+    assert_eq!(*clicks.borrow(), 1);
+}
+
+#[cfg(all(feature = "platform-opengl", feature = "default-font"))]
+#[test]
+fn text_tooltip_fits_text() {
+    use suzy::{
+        platforms::opengl::OpenGlRenderPlatform, tooltip::TooltipText,
+    };
+    type Area = TooltipArea<(), TooltipText<OpenGlRenderPlatform>>;
+    let mut area = Area::default();
+    area.set_text("Save");
+    let [short_width, short_height] = area.tooltip_size();
+    area.set_text("Save the file");
+    let [long_width, long_height] = area.tooltip_size();
+    // the padding around the text adds 12 pixels to each dimension
+    assert!(short_width > 12.0 && long_width > short_width);
+    assert!(short_height > 12.0 && long_height == short_height);
+}
@@ -0,0 +1,161 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2026 Violet Leonard */
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use suzy::{
+    app::App,
+    dims::Rect,
+    platform::RenderPlatform,
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerEvent},
+    widget::{self, UniqueHandle, Widget, WidgetGraphic, WidgetRect},
+};
+
+mod utils;
+use utils::*;
+
+/// A graphic which would draw its widget's children at twice their size,
+/// offset to the right.
+#[derive(Default)]
+struct Zoom;
+
+impl WidgetGraphic<NoGraphics> for Zoom {
+    type BeforeGetter = fn(&mut ()) -> &mut [(); 0];
+    type AfterGetter = fn(&mut ()) -> &mut [(); 0];
+
+    fn before_children(&mut self) -> &mut [(); 0] {
+        &mut []
+    }
+
+    fn after_children(&mut self) -> &mut [(); 0] {
+        &mut []
+    }
+
+    fn map_point_to_children(&self, [x, y]: [f32; 2]) -> [f32; 2] {
+        [(x - 100.0) / 2.0, y / 2.0]
+    }
+}
+
+type Log = Rc<RefCell<Vec<[f32; 4]>>>;
+
+#[derive(Default)]
+struct Recorder {
+    handle: UniqueHandle,
+    hovered: Rc<Cell<bool>>,
+    log: Log,
+}
+
+impl<P: RenderPlatform> widget::Content<P> for Recorder {
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, _rect| {
+            this.hovered.set(this.handle.is_hovered());
+        });
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        let [x, y] = event.pos();
+        let [dx, dy] = match *event.action() {
+            PointerAction::Move(dx, dy) => [dx, dy],
+            _ => [0.0, 0.0],
+        };
+        if rect.contains([x, y]) {
+            self.log.borrow_mut().push([x, y, dx, dy]);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn handle(&self) -> Option<&UniqueHandle> {
+        Some(&self.handle)
+    }
+}
+
+#[derive(Default)]
+struct Root<G> {
+    graphic: G,
+    child: Widget<Recorder>,
+}
+
+impl<P, G> widget::Content<P> for Root<G>
+where
+    P: RenderPlatform,
+    G: 'static + WidgetGraphic<P>,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|root, _rect| {
+            root.child.set_width(100.0);
+            root.child.set_height(100.0);
+            root.child.set_left(0.0);
+            root.child.set_bottom(0.0);
+        });
+        desc.graphic(|root| &mut root.graphic);
+        desc.child(|root| &mut root.child);
+    }
+}
+
+#[test]
+fn pointer_events_are_transformed() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let log = Log::default();
+    let hovered = Rc::new(Cell::new(false));
+    let mut root = Widget::<Root<Zoom>>::default();
+    root.child.log = Rc::clone(&log);
+    root.child.hovered = Rc::clone(&hovered);
+    app.add_root(root);
+    app.update_watches();
+    // untransformed, this would be inside the child
+    mouse(&mut app, PointerAction::Down, [50.0, 50.0]);
+    assert!(log.borrow().is_empty());
+    mouse(&mut app, PointerAction::Down, [250.0, 150.0]);
+    mouse(&mut app, PointerAction::Move(20.0, -10.0), [270.0, 140.0]);
+    assert_eq!(
+        log.take(),
+        [[75.0, 75.0, 0.0, 0.0], [85.0, 70.0, 10.0, -5.0]],
+    );
+    // hit-testing for hover also happens in the child's space
+    mouse(&mut app, PointerAction::Hover(0.0, 0.0), [50.0, 50.0]);
+    assert!(!hovered.get());
+    mouse(&mut app, PointerAction::Hover(0.0, 0.0), [290.0, 190.0]);
+    assert!(hovered.get());
+    mouse(&mut app, PointerAction::Hover(0.0, 0.0), [310.0, 190.0]);
+    assert!(!hovered.get());
+}
+
+#[cfg(feature = "platform-opengl")]
+#[test]
+fn pointer_events_are_rotated() {
+    use suzy::platforms::opengl::{Mat4, OpenGlRenderPlatform, Transform};
+
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let log = Log::default();
+    let mut root = Widget::<Root<Transform>>::default();
+    // children are turned a quarter turn counter-clockwise, then moved
+    // right, so (x, y) in the child is drawn at (300 - y, x)
+    root.graphic.matrix = Mat4::translate(300.0, 0.0)
+        * Mat4::rotate(std::f32::consts::FRAC_PI_2);
+    root.child.log = Rc::clone(&log);
+    app.add_root(root);
+    app.update_watches();
+    mouse(&mut app, PointerAction::Down, [50.0, 50.0]);
+    assert!(log.borrow().is_empty());
+    mouse(&mut app, PointerAction::Down, [250.0, 50.0]);
+    mouse(&mut app, PointerAction::Move(20.0, -10.0), [270.0, 40.0]);
+    let rounded: Vec<_> = log
+        .take()
+        .iter()
+        .map(|entry| entry.map(f32::round))
+        .collect();
+    assert_eq!(
+        rounded,
+        [[50.0, 50.0, 0.0, 0.0], [40.0, 30.0, -10.0, -20.0]]
+    );
+}
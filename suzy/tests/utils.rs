@@ -1,7 +1,14 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
-use suzy::graphics::Color;
+use suzy::{
+    app::App,
+    graphics::Color,
+    keyboard::{Key, KeyAction, KeyEvent, Modifiers, TextEvent},
+    platform::RenderPlatform,
+    pointer::{PointerAction, PointerEventData, PointerId},
+    widget::{Content, Widget},
+};
 
 // use this to ensure we split at pixel boundries (4 bytes)
 #[allow(unused)]
@@ -39,3 +46,125 @@ pub fn is_color(buffer: &[u8], color: Color) -> bool {
         buf_color == color
     })
 }
+
+/// Get the colors of the pixels in an area of a screenshot of a window
+/// `width` pixels wide.  Rows are counted from the bottom of the window.
+#[must_use]
+#[allow(unused)]
+pub fn region(
+    buffer: &[u8],
+    width: usize,
+    x: std::ops::Range<usize>,
+    y: std::ops::Range<usize>,
+) -> Vec<Color> {
+    y.flat_map(|row| {
+        let start = 4 * (row * width + x.start);
+        let end = 4 * (row * width + x.end);
+        buffer[start..end].chunks_exact(4).map(|chunk| {
+            Color::from_rgba8(chunk[0], chunk[1], chunk[2], chunk[3])
+        })
+    })
+    .collect()
+}
+
+/// Check if a color is close to black, e.g. where text is drawn.
+#[must_use]
+#[allow(unused)]
+pub fn is_dark(color: Color) -> bool {
+    let [r, g, b, _a] = color.rgba8();
+    r < 64 && g < 64 && b < 64
+}
+
+/// Check if a color is within a small distance of another, to allow for
+/// rounding and antialiasing.
+#[must_use]
+#[allow(unused)]
+pub fn is_near(color: Color, expected: Color) -> bool {
+    let actual = color.rgba8();
+    let expected = expected.rgba8();
+    actual
+        .iter()
+        .zip(expected.iter())
+        .all(|(&a, &b)| a.abs_diff(b) <= 8)
+}
+
+/// Send a pointer event to an app, then update its watches.  Returns true
+/// if the event was handled.
+#[allow(unused)]
+pub fn pointer<P: 'static>(
+    app: &mut App<P>,
+    id: PointerId,
+    action: PointerAction,
+    pos: [f32; 2],
+) -> bool {
+    let [x, y] = pos;
+    let handled = app.pointer_event(PointerEventData::new(id, action, x, y));
+    app.update_watches();
+    handled
+}
+
+/// Send a mouse event to an app; see [`pointer`].
+#[allow(unused)]
+pub fn mouse<P: 'static>(
+    app: &mut App<P>,
+    action: PointerAction,
+    pos: [f32; 2],
+) -> bool {
+    pointer(app, PointerId::Mouse, action, pos)
+}
+
+/// Send an event for a touch to an app; see [`pointer`].
+#[allow(unused)]
+pub fn touch<P: 'static>(
+    app: &mut App<P>,
+    id: i64,
+    action: PointerAction,
+    pos: [f32; 2],
+) -> bool {
+    pointer(app, PointerId::Touch(id), action, pos)
+}
+
+/// Press and release a key, then update an app's watches.  Returns true if
+/// the press was handled.
+#[allow(unused)]
+pub fn key<P: 'static>(app: &mut App<P>, key: Key) -> bool {
+    key_with(app, key, Modifiers::NONE)
+}
+
+/// Press and release a key while holding modifier keys; see [`key`].
+#[allow(unused)]
+pub fn key_with<P: 'static>(
+    app: &mut App<P>,
+    key: Key,
+    modifiers: Modifiers,
+) -> bool {
+    let press = KeyEvent::new(KeyAction::Down, key).with_modifiers(modifiers);
+    let handled = app.key_event(press);
+    let release = KeyEvent::new(KeyAction::Up, key).with_modifiers(modifiers);
+    app.key_event(release);
+    app.update_watches();
+    handled
+}
+
+/// Type text into an app one character at a time, then update its watches.
+#[allow(unused)]
+pub fn type_text<P: 'static>(app: &mut App<P>, text: &str) {
+    for ch in text.chars() {
+        app.text_event(TextEvent::Input(ch.to_string()));
+    }
+    app.update_watches();
+}
+
+/// Create an app with a 480x360 window showing a root widget, with its
+/// watches updated.
+#[allow(unused)]
+pub fn app_with_root<P, T>(root: Widget<T>) -> App<P>
+where
+    P: RenderPlatform,
+    T: Content<P>,
+{
+    let mut app = App::new(480.0, 360.0);
+    app.add_root(root);
+    app.update_watches();
+    app
+}